@@ -0,0 +1,42 @@
+//! Sending [`Command`]s to the kernel running on a core through its [`Mailbox`]
+//!
+//! [`Mailbox`]: core_arg::Mailbox
+
+use errchain::prelude::*;
+use core_arg::{CoreArg, Command, CommandStatus};
+
+use crate::uefi;
+
+/// Microseconds between two checks for the answer of a core
+const POLL_INTERVAL_US: usize = 1_000;
+
+/// Various errors that sending a command can result in
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    /// The core didn't answer the command in time
+    Timeout,
+}
+
+/// Post `command` to the kernel of `core_arg` and wait up to `timeout_us`
+/// microseconds for its answer
+///
+/// # Errors
+///
+/// * The previous command to the core is still unanswered
+/// * The core didn't answer within `timeout_us`
+pub fn send(core_arg: &CoreArg, command: Command, timeout_us: usize)
+        -> Result<CommandStatus> {
+    let seq = core_arg.mailbox.post(command)?;
+
+    let mut waited = 0;
+    loop {
+        if let Some(status) = core_arg.mailbox.response(seq) {
+            return Ok(status);
+        }
+
+        ensure!(waited < timeout_us, &Error::Timeout);
+
+        uefi::sleep(POLL_INTERVAL_US)?;
+        waited += POLL_INTERVAL_US;
+    }
+}
@@ -0,0 +1,141 @@
+//! Format agnostic view of the downloaded kernel. The kernel can either be a PE built
+//! for `x86_64-pc-windows-msvc` or an ELF built for `x86_64-unknown-none`. The format
+//! is detected from the magic bytes of the image. An aarch64 bootloader expects an
+//! aarch64 image, though the kernel doesn't build for aarch64 yet.
+
+use errchain::prelude::*;
+use core_arg::BuildId;
+
+/// Various errors that loading the kernel image can result in
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    /// The kernel image starts with neither the PE (`MZ`) nor the ELF (`\x7fELF`) magic
+    UnknownFormat,
+
+    /// The ELF kernel was built for a different architecture than the bootloader
+    ElfMachineMismatch,
+}
+
+/// The architecture a PE kernel must be built for to run on this bootloader
+#[cfg(target_arch = "x86_64")]
+const PE_MACHINE: pe::Machine = pe::Machine::Amd64;
+
+/// The architecture a PE kernel must be built for to run on this bootloader
+#[cfg(target_arch = "aarch64")]
+const PE_MACHINE: pe::Machine = pe::Machine::Arm64;
+
+/// The architecture an ELF kernel must be built for to run on this bootloader
+#[cfg(target_arch = "x86_64")]
+const ELF_MACHINE: elf::Machine = elf::Machine::X86_64;
+
+/// The architecture an ELF kernel must be built for to run on this bootloader
+#[cfg(target_arch = "aarch64")]
+const ELF_MACHINE: elf::Machine = elf::Machine::Aarch64;
+
+/// Number of sections that can be returned from either parser
+const NUM_SECTIONS: usize = 8;
+
+/// Section permissions of the kernel image regardless of the image format
+#[derive(Debug, Copy, Clone)]
+pub struct Permissions {
+    /// This section is executable
+    pub executable: bool,
+
+    /// This section is readable
+    pub readable:   bool,
+
+    /// This section is writable
+    pub writable:   bool
+}
+
+impl From<pe::SectionPermissions> for Permissions {
+    fn from(perms: pe::SectionPermissions) -> Permissions {
+        Permissions {
+            executable: perms.executable,
+            readable:   perms.readable,
+            writable:   perms.writable
+        }
+    }
+}
+
+impl From<elf::SegmentPermissions> for Permissions {
+    fn from(perms: elf::SegmentPermissions) -> Permissions {
+        Permissions {
+            executable: perms.executable,
+            readable:   perms.readable,
+            writable:   perms.writable
+        }
+    }
+}
+
+/// Parsed kernel image
+pub struct KernelImage<'a> {
+    /// Loadable sections with their address relative to `image_base` and permissions
+    pub sections: [Option<(&'a [u8], u64, Permissions)>; NUM_SECTIONS],
+
+    /// Requested image base for the kernel
+    pub image_base: u64,
+
+    /// Entry point of the kernel
+    pub entry_point: u64,
+
+    /// Build information of the kernel. Empty for ELF kernels.
+    pub build_id: BuildId
+}
+
+/// Parse the kernel found in `data`. ELF kernels have their relocations applied in
+/// place since the kernel is always mapped at its linked address.
+///
+/// # Errors
+///
+/// If the format of the image is unknown, if the image fails to parse, or if the image
+/// was built for a different architecture than the bootloader
+pub fn parse(data: &mut [u8]) -> Result<KernelImage> {
+    let mut sections = [None; NUM_SECTIONS];
+
+    if elf::is_elf(data) {
+        // Check the architecture before touching the image with relocations
+        ensure!(elf::parse(data)?.machine == ELF_MACHINE, &Error::ElfMachineMismatch);
+
+        let relocations = elf::relocate(data, 0)?;
+        print!("Applied {} kernel relocations\n", relocations);
+
+        let parsed = elf::parse(data)?;
+
+        for (section, segment) in sections.iter_mut().zip(parsed.segments.iter()) {
+            *section = segment.map(|(data, addr, perms)| (data, addr, perms.into()));
+        }
+
+        return Ok(KernelImage {
+            sections,
+            image_base:  parsed.image_base,
+            entry_point: parsed.entry_point,
+            build_id:    BuildId::new()
+        });
+    }
+
+    ensure!(data.starts_with(b"MZ"), &Error::UnknownFormat);
+
+    let parsed = pe::parse_for(data, PE_MACHINE)?;
+
+    for (section, pe_section) in sections.iter_mut().zip(parsed.sections.iter()) {
+        *section = pe_section.map(|(data, addr, perms)| (data, addr.into(), perms.into()));
+    }
+
+    // Get the build information from the debug directory
+    let mut build_id = BuildId::new();
+    build_id.timestamp = parsed.timestamp;
+
+    if let Some(codeview) = parsed.codeview() {
+        build_id.guid = codeview.guid;
+        build_id.age  = codeview.age;
+        build_id.set_pdb_path(codeview.pdb_path);
+    }
+
+    Ok(KernelImage {
+        sections,
+        image_base:  parsed.image_base,
+        entry_point: parsed.entry_point,
+        build_id
+    })
+}
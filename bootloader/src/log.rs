@@ -0,0 +1,117 @@
+//! Printing the [`LogRing`](core_arg::LogRing) of every core to serial
+
+use core_arg::{CoreArg, LOG_RING_SIZE};
+
+use crate::NUM_CPUS;
+
+/// Number of bytes read from a ring at a time
+const CHUNK_SIZE: usize = 256;
+
+/// Maximum number of bytes of a UTF-8 character cut off at the end of a read
+const MAX_INCOMPLETE: usize = 3;
+
+/// Print `bytes` as text, replacing invalid UTF-8 sequences. Returns the bytes of a
+/// character cut off at the end of `bytes`, which aren't printed.
+fn print_lossy(mut bytes: &[u8]) -> &[u8] {
+    while !bytes.is_empty() {
+        match core::str::from_utf8(bytes) {
+            Ok(text) => {
+                print!("{}", text);
+                break;
+            }
+            Err(err) => {
+                let (valid, rest) = bytes.split_at(err.valid_up_to());
+
+                // Safe since `from_utf8` checked the bytes up to `valid_up_to`
+                print!("{}", unsafe { core::str::from_utf8_unchecked(valid) });
+
+                // The rest of a sequence cut at the end of the chunk may be in the
+                // next read
+                let invalid = match err.error_len() {
+                    Some(invalid) => invalid,
+                    None          => return rest
+                };
+
+                print!("\u{fffd}");
+                bytes = &rest[invalid..];
+            }
+        }
+    }
+
+    &[]
+}
+
+/// Drains the log rings of the cores, prefixing each line with the core it came from
+pub struct LogDrain {
+    /// Whether the next byte of each core starts a new line
+    at_line_start: [bool; NUM_CPUS],
+
+    /// Start of a character of each core cut off at the end of the last read
+    incomplete: [[u8; MAX_INCOMPLETE]; NUM_CPUS],
+
+    /// Number of valid bytes in `incomplete`
+    incomplete_len: [usize; NUM_CPUS]
+}
+
+impl LogDrain {
+    /// Create a drain where every core starts on a new line
+    pub fn new() -> Self {
+        Self {
+            at_line_start:  [true; NUM_CPUS],
+            incomplete:     [[0; MAX_INCOMPLETE]; NUM_CPUS],
+            incomplete_len: [0; NUM_CPUS]
+        }
+    }
+
+    /// Print everything in the rings of `core_args` along with the number of bytes each
+    /// core dropped since the last call
+    pub fn drain(&mut self, core_args: &[CoreArg]) {
+        let mut chunk = [0u8; MAX_INCOMPLETE + CHUNK_SIZE];
+
+        for (core_id, core_arg) in core_args.iter().enumerate().take(NUM_CPUS) {
+            // Read at most a ring's worth so that a busy core can't keep the bootloader
+            // here forever
+            for _ in 0..LOG_RING_SIZE / CHUNK_SIZE {
+                // Continue the character cut off by the last read
+                let incomplete = self.incomplete_len[core_id];
+                chunk[..incomplete]
+                    .copy_from_slice(&self.incomplete[core_id][..incomplete]);
+
+                let len = core_arg.log.read(&mut chunk[incomplete..]);
+                if len == 0 {
+                    break;
+                }
+
+                self.incomplete_len[core_id] = 0;
+
+                let mut rest = &chunk[..incomplete + len];
+                while !rest.is_empty() {
+                    let end = rest.iter().position(|&byte| byte == b'\n')
+                        .map_or(rest.len(), |newline| newline + 1);
+                    let (line, next) = rest.split_at(end);
+
+                    if self.at_line_start[core_id] {
+                        print!("[core {}] ", core_id);
+                    }
+
+                    let cut = print_lossy(line);
+                    self.incomplete[core_id][..cut.len()].copy_from_slice(cut);
+                    self.incomplete_len[core_id] = cut.len();
+
+                    self.at_line_start[core_id] = line.ends_with(b"\n");
+                    rest = next;
+                }
+            }
+
+            let dropped = core_arg.log.take_dropped();
+            if dropped > 0 {
+                if !self.at_line_start[core_id] {
+                    print!("\n");
+                    self.at_line_start[core_id] = true;
+                }
+
+                print!("[core {}] dropped {} bytes of log\n", core_id, dropped);
+            }
+        }
+    }
+}
@@ -1,12 +1,6 @@
 //! Entry point for Paintbrush
 
-#![feature(asm)]
-#![feature(panic_info_message)]
-#![feature(bool_to_option)]
-#![feature(try_trait)]
 #![feature(stmt_expr_attributes)]
-#![feature(abi_efiapi)]
-#![feature(exclusive_range_pattern)]
 #![no_std]
 #![no_main]
 // Specific clippy deny requests
@@ -21,14 +15,18 @@ mod uefi;
 // #[macro_use] mod errchain;
 // mod acpi;
 mod stackvec;
+mod kernel_image;
+mod stats;
+mod command;
+mod log;
 
 #[cfg(target_arch = "x86_64")]
 pub mod intel;
 
-use core_arg::CoreArg;
+use core_arg::{CoreArg, CoreState, PanicContext, Command, KernelArgs, LOG_RING_SIZE};
+use cpu_x86::X86Cpu;
 
 use core::panic::PanicInfo;
-use core::convert::TryInto;
 
 use phys_mem::PhysMem;
 use page_table::{CanMap, CanTranslate, EntryBuilder, PageSize};
@@ -39,6 +37,28 @@ use errchain::prelude::*;
 /// Total number of CPUs we can currently handle
 const NUM_CPUS: usize = 36;
 
+/// Maximum number of frames printed in the backtrace of a panicking core
+const MAX_BACKTRACE_FRAMES: usize = 32;
+
+/// Number of times the kernel download is attempted before giving up on network errors
+const KERNEL_DOWNLOAD_ATTEMPTS: usize = 3;
+
+/// File on the TFTP server holding the arguments of the kernel. See
+/// [`KernelArgs::from_config`] for the format.
+const KERNEL_ARGS_FILE: &str = "paintbrush.args";
+
+/// Maximum number of bytes of [`KERNEL_ARGS_FILE`]
+const KERNEL_ARGS_FILE_SIZE: usize = 4096;
+
+/// Microseconds between two reports of the stats of every core
+const STATS_INTERVAL_US: usize = 1_000_000;
+
+/// Microseconds the cores may run before the bootloader asks them to stop
+const CORE_TIME_LIMIT_US: usize = 60_000_000;
+
+/// Microseconds a core has to answer a command
+const COMMAND_TIMEOUT_US: usize = 10_000_000;
+
 /// Callback function to used with `cfg("verbose")` to help debug library calls such as
 /// `PageTable`
 pub fn print_callback(input: core::fmt::Arguments) {
@@ -56,9 +76,7 @@ fn panic(info: &PanicInfo) -> ! {
         print!("PANIC: ");
     }
 
-    if let Some(message) = info.message() {
-        print!("{} ", message);
-    }
+    print!("{} ", info.message());
 
     print!("\n");
 
@@ -67,6 +85,84 @@ fn panic(info: &PanicInfo) -> ! {
     loop {}
 }
 
+/// Print the backtrace of a panicking core using the exception directory of the kernel.
+/// Only the stack of the core between the panic and `stack_top` is read.
+fn print_backtrace(image: &pe::Parsed, context: PanicContext, stack_top: u64) {
+    let mut frames = [0u64; MAX_BACKTRACE_FRAMES];
+
+    // The stacks of the cores are mapped in the bootloader's page table, but a corrupt
+    // stack can point anywhere, so only the kernel's part of the stack is read
+    let read_u64 = |addr: u64| {
+        let on_stack = addr >= context.rsp
+            && addr.checked_add(8).map_or(false, |end| end <= stack_top);
+
+        (on_stack && addr % 8 == 0)
+            .then(|| unsafe { core::ptr::read_volatile(addr as *const u64) })
+    };
+
+    let context = pe::unwind::Context::new(context.rip, context.rsp, context.rbp);
+    let count   = pe::unwind::backtrace(image, context, read_u64, &mut frames);
+
+    for (index, frame) in frames[..count].iter().enumerate() {
+        print!("  {:2}: {:#x} (kernel+{:#x})\n", index, frame,
+            frame.wrapping_sub(image.image_base));
+    }
+}
+
+/// Print how the kernel ended on every core that was started
+fn print_core_summary(core_args: &[CoreArg]) {
+    print!("Core summary:\n");
+
+    for (core_id, core_arg) in core_args.iter().enumerate() {
+        let status = &core_arg.status;
+        let state  = status.state();
+
+        if state == CoreState::Offline {
+            continue;
+        }
+
+        print!("  [{:2}] {:13}", core_id, state);
+
+        // Time counter ticks from starting the core to the kernel running and to the
+        // kernel being done
+        let booted = status.time(CoreState::Booting);
+        if let (Some(booted), Some(running)) = (booted, status.time(CoreState::Running)) {
+            print!(" boot {:>12} cycles", running.wrapping_sub(booted));
+        }
+        if let (Some(booted), Some(done)) = (booted, status.time(state)) {
+            if state.is_done() {
+                print!(" total {:>14} cycles", done.wrapping_sub(booted));
+            }
+        }
+
+        match state {
+            CoreState::Finished => {
+                if let Some(result) = status.result() {
+                    print!(" returned {:#x}", result);
+                }
+                print!("\n");
+            }
+            CoreState::Failed => {
+                match core_arg.error.chain() {
+                    Some(chain) => print!(" returned an error:{:?}", chain),
+                    None        => print!(" returned an error that didn't fit\n")
+                }
+            }
+            CoreState::Panicked | CoreState::OutOfMemory => {
+                print!(" {}\n", status.panic_message().unwrap_or("<no message>"));
+            }
+            CoreState::Booting => {
+                if core_arg.kernel_abi.is_empty() {
+                    print!(" never reached the kernel\n");
+                } else {
+                    print!(" rejected its CoreArg\n");
+                }
+            }
+            CoreState::Offline | CoreState::Running => print!("\n")
+        }
+    }
+}
+
 /// Real main that is called from `efi_main` and can return a `errchain::Result`
 #[allow(clippy::too_many_lines)]
 fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Result<()> {
@@ -74,7 +170,7 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
     uefi::use_system_table(system_table);
 
     // Disable the watchdog timer to never auto-reboot us
-    uefi::disable_watchdog_timer();
+    uefi::disable_watchdog_timer()?;
 
     // Get the memory map from UEFI
     let mut available_memory = uefi::memory_map(image_handle)?;
@@ -83,9 +179,22 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
     assert!(NUM_CPUS >= uefi::cpu_count()?.total, 
         "Too few CPUs allocated for this processor");
 
-    // Initialize the CoreArg and alive status array 
-    let mut core_args   = [CoreArg::new(); NUM_CPUS];
-    let mut alive_cores = [false;          NUM_CPUS];
+    // Allocate the CoreArg array from the available memory, since `NUM_CPUS` `CoreArg`s
+    // of 8 KiB each are too large for the UEFI stack. Allocated before the available
+    // memory is saved below, so resetting it doesn't hand the array to a core.
+    let core_args_size = core::mem::size_of::<[CoreArg; NUM_CPUS]>();
+    let core_args_addr = available_memory.allocate(core_args_size as u64,
+        core::mem::align_of::<CoreArg>() as u64)?;
+
+    // `CoreArg` holds atomics so it can't be copied into the array
+    let core_args = unsafe {
+        let core_args = core_args_addr as *mut CoreArg;
+        for core_id in 0..NUM_CPUS {
+            core_args.add(core_id).write(CoreArg::new());
+        }
+
+        core::slice::from_raw_parts_mut(core_args, NUM_CPUS)
+    };
 
     print!("Downloading kernel\n");
 
@@ -106,11 +215,50 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
     // Save the original available memory to use during soft reboot
     let original_available_memory = available_memory;
 
-    // Download the kernel from the TFTP server
-    uefi::tftp::read_file("paintbrush_x86.kernel", &mut kernel_buffer)?;
+    // Download the kernel from the TFTP server, retrying if the network failed. Any
+    // other error, such as a missing protocol, is returned immediately.
+    let mut attempt = 1;
+    loop {
+        let err = match uefi::tftp::read_file("paintbrush_x86.kernel", &mut kernel_buffer) {
+            Ok(()) => break,
+            Err(err) => err
+        };
+
+        let network_error = matches!(err.downcast_ref::<uefi::Error>(),
+            Some(uefi::Error::TftpConfigureFailed) | Some(uefi::Error::TftpReadFileFailed));
+
+        if !network_error || attempt >= KERNEL_DOWNLOAD_ATTEMPTS {
+            return Err(err);
+        }
+
+        print!("Kernel download failed ({}/{}), retrying\n{:?}\n", attempt, 
+            KERNEL_DOWNLOAD_ATTEMPTS, err);
+        attempt += 1;
+    }
+
+    // Download the kernel arguments. The file is optional, so any error means the
+    // kernel runs without arguments.
+    let mut kernel_args_file = [0u8; KERNEL_ARGS_FILE_SIZE];
+    let kernel_args_config = match uefi::tftp::read_file(KERNEL_ARGS_FILE,
+            &mut kernel_args_file) {
+        Ok(()) => {
+            // The file is text, so it ends at the first zero left in the buffer
+            let len = kernel_args_file.iter().position(|&byte| byte == 0)
+                .unwrap_or(KERNEL_ARGS_FILE_SIZE);
+            let config = core::str::from_utf8(&kernel_args_file[..len]).ok();
+            context_fmt!(config, "{} is not UTF-8", KERNEL_ARGS_FILE)?
+        }
+        Err(_) => {
+            print!("No {} on the TFTP server, running without arguments\n",
+                KERNEL_ARGS_FILE);
+            ""
+        }
+    };
 
     // Parse the kernel from the TFTP server for the segments and entry point
-    let parsed = pe::parse(&kernel_buffer)?;
+    let parsed = kernel_image::parse(&mut kernel_buffer)?;
+
+    print!("Kernel build: {}\n", parsed.build_id);
 
     // Create a page table for the next core
     let new_page_table = unsafe { 
@@ -129,13 +277,13 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
             print!("..Data: {:#x} Addr: {:#x} Perms: {:?}\n", section_data.len(), 
                 section_addr, perms);
 
-            // Get the section addr as as u64
-            let section_addr: u64 = (*section_addr).try_into().unwrap();
+            // Get the physical address of the section data in the kernel buffer
+            let section_phys = section_data.as_ptr() as u64 & !0xfff;
 
             if perms.readable && perms.executable && !perms.writable {
                 // Create the entry for the executable/readable section
                 let new_entry = EntryBuilder::default()
-                    .address(PhysAddr(kernel_buffer_addr + section_addr))
+                    .address(PhysAddr(section_phys))
                     .page_size(PageSize::Size4K)
                     .present(true)
                     .user_permitted(true)
@@ -144,7 +292,7 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
                     .finish();
 
                 // Calculate the virtual address for this section
-                let virt_addr = VirtAddr(parsed.image_base + section_addr);
+                let virt_addr = VirtAddr((parsed.image_base + section_addr) & !0xfff);
 
                 // Map the kernel into the page table for the core
                 new_page_table.map_raw_4k(new_entry, virt_addr, &mut available_memory, 
@@ -162,7 +310,7 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
 
     for core_id in 0..NUM_CPUS {
         // Get the CoreArg for this core
-        let mut core_arg = core_args[core_id];
+        let core_arg = &mut core_args[core_id];
 
         // Ignore the first core and all cores not available on the system
         if core_id == 0 || core_id >= NUM_CPUS {
@@ -172,17 +320,20 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
         // Modify the kernel arg for this core
         core_arg.reset();
         core_arg.set_core(core_id);
-
-        // Get the physical address of the location to write the status of the core
-        let alive_addr = &mut alive_cores[core_id] as *mut bool;
-        core_arg.set_alive_address(alive_addr);
+        core_arg.build_id = parsed.build_id;
+        let args = KernelArgs::from_config(kernel_args_config, core_id);
+        core_arg.args = context_fmt!(args, "arguments of core {}", core_id)?;
 
         // Amount of memory to allocate for each core
         let memory_size = 1024 * 1024 * 1024;
 
         // Allocate the physcial memory for this core
         let memory_start = available_memory.allocate(memory_size, 0x1000)?;
-        core_arg.insert_memory(memory_start, memory_size);
+        core_arg.insert_memory(memory_start, memory_size)?;
+
+        // Allocate the ring the kernel prints to
+        let log_ring = available_memory.allocate(LOG_RING_SIZE as u64, 0x1000)?;
+        unsafe { core_arg.log.set_buffer(log_ring as *mut u8, LOG_RING_SIZE)?; }
 
         // Get the physical address of the kernel entry point
         let entry_point_phys = curr_page_table.translate(VirtAddr(parsed.entry_point), 
@@ -197,9 +348,32 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
 
         // Start the core
         // uefi::startup_this_ap(core_id, parsed.entry_point as usize, core_arg_addr);
-        uefi::startup_this_ap(core_id, entry_point_func, core_arg_addr);
+        core_args[core_id].status.enter::<X86Cpu>(CoreState::Booting);
+        uefi::startup_this_ap(core_id, entry_point_func, core_arg_addr)?;
     }
 
+    // Parse the kernel again to unwind the stacks of panicking cores. ELF kernels
+    // don't have an exception directory to unwind with.
+    let unwind_image = if elf::is_elf(kernel_buffer) {
+        None
+    } else {
+        Some(pe::parse(kernel_buffer)?)
+    };
+
+    // Cores that have already had their panic or ABI mismatch reported
+    let mut panic_reported = [false; NUM_CPUS];
+    let mut abi_reported   = [false; NUM_CPUS];
+
+    // Stats of every core at the last report
+    let mut stats_report = stats::StatsReport::new();
+
+    // Prints what the cores printed to their log rings
+    let mut log_drain = log::LogDrain::new();
+
+    // Microseconds since the cores were started and whether they were asked to stop
+    let mut elapsed_us = 0;
+    let mut stop_sent  = false;
+
     let mut all_cores_finished = false; 
 
     while !all_cores_finished {
@@ -207,25 +381,85 @@ fn try_main(image_handle: usize, system_table: uefi::EfiMainSystemTable) -> Resu
         // working
         all_cores_finished = true; 
 
-        print!("Cores alive: ");
-        for (core_id, is_alive) in alive_cores.iter().enumerate() {
-            if *is_alive {
+        log_drain.drain(&core_args);
+
+        print!("Cores running: ");
+        for (core_id, core_arg) in core_args.iter().enumerate() {
+            // A core that rejected its `CoreArg` never leaves `Booting`
+            let state = core_arg.status.state();
+            if state != CoreState::Offline && !state.is_done() && !abi_reported[core_id] {
                 all_cores_finished = false;
                 print!("{} ", core_id);
             }
         }
         print!("\n");
 
-        for _ in 0..100 {
-            for (id, core_arg) in core_args.iter().enumerate() {
-                print!("[{}]: {:x?}\n", id, core_arg.stats.start_time);
+        // Report every core that rejected its `CoreArg` or panicked since the last
+        // check
+        for (core_id, core_arg) in core_args.iter().enumerate() {
+            // A kernel built with a different `CoreArg` layout writes its own header
+            // instead of using the rest of the `CoreArg`
+            let kernel_abi = unsafe { core::ptr::read_volatile(&core_arg.kernel_abi) };
+
+            if !kernel_abi.is_empty() && !abi_reported[core_id] {
+                abi_reported[core_id] = true;
+                if let Err(mismatch) = core_arg.abi.compare(&kernel_abi) {
+                    print!("Core {} rejected its CoreArg: {}\n", core_id, mismatch);
+                }
+            }
+
+            // The panic context and message are written before the state
+            let panicked = core_arg.status.state() == CoreState::Panicked;
+            if panicked && !panic_reported[core_id] {
+                panic_reported[core_id] = true;
+                print!("Core {} panicked running {}\n", core_id, core_arg.build_id);
+
+                if let Some(message) = core_arg.status.panic_message() {
+                    print!("Core {} panic message: {}\n", core_id, message);
+                }
+
+                let panic_context = unsafe {
+                    core::ptr::read_volatile(core_arg.panic_context.as_ptr())
+                };
+                let stack_top = unsafe { core::ptr::read_volatile(&core_arg.stack_top) };
+
+                if let Some(context) = panic_context {
+                    print!("Core {} panicked at {:#x}\n", core_id, context.rip);
+
+                    if let Some(image) = &unwind_image {
+                        print_backtrace(image, context, stack_top);
+                    }
+                }
             }
-            uefi::sleep(1_000_000);
         }
 
-        uefi::sleep(500_000);
+        uefi::sleep(STATS_INTERVAL_US)?;
+
+        // Print the stats summed across every core since the last check
+        stats_report.print(&core_args, STATS_INTERVAL_US as u64);
+
+        // Stop the cores still running once they are out of time
+        elapsed_us += STATS_INTERVAL_US;
+        if elapsed_us >= CORE_TIME_LIMIT_US && !stop_sent {
+            stop_sent = true;
+
+            for (core_id, core_arg) in core_args.iter().enumerate() {
+                if core_arg.status.state() != CoreState::Running {
+                    continue;
+                }
+
+                match command::send(core_arg, Command::Stop, COMMAND_TIMEOUT_US) {
+                    Ok(status) => print!("Core {} stop: {:?}\n", core_id, status),
+                    Err(e)     => print!("Core {} didn't stop\n{:?}\n", core_id, e)
+                }
+            }
+        }
     }
 
+    // Print what the cores printed after the last check
+    log_drain.drain(&core_args);
+
+    print_core_summary(&core_args);
 
     // Get PEI Services via 8 bytes prior to IDT
     panic!("w00t! Finished!");
@@ -8,8 +8,9 @@ pub struct SerialWriter;
 
 impl Write for SerialWriter {
     fn write_str(&mut self, string: &str) -> Result {
-        crate::uefi::output_string(string);
-        crate::uefi::serial::get().write(string);
+        // Printing is best effort, there is nowhere to report a failure to print
+        let _ = crate::uefi::output_string(string);
+        let _ = crate::uefi::serial::get().write(string);
         Ok(())
     }
 }
@@ -17,10 +18,10 @@ impl Write for SerialWriter {
 /// Standard `print!` macro
 #[macro_export]
 macro_rules! print {
-    ($($arg:tt)*) => {
+    ($($arg:tt)*) => {{
         let _ = <$crate::print::SerialWriter as core::fmt::Write>::write_fmt(
             &mut $crate::print::SerialWriter, 
             format_args!($($arg)*)
         );
-    }
+    }}
 }
@@ -0,0 +1,208 @@
+//! Periodic report of the [`Stats`](core_arg::Stats) of every core
+
+use core_arg::{CoreArg, StatName, TimerValue, HistogramValue};
+use core_arg::{MAX_COUNTERS, MAX_TIMERS, HISTOGRAM_BUCKETS};
+
+use crate::NUM_CPUS;
+
+/// Maximum number of distinct stat names of each kind summed across cores. Cores
+/// running the same kernel register the same names, so this only needs to cover a
+/// kernel being reloaded with new stats on some of the cores.
+const MAX_TOTALS: usize = 32;
+
+/// Sum of a stat across every core
+#[derive(Copy, Clone)]
+struct Total<T: Copy> {
+    /// Name of the stat
+    name: StatName,
+
+    /// Sum of the current values
+    value: T,
+
+    /// Sum of the changes since the last report
+    delta: T
+}
+
+/// Fixed size table of [`Total`]s by name
+struct Totals<T: Copy> {
+    /// Totals found so far
+    data: [Option<Total<T>>; MAX_TOTALS],
+
+    /// Number of used entries in `data`
+    len: usize
+}
+
+impl<T: Copy + Default> Totals<T> {
+    /// Create an empty table
+    fn new() -> Self {
+        Self { data: [None; MAX_TOTALS], len: 0 }
+    }
+
+    /// Get the total for `name`, adding it if there is room
+    fn get(&mut self, name: StatName) -> Option<&mut Total<T>> {
+        let existing = self.iter().position(|total| total.name == name);
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                if self.len == MAX_TOTALS {
+                    return None;
+                }
+
+                self.data[self.len] = Some(Total {
+                    name,
+                    value: T::default(),
+                    delta: T::default()
+                });
+                self.len += 1;
+                self.len - 1
+            }
+        };
+
+        self.data[index].as_mut()
+    }
+
+    /// Iterate over the totals in the order their names were first seen
+    fn iter(&self) -> impl Iterator<Item = &Total<T>> {
+        self.data[..self.len].iter().flatten()
+    }
+}
+
+/// Difference between a stat and its value at the last report. Stats start over from
+/// zero when a core is reset, in which case the whole value is new.
+fn delta(value: u64, previous: u64) -> u64 {
+    value.checked_sub(previous).unwrap_or(value)
+}
+
+/// Change of `delta` over `elapsed_us` microseconds as a per second rate
+fn per_second(delta: u64, elapsed_us: u64) -> u64 {
+    if elapsed_us == 0 {
+        return 0;
+    }
+
+    (u128::from(delta) * 1_000_000 / u128::from(elapsed_us)) as u64
+}
+
+/// Remembers the stats of every core between reports to print what changed
+pub struct StatsReport {
+    /// Counter values of each core at the last report, by slot
+    counters: [[u64; MAX_COUNTERS]; NUM_CPUS],
+
+    /// Timer values of each core at the last report, by slot
+    timers: [[TimerValue; MAX_TIMERS]; NUM_CPUS],
+
+    /// Counter changes of each core since the last report, by slot
+    counter_deltas: [[u64; MAX_COUNTERS]; NUM_CPUS],
+
+    /// Timer changes of each core since the last report, by slot
+    timer_deltas: [[TimerValue; MAX_TIMERS]; NUM_CPUS]
+}
+
+impl StatsReport {
+    /// Create a report where every stat is new
+    pub fn new() -> Self {
+        Self {
+            counters:       [[0; MAX_COUNTERS]; NUM_CPUS],
+            timers:         [[TimerValue::default(); MAX_TIMERS]; NUM_CPUS],
+            counter_deltas: [[0; MAX_COUNTERS]; NUM_CPUS],
+            timer_deltas:   [[TimerValue::default(); MAX_TIMERS]; NUM_CPUS]
+        }
+    }
+
+    /// Sum the stats of `core_args` and print the totals, the per second rates over
+    /// the `elapsed_us` microseconds since the last report and the change on each core
+    pub fn print(&mut self, core_args: &[CoreArg], elapsed_us: u64) {
+        let mut counters   = Totals::<u64>::new();
+        let mut timers     = Totals::<TimerValue>::new();
+        let mut histograms = Totals::<HistogramValue>::new();
+
+        for (core_id, core_arg) in core_args.iter().enumerate().take(NUM_CPUS) {
+            for (slot, (name, value)) in core_arg.stats.counters().enumerate() {
+                let change = delta(value, self.counters[core_id][slot]);
+                self.counters[core_id][slot]       = value;
+                self.counter_deltas[core_id][slot] = change;
+
+                if let Some(total) = counters.get(name) {
+                    total.value = total.value.wrapping_add(value);
+                    total.delta = total.delta.wrapping_add(change);
+                }
+            }
+
+            for (slot, (name, value)) in core_arg.stats.timers().enumerate() {
+                let previous = self.timers[core_id][slot];
+                let change = TimerValue {
+                    ticks: delta(value.ticks, previous.ticks),
+                    count: delta(value.count, previous.count)
+                };
+                self.timers[core_id][slot]       = value;
+                self.timer_deltas[core_id][slot] = change;
+
+                if let Some(total) = timers.get(name) {
+                    total.value.ticks = total.value.ticks.wrapping_add(value.ticks);
+                    total.value.count = total.value.count.wrapping_add(value.count);
+                    total.delta.ticks = total.delta.ticks.wrapping_add(change.ticks);
+                    total.delta.count = total.delta.count.wrapping_add(change.count);
+                }
+            }
+
+            for (name, value) in core_arg.stats.histograms() {
+                if let Some(total) = histograms.get(name) {
+                    total.value.add(&value);
+                }
+            }
+        }
+
+        print!("Stats over {}.{:03}s\n", elapsed_us / 1_000_000,
+            elapsed_us / 1000 % 1000);
+
+        for total in counters.iter() {
+            print!("  {}: {} (+{}, {}/s)\n", total.name, total.value, total.delta,
+                per_second(total.delta, elapsed_us));
+
+            for (core_id, core_arg) in core_args.iter().enumerate().take(NUM_CPUS) {
+                let mut names = core_arg.stats.counters().map(|(name, _)| name);
+                if let Some(slot) = names.position(|name| name == total.name) {
+                    let change = self.counter_deltas[core_id][slot];
+                    if change > 0 {
+                        print!("    [{}]: +{}\n", core_id, change);
+                    }
+                }
+            }
+        }
+
+        for total in timers.iter() {
+            let average = total.delta.ticks.checked_div(total.delta.count).unwrap_or(0);
+            print!("  {}: {} calls, {} cycles (+{} calls, {} calls/s, {} cycles/call)\n",
+                total.name, total.value.count, total.value.ticks, total.delta.count,
+                per_second(total.delta.count, elapsed_us), average);
+
+            for (core_id, core_arg) in core_args.iter().enumerate().take(NUM_CPUS) {
+                let mut names = core_arg.stats.timers().map(|(name, _)| name);
+                if let Some(slot) = names.position(|name| name == total.name) {
+                    let change = self.timer_deltas[core_id][slot];
+                    if change.count > 0 {
+                        print!("    [{}]: +{} calls, +{} cycles\n", core_id, change.count,
+                            change.ticks);
+                    }
+                }
+            }
+        }
+
+        for total in histograms.iter() {
+            let count   = total.value.count();
+            let average = total.value.sum.checked_div(count).unwrap_or(0);
+            print!("  {}: {} values, {} average\n", total.name, count, average);
+
+            for (bucket, &count) in total.value.buckets.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+
+                if bucket == HISTOGRAM_BUCKETS - 1 {
+                    print!("    >= 2^{:<2}: {}\n", bucket, count);
+                } else {
+                    print!("    <  2^{:<2}: {}\n", bucket + 1, count);
+                }
+            }
+        }
+    }
+}
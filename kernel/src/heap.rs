@@ -0,0 +1,204 @@
+//! Per-core kernel heap backed by the physical memory in the core's `CoreArg`
+//!
+//! Every core keeps its [`Heap`] in pages taken from its own memory and only
+//! ever touches its own, so allocating takes no locks. The kernel's data sections are
+//! shared by every core, so the heap is found through the per-core `IA32_FS_BASE`.
+//! Small allocations come from power of two size classes carved out of whole pages,
+//! larger ones are taken straight from the core's memory [`RangeSet`].
+
+use core::alloc::{GlobalAlloc, Layout};
+
+use errchain::prelude::*;
+use rangeset::{RangeSet, InclusiveRange};
+use cpu_x86::{X86Cpu, Msr};
+
+use crate::Error;
+
+/// Size of the pages taken from the core's memory
+const PAGE_SIZE: usize = 0x1000;
+
+/// Size of the smallest size class, which fits the free list link
+const MIN_CLASS_SIZE: usize = 16;
+
+/// Number of size classes, from [`MIN_CLASS_SIZE`] doubling up to half a page
+const NUM_CLASSES: usize = 8;
+
+/// Heap of a single core
+struct Heap {
+    /// Physical memory of the core not handed out yet
+    memory: RangeSet,
+
+    /// Address of the first free block of each size class, or zero if there is none.
+    /// Each free block starts with the address of the next one.
+    free: [usize; NUM_CLASSES],
+
+    /// Bytes freed while `memory` had no room left to track them
+    leaked: u64
+}
+
+impl Heap {
+    /// Get the size class for `layout`, or `None` if it is too large for one
+    fn class(layout: Layout) -> Option<usize> {
+        let size = layout.size().max(layout.align()).max(MIN_CLASS_SIZE);
+        let size = size.next_power_of_two();
+        let class = (size / MIN_CLASS_SIZE).trailing_zeros() as usize;
+
+        if class < NUM_CLASSES {
+            Some(class)
+        } else {
+            None
+        }
+    }
+
+    /// Size and alignment of an allocation too large for a size class, in whole pages
+    fn large(layout: Layout) -> (u64, u64) {
+        let size  = (layout.size().max(1) + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+        let align = layout.align().max(PAGE_SIZE);
+        (size as u64, align as u64)
+    }
+
+    /// Split a fresh page into free blocks of `class`
+    ///
+    /// Returns `false` if the core is out of memory
+    fn refill(&mut self, class: usize) -> bool {
+        let page = match self.memory.allocate(PAGE_SIZE as u64, PAGE_SIZE as u64) {
+            Ok(page) => page as usize,
+            Err(_)   => return false
+        };
+
+        // Link the blocks in address order
+        let block_size = MIN_CLASS_SIZE << class;
+        for offset in (0..PAGE_SIZE).step_by(block_size).rev() {
+            let block = page + offset;
+
+            // Safe since the page was just taken from this core's memory
+            unsafe { (block as *mut usize).write(self.free[class]); }
+            self.free[class] = block;
+        }
+
+        true
+    }
+
+    /// Allocate memory for `layout`, or return null if the core is out of memory
+    fn alloc(&mut self, layout: Layout) -> *mut u8 {
+        match Self::class(layout) {
+            Some(class) => {
+                if self.free[class] == 0 && !self.refill(class) {
+                    return core::ptr::null_mut();
+                }
+
+                let block = self.free[class];
+
+                // Safe since free blocks always start with the next free block
+                self.free[class] = unsafe { (block as *const usize).read() };
+                block as *mut u8
+            }
+            None => {
+                let (size, align) = Self::large(layout);
+                self.memory.allocate(size, align)
+                    .map_or(core::ptr::null_mut(), |addr| addr as *mut u8)
+            }
+        }
+    }
+
+    /// Give the memory at `ptr` allocated for `layout` back to the heap
+    fn dealloc(&mut self, ptr: *mut u8, layout: Layout) {
+        match Self::class(layout) {
+            Some(class) => {
+                // Safe since the block was handed out for at least a `usize`
+                unsafe { (ptr as *mut usize).write(self.free[class]); }
+                self.free[class] = ptr as usize;
+            }
+            None => {
+                let (size, _) = Self::large(layout);
+                let start = ptr as u64;
+
+                // The range is leaked if the `RangeSet` has no room left to track it
+                let range = InclusiveRange::new(start, start + size - 1);
+                if self.memory.insert(range).is_err() {
+                    self.leaked += size;
+                    log!("Heap leaked {:#x} bytes at {:#x}, {:#x} bytes in total", size,
+                        start, self.leaked);
+                }
+            }
+        }
+    }
+}
+
+/// Give the heap of the current core the physical `memory` of the core. The heap takes
+/// over every range, leaving `memory` empty.
+///
+/// # Errors
+///
+/// If `memory` has no room for the [`Heap`] itself
+pub fn init(memory: &mut RangeSet) -> Result<()> {
+    let mut taken = *memory;
+
+    // The heap lives in the memory it hands out
+    let size = core::mem::size_of::<Heap>();
+    let size = (size + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+    let addr = taken.allocate(size as u64, PAGE_SIZE as u64).context(&Error::NoMemory)?;
+
+    // Safe since the pages were just taken from this core's memory
+    unsafe {
+        (addr as *mut Heap).write(Heap {
+            memory: taken,
+            free:   [0; NUM_CLASSES],
+            leaked: 0
+        });
+    }
+
+    // Nothing else may hand out the memory of the heap
+    memory.clear();
+    X86Cpu::wrmsr(Msr::FsBase, addr);
+
+    Ok(())
+}
+
+/// Get the number of bytes of the current core's memory not handed out by its heap,
+/// not counting the free blocks of the size classes
+///
+/// # Errors
+///
+/// If the heap of the current core wasn't initialized
+pub fn free_memory() -> Result<u64> {
+    current().context(&Error::NoHeap)?.memory.size()
+}
+
+/// Get the heap of the current core, if it was initialized
+fn current() -> Option<&'static mut Heap> {
+    let heap = X86Cpu::rdmsr(Msr::FsBase) as *mut Heap;
+
+    // Safe since only this core touches its heap. The heap isn't used from interrupts,
+    // so there is never more than one reference to it.
+    unsafe { heap.as_mut() }
+}
+
+/// Allocator handing out memory from the heap of the current core
+struct KernelAllocator;
+
+unsafe impl GlobalAlloc for KernelAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        current().map_or(core::ptr::null_mut(), |heap| heap.alloc(layout))
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let Some(heap) = current() {
+            heap.dealloc(ptr, layout);
+        }
+    }
+}
+
+/// The allocator used by `alloc` in the kernel
+#[global_allocator]
+static ALLOCATOR: KernelAllocator = KernelAllocator;
+
+/// Report a failed allocation to the bootloader through the core's status and stop
+#[alloc_error_handler]
+fn out_of_memory(layout: Layout) -> ! {
+    if let Some(arg) = crate::core_arg() {
+        arg.status.out_of_memory::<X86Cpu>(layout.size(), layout.align());
+    }
+
+    loop {}
+}
@@ -1,64 +1,267 @@
 //! Functionality isolated to each individual core
 
-#![feature(asm)]
-#![feature(global_asm)]
+#![feature(alloc_error_handler)]
 #![no_std]
 #![no_main]
 
 extern crate compiler_builtins;
+extern crate alloc;
+
+#[macro_use] mod print;
+mod heap;
+
+use core::arch::asm;
+
+use alloc::{boxed::Box, vec::Vec};
 
 use errchain::prelude::*;
-use core_arg::CoreArg;
+use core_arg::{CoreArg, CoreState, PanicContext, Command, CommandStatus};
+use core_arg::{CounterId, TimerId, HistogramId};
+use cpu_x86::{X86Cpu, Msr};
+
+/// Get the [`CoreArg`] of the current core. The kernel's data sections are shared by
+/// every core, so the pointer is kept in the per-core `IA32_GS_BASE` instead of a static.
+///
+/// Once the pointer is published, the `CoreArg` is only ever reached through shared
+/// references, so the fields written after that are atomic or in a cell.
+fn core_arg() -> Option<&'static CoreArg> {
+    let arg = X86Cpu::rdmsr(Msr::GsBase) as *const CoreArg;
+    unsafe { arg.as_ref() }
+}
 
 /// Entry point called from the UEFI bootloader
 #[no_mangle]
 pub fn kernel_main(arg: usize) {
-    // Get access to the `CoreArg` passed from the bootlaoder
-    let mut arg = unsafe { &mut *(arg as *mut CoreArg) };
+    // Keep the panic handler away from the `CoreArg` until its layout is checked, and
+    // the allocator away from the heap of a previous kernel
+    X86Cpu::wrmsr(Msr::GsBase, 0);
+    X86Cpu::wrmsr(Msr::FsBase, 0);
+
+    // Get access to the `CoreArg` passed from the bootlaoder, refusing one laid out
+    // by a bootloader built with a different ABI. The mismatch is reported to the
+    // bootloader through `kernel_abi`.
+    let arg = match unsafe { CoreArg::from_ptr(arg as *mut CoreArg) } {
+        Ok(arg) => arg,
+        Err(mismatch) => panic!("CoreArg ABI mismatch: {}", mismatch)
+    };
+
+    // Every frame of the kernel is below this one, so the bootloader can bound its
+    // reads of the stack when unwinding a panic
+    let stack_top: u64;
+    unsafe { asm!("mov {}, rsp", out(reg) stack_top); }
+    arg.stack_top = stack_top;
 
-    // Ensure the correct core and alive address from the CoreArg
+    // Ensure the correct core from the CoreArg
     assert!(arg.core.is_some(), "Core ID not set in CoreArg");
-    assert!(arg.alive_address.is_some(), "Alive address not set in CoreArg");
 
-    // Get access to the core id and address to notify the bootloader that this core is
-    // alive
+    // Get access to the core id
     let core_id = arg.core.unwrap();
-    let alive_address = arg.alive_address.unwrap();
 
     // Set the start time for this core
     arg.stats.start_time = unsafe { core::arch::x86_64::_rdtsc() as usize };
 
-    // Set that this core is alive
-    unsafe { alive_address.write(true); }
+    // Set up everything that needs the `&mut CoreArg` while nothing else can reach it.
+    // Its errors are reported once the `CoreArg` is published.
+    let setup = setup(arg);
+
+    // From here on the `CoreArg` is only used through shared references, so it can be
+    // reached from `print!`, the heap and the panic handler
+    let arg: &CoreArg = arg;
+    X86Cpu::wrmsr(Msr::GsBase, arg as *const CoreArg as u64);
+
+    // Let the bootloader know that the kernel accepted the `CoreArg`
+    arg.status.enter::<X86Cpu>(CoreState::Running);
+
+    match setup.and_then(|stats| try_main(core_id, arg, stats)) {
+        Ok(result) => arg.status.finish::<X86Cpu>(result),
+        Err(e) => {
+            // Hand the chain to the bootloader since there is nowhere to print it from
+            // the kernel
+            arg.error.set(&e);
+            arg.status.enter::<X86Cpu>(CoreState::Failed);
+        }
+    }
+}
+
+/// Stats reported by the bootloader, registered before `try_main`
+#[derive(Debug, Copy, Clone)]
+pub struct KernelStats {
+    /// Number of rounds of work done
+    iterations: CounterId,
+
+    /// Time spent spinning
+    spin: TimerId,
+
+    /// Distribution of the cycles of each spin
+    spin_cycles: HistogramId
+}
+
+/// Hand the memory of this core to the kernel heap and register the stats
+fn setup(arg: &mut CoreArg) -> Result<KernelStats> {
+    heap::init(&mut arg.memory)?;
+
+    Ok(KernelStats {
+        iterations:  arg.stats.register_counter("iterations")?,
+        spin:        arg.stats.register_timer("spin")?,
+        spin_cycles: arg.stats.register_histogram("spin_cycles")?
+    })
+}
+
+/// Errors from the kernel
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    /// The bootloader didn't give this core any memory
+    NoMemory,
+
+    /// The heap of this core wasn't initialized
+    NoHeap,
+
+    /// The `CoreArg` still lists memory after the heap took it over
+    MemoryNotTaken,
+
+    /// Memory from the heap didn't hold what was written to it
+    HeapMismatch,
+}
+
+/// Tests the bootloader can run with [`Command::RunTest`], indexed by their ID
+const TESTS: &[fn(&CoreArg) -> Result<()>] = &[
+    test_memory,
+    test_heap,
+];
+
+/// Check that the bootloader gave this core some memory, all of which the heap owns
+fn test_memory(arg: &CoreArg) -> Result<()> {
+    ensure!(arg.memory.size()? == 0, &Error::MemoryNotTaken);
+    ensure!(heap::free_memory()? > 0, &Error::NoMemory);
+    Ok(())
+}
+
+/// Check that small and large allocations from the heap hold their values
+fn test_heap(_arg: &CoreArg) -> Result<()> {
+    let small: Vec<u64> = (0..64).collect();
+    let large = Box::new([0x41_u8; 0x3000]);
+
+    ensure!(small.iter().sum::<u64>() == 63 * 64 / 2, &Error::HeapMismatch);
+    ensure!(large.iter().all(|&byte| byte == 0x41), &Error::HeapMismatch);
+    Ok(())
+}
+
+/// Whether `try_main` keeps working after handling the commands from the bootloader
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Flow {
+    /// Keep working
+    Continue,
+
+    /// Return from `try_main`
+    Stop
+}
 
-    let res = try_main(core_id, &mut arg);
+/// Handle the commands posted by the bootloader. Blocks while the core is paused.
+fn handle_commands(arg: &CoreArg) -> Flow {
+    let mut paused = false;
 
-    // Set that this core is dead
-    unsafe { alive_address.write(false); }
+    loop {
+        if let Some(pending) = arg.mailbox.poll() {
+            log!("Command {}: {:?}", pending.seq, pending.command);
+
+            let mut flow = Flow::Continue;
+
+            let status = match pending.command {
+                Command::Stop | Command::PrepareReload => {
+                    // Nothing outlives `try_main` yet, so the kernel can be reloaded
+                    // as soon as it returns
+                    flow = Flow::Stop;
+                    CommandStatus::Ok
+                }
+                Command::Pause => {
+                    paused = true;
+                    CommandStatus::Ok
+                }
+                Command::Resume if paused => {
+                    paused = false;
+                    CommandStatus::Ok
+                }
+                Command::Resume => CommandStatus::InvalidState,
+                Command::ResetStats => {
+                    arg.stats.clear();
+                    CommandStatus::Ok
+                }
+                Command::RunTest(id) => match TESTS.get(id as usize) {
+                    Some(test) if test(arg).is_ok() => CommandStatus::Ok,
+                    Some(_) => CommandStatus::Failed,
+                    None    => CommandStatus::UnknownTest
+                }
+            };
+
+            arg.mailbox.respond(pending, status);
+
+            if flow == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        if !paused {
+            return Flow::Continue;
+        }
 
-    if let Err(e) = res {
-        panic!("Top kernel main\n{:?}\n", e);
+        unsafe { asm!("pause") }
     }
 }
 
 /// Actual main entry point for this individual core in order to wrap the `Result`
-pub fn try_main(core_id: usize, arg: &mut CoreArg) -> Result<usize> {
+pub fn try_main(core_id: usize, arg: &CoreArg, stats: KernelStats) -> Result<usize> {
+    let KernelStats { iterations, spin, spin_cycles } = stats;
+
+    log!("Core {} running {}", core_id, arg.build_id);
+
+    // Size of the work, configurable per core by the bootloader
+    let rounds     = arg.args.parse_or::<usize>("iterations", 10)?;
+    let spin_count = arg.args.parse_or::<usize>("spin", 0x7ff_ffff)?;
+
+    log!("Core {} arguments: {:?}", core_id, arg.args);
+
     let mut sum = 0;
 
-    for _ in 0..10 {
-        for _ in 0..0x7ff_ffff {
-            unsafe { asm!("pause") }
+    for _ in 0..rounds {
+        if handle_commands(arg) == Flow::Stop {
+            break;
+        }
+
+        {
+            let _timer = arg.stats.time::<X86Cpu>(spin).with_histogram(spin_cycles);
+
+            for _ in 0..spin_count {
+                unsafe { asm!("pause") }
+            }
         }
 
         sum += core_id;
-        arg.stats.start_time = unsafe { core::arch::x86_64::_rdtsc() as usize };
+        arg.stats.increment(iterations);
     }
 
+    log!("Core {} done with sum {}", core_id, sum);
+
     Ok(sum)
 }
 
 /// Panic handler
 #[panic_handler]
-fn panic(_info: &core::panic::PanicInfo) -> ! {
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    let rip: u64;
+    let rsp: u64;
+    let rbp: u64;
+
+    // Capture the registers needed by the bootloader to unwind this core
+    unsafe {
+        asm!("lea {}, [rip]", out(reg) rip);
+        asm!("mov {}, rsp", out(reg) rsp);
+        asm!("mov {}, rbp", out(reg) rbp);
+    }
+
+    if let Some(arg) = core_arg() {
+        arg.panic_context.set(Some(PanicContext { rip, rsp, rbp }));
+        arg.status.panicked::<X86Cpu>(info.location(), &info.message());
+    }
+
     loop {}
 }
@@ -0,0 +1,45 @@
+//! Provides [`print!`] and [`log!`] macros writing into the log ring of the
+//! [`CoreArg`](core_arg::CoreArg) of the current core, drained to serial by the
+//! bootloader
+
+use core::fmt::{Arguments, Write};
+
+use errchain::TruncatingWriter;
+
+/// Maximum number of bytes of a single `print!`. Longer output is truncated.
+const LINE_SIZE: usize = 256;
+
+/// Format `args` and write them to the log ring of the current core. Output is
+/// dropped if the ring is full or the core has no `CoreArg` yet.
+pub fn _print(args: Arguments) {
+    let arg = match crate::core_arg() {
+        Some(arg) => arg,
+        None      => return
+    };
+
+    // Format the whole line first so that it reaches the ring in one piece
+    let mut line   = [0u8; LINE_SIZE];
+    let mut writer = TruncatingWriter::new(&mut line);
+
+    // Formatting into a `TruncatingWriter` only truncates, it never fails
+    let _ = writer.write_fmt(args);
+    let len = writer.len();
+
+    arg.log.write(&line[..len]);
+}
+
+/// Standard `print!` macro
+#[macro_export]
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::print::_print(format_args!($($arg)*))
+    }
+}
+
+/// `print!` with a trailing newline
+#[macro_export]
+macro_rules! log {
+    ($($arg:tt)*) => {
+        $crate::print::_print(format_args!("{}\n", format_args!($($arg)*)))
+    }
+}
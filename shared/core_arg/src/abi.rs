@@ -0,0 +1,270 @@
+//! Header identifying the layout of a [`CoreArg`](crate::CoreArg)
+//!
+//! The bootloader and the kernel are built separately, so a hot reloaded kernel may
+//! disagree with the bootloader on the layout of `CoreArg`. The header is the first
+//! field of `CoreArg` and never changes, so the kernel can always read it and refuse a
+//! `CoreArg` it doesn't understand.
+
+/// Magic value at the start of every [`AbiHeader`]
+pub const ABI_MAGIC: u64 = u64::from_le_bytes(*b"CORE_ARG");
+
+/// Version of the `CoreArg` ABI. Bump this when the meaning of a field changes without
+/// changing the layout.
+pub const ABI_VERSION: u32 = 1;
+
+/// FNV-1a hash usable in `const` to fingerprint layouts
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    /// Start a new hash
+    pub(crate) const fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    /// Add the bytes of `val` to the hash, skipping whitespace since the spacing of
+    /// `stringify!` varies between compilers
+    pub(crate) const fn str(mut self, val: &str) -> Self {
+        let bytes = val.as_bytes();
+
+        let mut index = 0;
+        while index < bytes.len() {
+            if !bytes[index].is_ascii_whitespace() {
+                self.0 = (self.0 ^ bytes[index] as u64).wrapping_mul(0x100_0000_01b3);
+            }
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Add `val` to the hash
+    pub(crate) const fn usize(self, val: usize) -> Self {
+        self.u64(val as u64)
+    }
+
+    /// Add `val` to the hash
+    pub(crate) const fn u64(mut self, val: u64) -> Self {
+        let bytes = val.to_le_bytes();
+
+        let mut index = 0;
+        while index < bytes.len() {
+            self.0 = (self.0 ^ bytes[index] as u64).wrapping_mul(0x100_0000_01b3);
+            index += 1;
+        }
+
+        self
+    }
+
+    /// Get the hash
+    pub(crate) const fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// Define a `repr(C)` struct with a `LAYOUT` constant fingerprinting the name, type,
+/// size and alignment of every field in order, along with the size and alignment of
+/// the struct. Those determine every offset of a `repr(C)` struct, so two builds with
+/// the same `LAYOUT` agree on where each field is.
+///
+/// The types listed after `nested:` must be defined with `abi_layout!` as well. Their
+/// `LAYOUT` is added to the fingerprint, so reordering the fields of a field's type
+/// changes the `LAYOUT` of the struct holding it.
+macro_rules! abi_layout {
+    (
+        $(#[$attr:meta])* $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])* $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+
+        $(nested: $($nested:ty),+ $(,)?)?
+    ) => {
+        $(#[$attr])* $vis struct $name {
+            $(
+                $(#[$field_attr])* $field_vis $field: $ty
+            ),*
+        }
+
+        impl $name {
+            /// Fingerprint of the layout of this struct
+            pub const LAYOUT: u64 = $crate::abi::Fnv1a::new()
+                .str(stringify!($name))
+                .usize(core::mem::size_of::<$name>())
+                .usize(core::mem::align_of::<$name>())
+                $(
+                    .str(stringify!($field))
+                    .str(stringify!($ty))
+                    .usize(core::mem::size_of::<$ty>())
+                    .usize(core::mem::align_of::<$ty>())
+                )*
+                $($(
+                    .u64(<$nested>::LAYOUT)
+                )+)?
+                .finish();
+        }
+    };
+}
+
+/// Identifies the layout of a `CoreArg`. The layout of this struct must never change.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct AbiHeader {
+    /// Always [`ABI_MAGIC`]
+    pub magic: u64,
+
+    /// The [`ABI_VERSION`] of the build
+    pub version: u32,
+
+    /// Size of the `CoreArg` of the build in bytes
+    pub size: u32,
+
+    /// The [`CoreArg::LAYOUT`](crate::CoreArg::LAYOUT) of the build
+    pub layout: u64
+}
+
+impl AbiHeader {
+    /// Create the header of the `CoreArg` this binary was built with
+    pub const fn current() -> Self {
+        Self {
+            magic:   ABI_MAGIC,
+            version: ABI_VERSION,
+            size:    core::mem::size_of::<crate::CoreArg>() as u32,
+            layout:  crate::CoreArg::LAYOUT
+        }
+    }
+
+    /// Create an all zero header, used before anything was reported
+    pub const fn empty() -> Self {
+        Self { magic: 0, version: 0, size: 0, layout: 0 }
+    }
+
+    /// Returns `true` if nothing was written to this header
+    pub fn is_empty(&self) -> bool {
+        *self == Self::empty()
+    }
+
+    /// Check that this header matches the `CoreArg` this binary was built with
+    ///
+    /// # Errors
+    ///
+    /// The first field that differs from [`AbiHeader::current`]
+    pub fn check(&self) -> Result<(), AbiMismatch> {
+        self.compare(&Self::current())
+    }
+
+    /// Check that this header matches `expected`
+    ///
+    /// # Errors
+    ///
+    /// The first field that differs from `expected`
+    pub fn compare(&self, expected: &AbiHeader) -> Result<(), AbiMismatch> {
+        if self.magic != expected.magic {
+            Err(AbiMismatch::Magic { found: self.magic })
+        } else if self.version != expected.version {
+            Err(AbiMismatch::Version { found: self.version, expected: expected.version })
+        } else if self.size != expected.size {
+            Err(AbiMismatch::Size { found: self.size, expected: expected.size })
+        } else if self.layout != expected.layout {
+            Err(AbiMismatch::Layout { found: self.layout, expected: expected.layout })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The way an [`AbiHeader`] differs from the expected one
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AbiMismatch {
+    /// The header doesn't start with [`ABI_MAGIC`], so it isn't a `CoreArg` at all
+    Magic { found: u64 },
+
+    /// The [`ABI_VERSION`]s differ
+    Version { found: u32, expected: u32 },
+
+    /// The sizes of the `CoreArg`s differ
+    Size { found: u32, expected: u32 },
+
+    /// The layout fingerprints differ
+    Layout { found: u64, expected: u64 }
+}
+
+impl core::fmt::Display for AbiMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            AbiMismatch::Magic { found } => {
+                write!(f, "magic {:#018x} is not a CoreArg", found)
+            }
+            AbiMismatch::Version { found, expected } => {
+                write!(f, "version {}, expected {}", found, expected)
+            }
+            AbiMismatch::Size { found, expected } => {
+                write!(f, "size {:#x}, expected {:#x}", found, expected)
+            }
+            AbiMismatch::Layout { found, expected } => {
+                write!(f, "layout {:#018x}, expected {:#018x}", found, expected)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::boxed::Box;
+
+    use super::*;
+    use crate::CoreArg;
+
+    #[test]
+    fn test_compare() {
+        let current = AbiHeader::current();
+        assert_eq!(current.check(), Ok(()));
+        assert!(AbiHeader::empty().is_empty());
+        assert!(!current.is_empty());
+
+        let cases = [
+            (AbiHeader { magic: 1, ..current }, AbiMismatch::Magic { found: 1 }),
+            (AbiHeader { version: ABI_VERSION + 1, ..current },
+                AbiMismatch::Version { found: ABI_VERSION + 1, expected: ABI_VERSION }),
+            (AbiHeader { size: current.size + 8, ..current },
+                AbiMismatch::Size { found: current.size + 8, expected: current.size }),
+            (AbiHeader { layout: !current.layout, ..current },
+                AbiMismatch::Layout { found: !current.layout, expected: current.layout })
+        ];
+
+        for (header, mismatch) in cases {
+            assert_eq!(header.check(), Err(mismatch));
+            assert!(current.compare(&header).is_err());
+        }
+
+        // Only the first field that differs is reported
+        let header = AbiHeader { version: 0, size: 0, ..current };
+        assert_eq!(header.check(),
+            Err(AbiMismatch::Version { found: 0, expected: ABI_VERSION }));
+    }
+
+    #[test]
+    fn test_from_ptr() {
+        let mut arg = Box::new(CoreArg::new());
+        assert!(unsafe { CoreArg::from_ptr(&mut *arg) }.is_ok());
+        assert!(arg.kernel_abi.is_empty());
+
+        // A kernel rejecting the layout reports its own header
+        arg.abi.layout ^= 1;
+        let mismatch = unsafe { CoreArg::from_ptr(&mut *arg) }.err();
+        assert!(matches!(mismatch, Some(AbiMismatch::Layout { .. })));
+        assert_eq!(arg.kernel_abi, AbiHeader::current());
+
+        // The report of the previous kernel doesn't outlive a reset
+        arg.build_id.set_pdb_path(b"kernel.pdb");
+        arg.reset();
+        assert!(arg.kernel_abi.is_empty());
+        assert!(arg.build_id.pdb_path().is_empty());
+
+        // Without the magic the memory isn't a `CoreArg`, so nothing is written to it
+        arg.abi.magic = 0;
+        let mismatch = unsafe { CoreArg::from_ptr(&mut *arg) }.err();
+        assert_eq!(mismatch, Some(AbiMismatch::Magic { found: 0 }));
+        assert!(arg.kernel_abi.is_empty());
+    }
+}
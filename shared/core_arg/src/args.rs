@@ -0,0 +1,284 @@
+//! Key/value arguments passed to the kernel, like a command line
+//!
+//! Arguments are stored as whitespace separated `key=value` entries. An entry without a
+//! `=` is a flag with an empty value. When a key is given more than once, the last entry
+//! wins, so the bootloader appends the arguments specific to a core after the ones
+//! shared by every core.
+
+use core::str::FromStr;
+
+use errchain::prelude::*;
+
+/// Maximum number of bytes of the arguments of a core
+pub const MAX_KERNEL_ARGS_LEN: usize = 1024;
+
+/// Errors from building or parsing [`KernelArgs`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KernelArgsError {
+    /// The arguments don't fit in [`MAX_KERNEL_ARGS_LEN`] bytes
+    TooLong,
+
+    /// A key is empty or a key or value holds whitespace or a key holds `=`
+    InvalidEntry,
+
+    /// A value couldn't be parsed as the requested type
+    InvalidValue,
+
+    /// A required argument wasn't given
+    Missing,
+}
+
+/// Split `text` at the first `separator`
+fn split(text: &str, separator: char) -> Option<(&str, &str)> {
+    let index = text.find(separator)?;
+    Some((&text[..index], &text[index + separator.len_utf8()..]))
+}
+
+abi_layout! {
+/// Arguments of the kernel on a core
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct KernelArgs {
+    /// The entries, separated by newlines
+    data: [u8; MAX_KERNEL_ARGS_LEN],
+
+    /// Number of valid bytes in `data`
+    len: usize
+}
+}
+
+impl KernelArgs {
+    /// Create an empty set of arguments
+    pub const fn new() -> Self {
+        Self {
+            data: [0; MAX_KERNEL_ARGS_LEN],
+            len:  0
+        }
+    }
+
+    /// Remove every argument
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Build the arguments of core `core_id` from a config file. Entries are whitespace
+    /// separated `key=value` pairs and `#` starts a comment until the end of the line.
+    /// An entry prefixed with `N.`, such as `3.iterations=20`, only applies to core `N`
+    /// and overrides the entries for every core.
+    ///
+    /// # Errors
+    ///
+    /// If an entry is invalid or the arguments of the core don't fit
+    pub fn from_config(config: &str, core_id: usize) -> Result<Self> {
+        let mut args = Self::new();
+
+        // Entries for every core, then the entries for this core so that they win
+        for specific in [false, true].iter().copied() {
+            let entries = config.lines()
+                .map(|line| line.split('#').next().unwrap_or(""))
+                .flat_map(str::split_whitespace);
+
+            for entry in entries {
+                let prefixed = split(entry, '.')
+                    .and_then(|(core, rest)| Some((core.parse::<usize>().ok()?, rest)));
+
+                let (core, entry) = match prefixed {
+                    Some((core, rest)) => (Some(core), rest),
+                    None               => (None, entry)
+                };
+
+                let applies = match core {
+                    Some(core) => specific && core == core_id,
+                    None       => !specific
+                };
+
+                if applies {
+                    let (key, value) = split(entry, '=').unwrap_or((entry, ""));
+                    context_fmt!(args.push(key, value), "kernel argument {}", entry)?;
+                }
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Add the argument `key` with `value` after the existing entries. An earlier entry
+    /// for `key` is kept, but [`KernelArgs::get`] returns the value added last.
+    ///
+    /// # Errors
+    ///
+    /// * `key` is empty or holds whitespace or `=`, or `value` holds whitespace
+    /// * The entry doesn't fit in [`MAX_KERNEL_ARGS_LEN`] bytes
+    pub fn push(&mut self, key: &str, value: &str) -> Result<()> {
+        let valid_key = !key.is_empty()
+            && !key.contains(|c: char| c.is_whitespace() || c == '=');
+        ensure!(valid_key && !value.contains(char::is_whitespace),
+            &KernelArgsError::InvalidEntry);
+
+        // Separator, key, `=` and value
+        let needed = usize::from(self.len > 0) + key.len() + 1 + value.len();
+        ensure!(needed <= MAX_KERNEL_ARGS_LEN - self.len, &KernelArgsError::TooLong);
+
+        if self.len > 0 {
+            self.append(b"\n");
+        }
+        self.append(key.as_bytes());
+        self.append(b"=");
+        self.append(value.as_bytes());
+
+        Ok(())
+    }
+
+    /// Append `bytes` to the entries, which must have room for them
+    fn append(&mut self, bytes: &[u8]) {
+        self.data[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    /// Get the entries as text
+    pub fn as_str(&self) -> &str {
+        let len = core::cmp::min(self.len, MAX_KERNEL_ARGS_LEN);
+        core::str::from_utf8(&self.data[..len]).unwrap_or("")
+    }
+
+    /// Iterate over the key and value of every entry in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.as_str().split_whitespace()
+            .map(|entry| split(entry, '=').unwrap_or((entry, "")))
+    }
+
+    /// Get the value of `key`, if it was given
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().filter(|(name, _)| *name == key).map(|(_, value)| value).last()
+    }
+
+    /// Returns `true` if `key` was given without a value or with `1` or `true`
+    pub fn flag(&self, key: &str) -> bool {
+        matches!(self.get(key), Some("") | Some("1") | Some("true"))
+    }
+
+    /// Parse the value of `key` as a `T`, if it was given
+    ///
+    /// # Errors
+    ///
+    /// If the value of `key` isn't a valid `T`
+    pub fn parse<T: FromStr>(&self, key: &str) -> Result<Option<T>> {
+        let value = match self.get(key) {
+            Some(value) => value,
+            None        => return Ok(None)
+        };
+
+        let parsed = value.parse::<T>().ok().context(&KernelArgsError::InvalidValue);
+        context_fmt!(parsed, "kernel argument {}={}", key, value).map(Some)
+    }
+
+    /// Parse the value of `key` as a `T`, or get `default` if it wasn't given
+    ///
+    /// # Errors
+    ///
+    /// If the value of `key` isn't a valid `T`
+    pub fn parse_or<T: FromStr>(&self, key: &str, default: T) -> Result<T> {
+        Ok(self.parse(key)?.unwrap_or(default))
+    }
+
+    /// Parse the value of `key` as a `T`
+    ///
+    /// # Errors
+    ///
+    /// If `key` wasn't given or its value isn't a valid `T`
+    pub fn require<T: FromStr>(&self, key: &str) -> Result<T> {
+        let parsed = self.parse(key)?.context(&KernelArgsError::Missing);
+        context_fmt!(parsed, "kernel argument {}", key)
+    }
+}
+
+impl Default for KernelArgs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for KernelArgs {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config() {
+        let config = "iterations=10 spin=5 # comment with=entries\n\
+                      verbose\n\
+                      # 2.iterations=99\n\
+                      1.iterations=20 1.name=one 12.spin=7\n";
+
+        let args = KernelArgs::from_config(config, 0).unwrap();
+        assert_eq!(args.get("iterations"), Some("10"));
+        assert_eq!(args.get("spin"), Some("5"));
+        assert_eq!(args.get("with"), None);
+        assert_eq!(args.get("name"), None);
+        assert!(args.flag("verbose"));
+
+        // Entries for a core win over the ones for every core, wherever they are
+        let args = KernelArgs::from_config(config, 1).unwrap();
+        assert_eq!(args.get("iterations"), Some("20"));
+        assert_eq!(args.get("name"), Some("one"));
+        assert_eq!(args.get("spin"), Some("5"));
+
+        let args = KernelArgs::from_config(config, 12).unwrap();
+        assert_eq!(args.get("spin"), Some("7"));
+        assert_eq!(args.get("iterations"), Some("10"));
+
+        // Commented out entries don't apply
+        let args = KernelArgs::from_config(config, 2).unwrap();
+        assert_eq!(args.get("iterations"), Some("10"));
+
+        let err = KernelArgs::from_config("=5", 0).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(KernelArgsError::InvalidEntry)));
+    }
+
+    #[test]
+    fn test_push() {
+        let mut args = KernelArgs::new();
+        args.push("spin", "1").unwrap();
+        args.push("spin", "2").unwrap();
+        assert_eq!(args.get("spin"), Some("2"));
+        assert_eq!(args.as_str(), "spin=1\nspin=2");
+
+        for (key, value) in [("", "1"), ("a b", "1"), ("a=b", "1"), ("a", "1 2")] {
+            let err = args.push(key, value).unwrap_err();
+            assert!(matches!(err.downcast_ref(), Some(KernelArgsError::InvalidEntry)));
+        }
+
+        // The separator counts towards the length
+        let value = [b'x'; MAX_KERNEL_ARGS_LEN - 17];
+        let value = core::str::from_utf8(&value).unwrap();
+        let err = args.push("key", value).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(KernelArgsError::TooLong)));
+
+        args.push("key", &value[1..]).unwrap();
+        assert_eq!(args.as_str().len(), MAX_KERNEL_ARGS_LEN);
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = KernelArgs::from_config("count=12 ratio=half", 0).unwrap();
+
+        assert_eq!(args.parse::<u32>("count").unwrap(), Some(12));
+        assert_eq!(args.parse::<u32>("other").unwrap(), None);
+        assert_eq!(args.parse_or::<u32>("count", 3).unwrap(), 12);
+        assert_eq!(args.parse_or::<u32>("other", 3).unwrap(), 3);
+        assert_eq!(args.require::<u32>("count").unwrap(), 12);
+
+        let err = args.require::<u32>("other").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(KernelArgsError::Missing)));
+
+        for err in [args.parse_or::<u32>("ratio", 3).unwrap_err(),
+                args.require::<u32>("ratio").unwrap_err()] {
+            assert!(matches!(err.downcast_ref(), Some(KernelArgsError::InvalidValue)));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Identity of the kernel build a core is running
+
+use pe::debug::Guid;
+
+/// Maximum number of bytes of the PDB path kept in a [`BuildId`]
+pub const MAX_PDB_PATH: usize = 64;
+
+abi_layout! {
+/// Build information of the kernel parsed from its debug directory by the bootloader.
+/// Used to match a crash report from a core to the kernel binary that produced it.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct BuildId {
+    /// The COFF timestamp of the kernel image
+    pub timestamp: u32,
+
+    /// The CodeView GUID of the kernel image
+    pub guid: Guid,
+
+    /// The CodeView age of the kernel image
+    pub age: u32,
+
+    /// The end of the PDB path of the kernel image
+    pdb_path: [u8; MAX_PDB_PATH],
+
+    /// Number of valid bytes in `pdb_path`
+    pdb_path_len: usize
+}
+}
+
+impl BuildId {
+    /// Create an empty [`BuildId`] for kernels without debug information
+    pub const fn new() -> Self {
+        Self {
+            timestamp:    0,
+            guid:         Guid([0; 16]),
+            age:          0,
+            pdb_path:     [0; MAX_PDB_PATH],
+            pdb_path_len: 0
+        }
+    }
+
+    /// Set the PDB path of the kernel. Only the last [`MAX_PDB_PATH`] bytes are kept
+    /// since the end of the path holds the file name.
+    pub fn set_pdb_path(&mut self, path: &[u8]) {
+        let mut start = path.len().saturating_sub(MAX_PDB_PATH);
+
+        // Don't keep half of a character of a UTF-8 path
+        if let Ok(text) = core::str::from_utf8(path) {
+            while !text.is_char_boundary(start) {
+                start += 1;
+            }
+        }
+
+        let path = &path[start..];
+
+        self.pdb_path[..path.len()].copy_from_slice(path);
+        self.pdb_path_len = path.len();
+    }
+
+    /// Get the stored PDB path of the kernel
+    pub fn pdb_path(&self) -> &[u8] {
+        &self.pdb_path[..self.pdb_path_len]
+    }
+}
+
+impl Default for BuildId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Display for BuildId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{} age {} timestamp {:#010x} pdb {}", self.guid, self.age,
+            self.timestamp, core::str::from_utf8(self.pdb_path()).unwrap_or("<invalid>"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdb_path() {
+        let mut build_id = BuildId::new();
+
+        build_id.set_pdb_path(b"C:\\kernel.pdb");
+        assert_eq!(build_id.pdb_path(), b"C:\\kernel.pdb");
+
+        // The last 64 bytes of the path start in the middle of the 'é'
+        let path = "aé/target/x86_64-pc-windows-msvc/release/deps/kernel-01234567.pdb";
+        assert_eq!(path.len(), MAX_PDB_PATH + 2);
+
+        build_id.set_pdb_path(path.as_bytes());
+        assert_eq!(build_id.pdb_path(), &path.as_bytes()[3..]);
+        assert!(core::str::from_utf8(build_id.pdb_path()).is_ok());
+    }
+}
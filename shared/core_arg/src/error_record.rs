@@ -0,0 +1,109 @@
+//! Error returned by the kernel on a core, kept in a buffer owned by the bootloader
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use errchain::{ErrorChain, EncodedChain};
+
+/// Maximum number of bytes of an encoded [`ErrorChain`] kept in an [`ErrorRecord`]
+pub const MAX_ERROR_RECORD_LEN: usize = 1024;
+
+/// Value of `len` while an error is being recorded
+const WRITING: usize = usize::MAX;
+
+abi_layout! {
+/// The [`ErrorChain`] a core failed with, encoded with [`ErrorChain::encode`] so that
+/// it can be decoded and printed by the bootloader or a host tool. An error is only
+/// recorded once, so the record can be written through a shared reference.
+#[repr(C)]
+pub struct ErrorRecord {
+    /// Encoded error chain, only written while `len` is zero
+    data: UnsafeCell<[u8; MAX_ERROR_RECORD_LEN]>,
+
+    /// Number of valid bytes in `data`, published with `Release` once written
+    len: AtomicUsize
+}
+}
+
+impl ErrorRecord {
+    /// Create an empty [`ErrorRecord`]
+    pub const fn new() -> Self {
+        Self {
+            data: UnsafeCell::new([0; MAX_ERROR_RECORD_LEN]),
+            len:  AtomicUsize::new(0)
+        }
+    }
+
+    /// Record the given error, unless one is already recorded. Links of the chain that
+    /// don't fit are dropped.
+    pub fn set(&self, error: &ErrorChain) {
+        // Claim the empty record, so that nothing borrows `data` while it is written
+        if self.len.compare_exchange(0, WRITING, Ordering::Acquire, Ordering::Relaxed)
+                .is_err() {
+            return;
+        }
+
+        // Safe since `data` is only borrowed by `chain` once `len` is published
+        let data = unsafe { &mut *self.data.get() };
+        let len  = error.encode(data).unwrap_or(0);
+
+        self.len.store(len, Ordering::Release);
+    }
+
+    /// Remove the recorded error
+    pub fn clear(&mut self) {
+        *self.len.get_mut() = 0;
+    }
+
+    /// Get the recorded error, if any
+    pub fn chain(&self) -> Option<EncodedChain<'_>> {
+        let len = self.len.load(Ordering::Acquire);
+        if len == 0 || len == WRITING {
+            return None;
+        }
+
+        // Safe since `data` isn't written again until the record is cleared
+        let data = unsafe { &*self.data.get() };
+        EncodedChain::decode(data.get(..len)?)
+    }
+}
+
+impl Default for ErrorRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for ErrorRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.chain() {
+            Some(chain) => write!(f, "{:?}", chain),
+            None        => write!(f, "None")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_once() {
+        let mut record = ErrorRecord::new();
+        assert!(record.chain().is_none());
+
+        record.set(&ErrorChain::new_with_debug("first.rs", 1, &"first"));
+        record.set(&ErrorChain::new_with_debug("second.rs", 2, &"second"));
+
+        // The first error is kept
+        let message = record.chain().unwrap().iter().next().unwrap();
+        assert_eq!(message.file, "first.rs");
+        assert_eq!(message.line, 1);
+
+        record.clear();
+        assert!(record.chain().is_none());
+
+        record.set(&ErrorChain::new_with_debug("second.rs", 2, &"second"));
+        assert_eq!(record.chain().unwrap().iter().next().unwrap().file, "second.rs");
+    }
+}
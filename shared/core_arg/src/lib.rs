@@ -1,31 +1,111 @@
 
 #![no_std]
+// The `ErrorChain` is stored inline since there is no allocator to box it with
+#![allow(clippy::result_large_err)]
+
+use core::cell::Cell;
 
 use rangeset::{RangeSet, InclusiveRange};
 use errchain::prelude::*;
 use global_types::PhysAddr;
 
+#[macro_use]
+mod abi;
+pub use abi::{AbiHeader, AbiMismatch, ABI_MAGIC, ABI_VERSION};
+
 mod stats;
-pub use stats::Stats;
+pub use stats::{Stats, StatsError, StatName, ScopedTimer};
+pub use stats::{CounterId, TimerId, HistogramId, TimerValue, HistogramValue};
+pub use stats::{MAX_COUNTERS, MAX_TIMERS, MAX_HISTOGRAMS};
+pub use stats::{MAX_STAT_NAME, HISTOGRAM_BUCKETS};
 
-/// Argument passed to the kernel from UEFI
+mod state;
+pub use state::{CoreState, CoreStatus, MAX_PANIC_MESSAGE_LEN};
+
+mod mailbox;
+pub use mailbox::{Mailbox, MailboxError, Command, CommandStatus, PendingCommand};
+
+mod log;
+pub use log::{LogRing, LogRingError, LOG_RING_SIZE};
+
+mod args;
+pub use args::{KernelArgs, KernelArgsError, MAX_KERNEL_ARGS_LEN};
+
+mod build_id;
+pub use build_id::BuildId;
+
+mod error_record;
+pub use error_record::{ErrorRecord, MAX_ERROR_RECORD_LEN};
+
+abi_layout! {
+/// Registers captured by the kernel's panic handler. Used by the bootloader to unwind
+/// the stack of the panicking core.
 #[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct PanicContext {
+    /// Instruction pointer in the panic handler
+    pub rip: u64,
+
+    /// Stack pointer in the panic handler
+    pub rsp: u64,
+
+    /// Frame pointer in the panic handler
+    pub rbp: u64
+}
+}
+
+abi_layout! {
+/// Argument passed to the kernel from UEFI
+#[derive(Debug)]
 #[repr(C, align(4096))]
 pub struct CoreArg {
+    /// Layout of this struct in the bootloader. Must stay the first field.
+    pub abi: AbiHeader,
+
+    /// Layout of this struct in the kernel, written by a kernel which rejected `abi`.
+    /// Must stay the second field.
+    pub kernel_abi: AbiHeader,
+
     /// ID for this core
     pub core: Option<usize>,
 
     /// [`RangeSet`] containing the physical memory available to this core
     pub memory: RangeSet,
 
-    /// Physical address of the alive bit to set in the bootloader
-    pub alive_address: Option<*mut bool>,
+    /// Lifecycle of the kernel on this core
+    pub status: CoreStatus,
+
+    /// Commands from the bootloader to the kernel on this core
+    pub mailbox: Mailbox,
+
+    /// Text printed by the kernel on this core
+    pub log: LogRing,
+
+    /// Arguments of the kernel on this core
+    pub args: KernelArgs,
 
     /// The [`PhysAddr`] of the page table specific for this core
     pub page_table: PhysAddr,
 
     /// The [`Stats`] for this core
-    pub stats: Stats
+    pub stats: Stats,
+
+    /// Registers of this core at the time of a panic
+    pub panic_context: Cell<Option<PanicContext>>,
+
+    /// Stack pointer of `kernel_main` on this core. The bootloader only reads the stack
+    /// between the stack pointer of a panic and this address while unwinding.
+    pub stack_top: u64,
+
+    /// The build of the kernel running on this core
+    pub build_id: BuildId,
+
+    /// The error this core's kernel returned, if any
+    pub error: ErrorRecord
+}
+
+nested: CoreStatus, Mailbox, LogRing, KernelArgs, Stats, PanicContext, BuildId,
+    ErrorRecord
 }
 
 impl CoreArg {
@@ -33,18 +113,67 @@ impl CoreArg {
     /// `const`
     pub const fn new() -> Self {
         CoreArg {
+            abi:           AbiHeader::current(),
+            kernel_abi:    AbiHeader::empty(),
             core:          None,
             memory:        RangeSet::new(),
-            alive_address: None,
+            status:        CoreStatus::new(),
+            mailbox:       Mailbox::new(),
+            log:           LogRing::new(),
+            args:          KernelArgs::new(),
             page_table:    PhysAddr(0),
-            stats:         Stats::new()
+            stats:         Stats::new(),
+            panic_context: Cell::new(None),
+            stack_top:     0,
+            build_id:      BuildId::new(),
+            error:         ErrorRecord::new()
         }
     }
 
+    /// Get the [`CoreArg`] at `arg` after checking that it has the layout this binary
+    /// was built with. On a mismatch the header of this binary is written to
+    /// `kernel_abi` for the bootloader to report, unless `arg` isn't a `CoreArg` at
+    /// all.
+    ///
+    /// # Errors
+    ///
+    /// If the [`AbiHeader`] at `arg` doesn't match [`AbiHeader::current`]
+    ///
+    /// # Safety
+    ///
+    /// `arg` must be valid for reads and writes of the two [`AbiHeader`]s, and of a
+    /// whole [`CoreArg`] for `'a` if they match
+    pub unsafe fn from_ptr<'a>(arg: *mut CoreArg)
+            -> core::result::Result<&'a mut CoreArg, AbiMismatch> {
+        // The headers are at the same offsets in every layout
+        let abi = core::ptr::read_volatile(core::ptr::addr_of!((*arg).abi));
+
+        if let Err(mismatch) = abi.check() {
+            if !matches!(mismatch, AbiMismatch::Magic { .. }) {
+                core::ptr::write_volatile(core::ptr::addr_of_mut!((*arg).kernel_abi),
+                    AbiHeader::current());
+            }
+
+            return Err(mismatch);
+        }
+
+        Ok(&mut *arg)
+    }
+
     /// Reset the core arg back to the original state
     pub fn reset(&mut self) {
+        self.kernel_abi = AbiHeader::empty();
         self.core = None;
         self.memory.clear();
+        self.panic_context.set(None);
+        self.stack_top = 0;
+        self.status.reset();
+        self.mailbox.reset();
+        self.log.reset();
+        self.args.clear();
+        self.stats.reset();
+        self.error.clear();
+        self.build_id = BuildId::new();
     }
 
     /// Set the core id for this core
@@ -52,11 +181,6 @@ impl CoreArg {
         self.core = Some(core);
     }
 
-    /// Set the alive address to write the status of if this core is alive
-    pub fn set_alive_address(&mut self, addr: *mut bool) {
-        self.alive_address = Some(addr);
-    }
-
     /// Set the beginning of memory for this core
     ///
     /// # Errors
@@ -67,8 +191,14 @@ impl CoreArg {
         let memory_end = add!(memory_start, sub!(memory_size, 1));
 
         // Insert the entire physical memory chunk into the `RangeSet`
-        self.memory.insert(InclusiveRange::new(memory_start, memory_end));
+        self.memory.insert(InclusiveRange::new(memory_start, memory_end))?;
 
         Ok(())
     }
 }
+
+impl Default for CoreArg {
+    fn default() -> Self {
+        Self::new()
+    }
+}
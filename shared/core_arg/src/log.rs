@@ -0,0 +1,225 @@
+//! Ring of text written by the kernel on a core and drained by the bootloader
+//!
+//! The kernel is the only producer and the bootloader the only consumer. `head` and
+//! `tail` count every byte ever written and read, so the ring never needs a lock: the
+//! kernel only moves `head` and the bootloader only moves `tail`. A write that doesn't
+//! fit is dropped and counted instead of waiting for the bootloader.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use errchain::prelude::*;
+
+/// Default number of bytes of the ring of each core. The bootloader drains it about
+/// once a second.
+pub const LOG_RING_SIZE: usize = 64 * 1024;
+
+/// Errors from setting up a [`LogRing`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LogRingError {
+    /// The capacity of the ring isn't a power of two
+    InvalidCapacity,
+}
+
+abi_layout! {
+/// Single producer single consumer byte ring in memory given by the bootloader
+#[repr(C)]
+pub struct LogRing {
+    /// Start of the memory holding the bytes of the ring
+    buffer: *mut u8,
+
+    /// Number of bytes at `buffer`, a power of two. Zero if there is no buffer.
+    capacity: usize,
+
+    /// Number of bytes ever written by the kernel
+    head: AtomicU64,
+
+    /// Number of bytes ever read by the bootloader
+    tail: AtomicU64,
+
+    /// Number of bytes dropped because the ring was full
+    dropped: AtomicU64
+}
+}
+
+impl LogRing {
+    /// Create a ring without a buffer, where every write is dropped
+    pub const fn new() -> Self {
+        Self {
+            buffer:   core::ptr::null_mut(),
+            capacity: 0,
+            head:     AtomicU64::new(0),
+            tail:     AtomicU64::new(0),
+            dropped:  AtomicU64::new(0)
+        }
+    }
+
+    /// Remove the buffer and drop everything in the ring
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Use the `capacity` bytes at `buffer` for the ring
+    ///
+    /// # Errors
+    ///
+    /// If `capacity` isn't a power of two
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must be valid for reads and writes of `capacity` bytes by both the
+    /// kernel and the bootloader for as long as the ring uses it, and not be used by
+    /// anything else
+    pub unsafe fn set_buffer(&mut self, buffer: *mut u8, capacity: usize) -> Result<()> {
+        ensure!(capacity.is_power_of_two(), &LogRingError::InvalidCapacity);
+
+        *self = Self::new();
+        self.buffer   = buffer;
+        self.capacity = capacity;
+
+        Ok(())
+    }
+
+    /// Write all of `bytes` to the ring, or drop them if they don't fit. Only called by
+    /// the kernel.
+    ///
+    /// Returns `true` if the bytes were written
+    pub fn write(&self, bytes: &[u8]) -> bool {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Acquire);
+        let free = (self.capacity as u64).saturating_sub(head.wrapping_sub(tail));
+
+        if bytes.len() as u64 > free {
+            self.dropped.fetch_add(bytes.len() as u64, Ordering::Relaxed);
+            return false;
+        }
+
+        for (index, byte) in bytes.iter().enumerate() {
+            let offset = (head as usize).wrapping_add(index) & (self.capacity - 1);
+
+            // Safe since the offset is within the buffer given to `set_buffer` and the
+            // bootloader doesn't read past `head`
+            unsafe { self.buffer.add(offset).write_volatile(*byte); }
+        }
+
+        // Publish the bytes to the bootloader
+        self.head.store(head.wrapping_add(bytes.len() as u64), Ordering::Release);
+        true
+    }
+
+    /// Move bytes from the ring into `out`, returning the number of bytes read. Only
+    /// called by the bootloader.
+    pub fn read(&self, out: &mut [u8]) -> usize {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Acquire);
+
+        let count = core::cmp::min(head.wrapping_sub(tail), out.len() as u64) as usize;
+
+        for (index, byte) in out[..count].iter_mut().enumerate() {
+            let offset = (tail as usize).wrapping_add(index) & (self.capacity - 1);
+
+            // Safe since the offset is within the buffer given to `set_buffer` and the
+            // kernel doesn't write before `tail`
+            *byte = unsafe { self.buffer.add(offset).read_volatile() };
+        }
+
+        // Give the space back to the kernel
+        self.tail.store(tail.wrapping_add(count as u64), Ordering::Release);
+        count
+    }
+
+    /// Get the number of bytes dropped since the last call. Only called by the
+    /// bootloader.
+    pub fn take_dropped(&self) -> u64 {
+        self.dropped.swap(0, Ordering::Relaxed)
+    }
+}
+
+impl Default for LogRing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for LogRing {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("LogRing")
+            .field("buffer", &self.buffer)
+            .field("capacity", &self.capacity)
+            .field("head", &self.head.load(Ordering::Relaxed))
+            .field("tail", &self.tail.load(Ordering::Relaxed))
+            .field("dropped", &self.dropped.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_wraparound() {
+        let mut buffer = vec![0u8; 8];
+        let mut ring   = LogRing::new();
+        unsafe { ring.set_buffer(buffer.as_mut_ptr(), buffer.len()).unwrap(); }
+
+        let mut out = [0u8; 8];
+        assert!(ring.write(b"abcdef"));
+        assert_eq!(ring.read(&mut out[..4]), 4);
+        assert_eq!(&out[..4], b"abcd");
+
+        // The write continues at the start of the buffer
+        assert!(ring.write(b"ghijk"));
+        assert_eq!(ring.read(&mut out), 7);
+        assert_eq!(&out[..7], b"efghijk");
+        assert_eq!(ring.read(&mut out), 0);
+    }
+
+    #[test]
+    fn test_full() {
+        let mut buffer = vec![0u8; 8];
+        let mut ring   = LogRing::new();
+        unsafe { ring.set_buffer(buffer.as_mut_ptr(), buffer.len()).unwrap(); }
+
+        // A write that doesn't fit is dropped as a whole
+        assert!(ring.write(b"abcdef"));
+        assert!(!ring.write(b"ghi"));
+        assert!(!ring.write(b"jkl"));
+        assert_eq!(ring.take_dropped(), 6);
+        assert_eq!(ring.take_dropped(), 0);
+
+        // The ring can still be filled to the last byte
+        assert!(ring.write(b"gh"));
+
+        let mut out = [0u8; 16];
+        assert_eq!(ring.read(&mut out), 8);
+        assert_eq!(&out[..8], b"abcdefgh");
+    }
+
+    #[test]
+    fn test_no_buffer() {
+        let ring = LogRing::new();
+
+        assert!(!ring.write(b"abc"));
+        assert_eq!(ring.take_dropped(), 3);
+        assert_eq!(ring.read(&mut [0u8; 8]), 0);
+    }
+
+    #[test]
+    fn test_set_buffer() {
+        let mut buffer = vec![0u8; 12];
+        let mut ring   = LogRing::new();
+
+        for capacity in [0, 12] {
+            let err = unsafe { ring.set_buffer(buffer.as_mut_ptr(), capacity) };
+            assert!(matches!(err.unwrap_err().downcast_ref(),
+                Some(LogRingError::InvalidCapacity)));
+        }
+
+        // The ring is left without a buffer
+        assert!(!ring.write(b"a"));
+    }
+}
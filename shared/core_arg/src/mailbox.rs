@@ -0,0 +1,270 @@
+//! Commands posted by the bootloader to a running core
+//!
+//! The bootloader writes a command and then bumps the command sequence number with
+//! `Release`. The kernel polls for a sequence number it hasn't acknowledged, handles
+//! the command and answers by writing a [`CommandStatus`] followed by the sequence
+//! number it handled. Only one command is in flight at a time.
+
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use errchain::prelude::*;
+
+/// Errors from posting commands to a [`Mailbox`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MailboxError {
+    /// The kernel hasn't acknowledged the previous command yet
+    Busy,
+}
+
+/// Command for the kernel on a core
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Return from `try_main`
+    Stop,
+
+    /// Stop working until [`Command::Resume`], while still handling commands
+    Pause,
+
+    /// Continue working after [`Command::Pause`]
+    Resume,
+
+    /// Run the kernel test with the given ID
+    RunTest(u32),
+
+    /// Zero every stat while keeping them registered
+    ResetStats,
+
+    /// Get to a point where the kernel can be replaced, then return from `try_main`
+    PrepareReload
+}
+
+impl Command {
+    /// Get the code and argument the command is posted as
+    fn to_raw(self) -> (u32, u64) {
+        match self {
+            Command::Stop          => (1, 0),
+            Command::Pause         => (2, 0),
+            Command::Resume        => (3, 0),
+            Command::RunTest(id)   => (4, u64::from(id)),
+            Command::ResetStats    => (5, 0),
+            Command::PrepareReload => (6, 0)
+        }
+    }
+
+    /// Get the command posted as `code` and `arg`
+    fn from_raw(code: u32, arg: u64) -> Option<Self> {
+        Some(match code {
+            1 => Command::Stop,
+            2 => Command::Pause,
+            3 => Command::Resume,
+            4 => Command::RunTest(arg as u32),
+            5 => Command::ResetStats,
+            6 => Command::PrepareReload,
+            _ => return None
+        })
+    }
+}
+
+/// Result of a [`Command`] reported by the kernel
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CommandStatus {
+    /// The command was handled
+    Ok = 1,
+
+    /// The kernel doesn't know the command, likely because it is older than the
+    /// bootloader
+    UnknownCommand = 2,
+
+    /// The command doesn't apply to the current state, such as resuming a core that
+    /// isn't paused
+    InvalidState = 3,
+
+    /// There is no test with the requested ID
+    UnknownTest = 4,
+
+    /// The command was handled but failed
+    Failed = 5
+}
+
+impl CommandStatus {
+    /// Get the status reported as `val`
+    fn from_u32(val: u32) -> Option<Self> {
+        Some(match val {
+            1 => CommandStatus::Ok,
+            2 => CommandStatus::UnknownCommand,
+            3 => CommandStatus::InvalidState,
+            4 => CommandStatus::UnknownTest,
+            5 => CommandStatus::Failed,
+            _ => return None
+        })
+    }
+}
+
+/// A command taken from a [`Mailbox`] by the kernel, to be answered with
+/// [`Mailbox::respond`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PendingCommand {
+    /// Sequence number the command was posted with
+    pub seq: u64,
+
+    /// The posted command
+    pub command: Command
+}
+
+abi_layout! {
+/// Single command slot shared by the bootloader and the kernel of a core
+#[repr(C)]
+pub struct Mailbox {
+    /// Sequence number of the last posted command
+    command_seq: AtomicU64,
+
+    /// Code of the last posted command
+    command: AtomicU32,
+
+    /// Argument of the last posted command
+    argument: AtomicU64,
+
+    /// Sequence number of the last command answered by the kernel
+    ack_seq: AtomicU64,
+
+    /// [`CommandStatus`] of the last answered command as a `u32`
+    status: AtomicU32
+}
+}
+
+impl Mailbox {
+    /// Create an empty mailbox
+    pub const fn new() -> Self {
+        Self {
+            command_seq: AtomicU64::new(0),
+            command:     AtomicU32::new(0),
+            argument:    AtomicU64::new(0),
+            ack_seq:     AtomicU64::new(0),
+            status:      AtomicU32::new(0)
+        }
+    }
+
+    /// Drop every command and response
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Post `command` for the kernel, returning the sequence number to wait for with
+    /// [`Mailbox::response`]. Only called by the bootloader.
+    ///
+    /// # Errors
+    ///
+    /// If the kernel hasn't answered the previous command
+    pub fn post(&self, command: Command) -> Result<u64> {
+        let seq = self.command_seq.load(Ordering::Relaxed);
+        ensure!(self.ack_seq.load(Ordering::Acquire) == seq, &MailboxError::Busy);
+
+        let (code, arg) = command.to_raw();
+        self.command.store(code, Ordering::Relaxed);
+        self.argument.store(arg, Ordering::Relaxed);
+        self.command_seq.store(seq + 1, Ordering::Release);
+
+        Ok(seq + 1)
+    }
+
+    /// Get the status of the command posted as `seq` once the kernel answered it
+    pub fn response(&self, seq: u64) -> Option<CommandStatus> {
+        if self.ack_seq.load(Ordering::Acquire) != seq {
+            return None;
+        }
+
+        CommandStatus::from_u32(self.status.load(Ordering::Relaxed))
+    }
+
+    /// Get the command waiting to be answered, if any. Commands this kernel doesn't
+    /// know are answered with [`CommandStatus::UnknownCommand`] right away. Only called
+    /// by the kernel.
+    pub fn poll(&self) -> Option<PendingCommand> {
+        let seq = self.command_seq.load(Ordering::Acquire);
+        if seq == self.ack_seq.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let code = self.command.load(Ordering::Relaxed);
+        let arg  = self.argument.load(Ordering::Relaxed);
+
+        match Command::from_raw(code, arg) {
+            Some(command) => Some(PendingCommand { seq, command }),
+            None => {
+                self.answer(seq, CommandStatus::UnknownCommand);
+                None
+            }
+        }
+    }
+
+    /// Answer a command returned by [`Mailbox::poll`]. Only called by the kernel.
+    pub fn respond(&self, pending: PendingCommand, status: CommandStatus) {
+        self.answer(pending.seq, status);
+    }
+
+    /// Publish `status` as the answer to the command posted as `seq`
+    fn answer(&self, seq: u64, status: CommandStatus) {
+        self.status.store(status as u32, Ordering::Relaxed);
+        self.ack_seq.store(seq, Ordering::Release);
+    }
+}
+
+impl Default for Mailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Mailbox {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Mailbox")
+            .field("command_seq", &self.command_seq.load(Ordering::Relaxed))
+            .field("ack_seq", &self.ack_seq.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post() {
+        let mailbox = Mailbox::new();
+        assert!(mailbox.poll().is_none());
+
+        let seq = mailbox.post(Command::RunTest(7)).unwrap();
+        assert_eq!(mailbox.response(seq), None);
+
+        // Only one command is in flight at a time
+        let err = mailbox.post(Command::Stop).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(MailboxError::Busy)));
+
+        let pending = mailbox.poll().unwrap();
+        assert_eq!(pending, PendingCommand { seq, command: Command::RunTest(7) });
+
+        mailbox.respond(pending, CommandStatus::UnknownTest);
+        assert_eq!(mailbox.response(seq), Some(CommandStatus::UnknownTest));
+        assert!(mailbox.poll().is_none());
+
+        // Answering frees the slot for the next command
+        let next = mailbox.post(Command::Stop).unwrap();
+        assert_eq!(next, seq + 1);
+        assert_eq!(mailbox.response(next), None);
+        assert_eq!(mailbox.poll().map(|pending| pending.command), Some(Command::Stop));
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        let mailbox = Mailbox::new();
+
+        // A command from a newer bootloader is answered without reaching the kernel
+        let seq = mailbox.post(Command::Pause).unwrap();
+        mailbox.command.store(0x1337, Ordering::Relaxed);
+
+        assert!(mailbox.poll().is_none());
+        assert_eq!(mailbox.response(seq), Some(CommandStatus::UnknownCommand));
+        assert!(mailbox.post(Command::Resume).is_ok());
+    }
+}
@@ -0,0 +1,344 @@
+//! Lifecycle of the kernel on a core, reported to the bootloader
+//!
+//! The bootloader and the kernel move a core through the [`CoreState`]s, recording the
+//! time counter at each transition. The state is published last with `Release` so
+//! that the bootloader sees the result or panic message of a core once it reads a
+//! state that has one.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+
+use cpu_trait::CpuTrait;
+use errchain::TruncatingWriter;
+
+/// Maximum number of bytes of the rendered panic message kept in a [`CoreStatus`]
+pub const MAX_PANIC_MESSAGE_LEN: usize = 256;
+
+/// State of the kernel on a core
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CoreState {
+    /// The bootloader hasn't started the core
+    Offline = 0,
+
+    /// The bootloader started the core but the kernel hasn't accepted its `CoreArg`
+    Booting = 1,
+
+    /// The kernel is running `try_main`
+    Running = 2,
+
+    /// `try_main` returned `Ok`
+    Finished = 3,
+
+    /// `try_main` returned `Err`, which is kept in the `error` of the `CoreArg`
+    Failed = 4,
+
+    /// The kernel panicked
+    Panicked = 5,
+
+    /// The kernel heap couldn't satisfy an allocation
+    OutOfMemory = 6
+}
+
+impl CoreState {
+    /// Number of states
+    pub const COUNT: usize = 7;
+
+    /// Every state, in the order a core goes through them
+    pub const ALL: [CoreState; CoreState::COUNT] = [
+        CoreState::Offline, CoreState::Booting, CoreState::Running,
+        CoreState::Finished, CoreState::Failed, CoreState::Panicked,
+        CoreState::OutOfMemory
+    ];
+
+    /// Get the state stored as `val`
+    fn from_u32(val: u32) -> Option<Self> {
+        Self::ALL.get(val as usize).copied()
+    }
+
+    /// Returns `true` if the kernel is done on a core in this state
+    pub fn is_done(self) -> bool {
+        matches!(self, CoreState::Finished | CoreState::Failed | CoreState::Panicked
+            | CoreState::OutOfMemory)
+    }
+}
+
+impl core::fmt::Display for CoreState {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let name = match self {
+            CoreState::Offline     => "offline",
+            CoreState::Booting     => "booting",
+            CoreState::Running     => "running",
+            CoreState::Finished    => "finished",
+            CoreState::Failed      => "failed",
+            CoreState::Panicked    => "panicked",
+            CoreState::OutOfMemory => "out of memory"
+        };
+
+        f.pad(name)
+    }
+}
+
+// Used to create the array of transition times, as atomics aren't `Copy`
+#[allow(clippy::declare_interior_mutable_const)]
+const NEVER: AtomicU64 = AtomicU64::new(0);
+
+abi_layout! {
+/// Current [`CoreState`] of a core along with how it got there
+#[repr(C)]
+pub struct CoreStatus {
+    /// The [`CoreState`] as a `u32`
+    state: AtomicU32,
+
+    /// Time counter when the core entered each state, or zero if it never did
+    times: [AtomicU64; CoreState::COUNT],
+
+    /// Value returned by `try_main`, valid in [`CoreState::Finished`]
+    result: AtomicU64,
+
+    /// Rendered location and message of the panic, valid in [`CoreState::Panicked`]
+    /// and [`CoreState::OutOfMemory`]. Only written by the core before it enters one
+    /// of those states.
+    panic_message: UnsafeCell<[u8; MAX_PANIC_MESSAGE_LEN]>,
+
+    /// Number of valid bytes in `panic_message`
+    panic_message_len: AtomicUsize
+}
+}
+
+impl CoreStatus {
+    /// Create the status of a core that wasn't started
+    pub const fn new() -> Self {
+        Self {
+            state:             AtomicU32::new(CoreState::Offline as u32),
+            times:             [NEVER; CoreState::COUNT],
+            result:            AtomicU64::new(0),
+            panic_message:     UnsafeCell::new([0; MAX_PANIC_MESSAGE_LEN]),
+            panic_message_len: AtomicUsize::new(0)
+        }
+    }
+
+    /// Put the core back in [`CoreState::Offline`]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Move the core to `state`, timestamped with the time counter of `C`
+    pub fn enter<C: CpuTrait>(&self, state: CoreState) {
+        self.times[state as usize].store(C::read_time_counter(), Ordering::Relaxed);
+        self.state.store(state as u32, Ordering::Release);
+    }
+
+    /// Move the core to [`CoreState::Finished`] with the value returned by `try_main`
+    pub fn finish<C: CpuTrait>(&self, result: usize) {
+        self.result.store(result as u64, Ordering::Relaxed);
+        self.enter::<C>(CoreState::Finished);
+    }
+
+    /// Move the core to [`CoreState::Panicked`], keeping as much of the panic location
+    /// and message as fits in [`MAX_PANIC_MESSAGE_LEN`] bytes. Does nothing if the
+    /// kernel is already done on the core.
+    pub fn panicked<C: CpuTrait>(&self, location: Option<&core::panic::Location>,
+            message: &dyn core::fmt::Display) {
+        match location {
+            Some(location) => {
+                self.set_panic_message::<C>(CoreState::Panicked, format_args!(
+                    "[{}:{}:{}] {}", location.file(), location.line(), location.column(),
+                    message));
+            }
+            None => {
+                self.set_panic_message::<C>(CoreState::Panicked,
+                    format_args!("{}", message));
+            }
+        }
+    }
+
+    /// Move the core to [`CoreState::OutOfMemory`] after failing to allocate `size`
+    /// bytes aligned to `align`. Does nothing if the kernel is already done on the core.
+    pub fn out_of_memory<C: CpuTrait>(&self, size: usize, align: usize) {
+        self.set_panic_message::<C>(CoreState::OutOfMemory, format_args!(
+            "out of memory allocating {:#x} bytes aligned to {:#x}", size, align));
+    }
+
+    /// Render `message` into the panic message and move the core to `state`, unless the
+    /// kernel is already done on the core
+    fn set_panic_message<C: CpuTrait>(&self, state: CoreState,
+            message: core::fmt::Arguments) {
+        // The bootloader may already be reading the message of a core that is done
+        if self.state().is_done() {
+            return;
+        }
+
+        // Safe since only the core itself writes the message, and nothing reads it
+        // before the core is done
+        let buffer = unsafe { &mut *self.panic_message.get() };
+        let mut writer = TruncatingWriter::new(buffer);
+
+        // Writing never fails, only truncates
+        let _ = core::fmt::write(&mut writer, message);
+
+        self.panic_message_len.store(writer.len(), Ordering::Relaxed);
+        self.enter::<C>(state);
+    }
+
+    /// Get the current state of the core
+    pub fn state(&self) -> CoreState {
+        let state = self.state.load(Ordering::Acquire);
+        CoreState::from_u32(state).unwrap_or(CoreState::Offline)
+    }
+
+    /// Get the time counter of when the core entered `state`, if it did
+    pub fn time(&self, state: CoreState) -> Option<u64> {
+        match self.times[state as usize].load(Ordering::Relaxed) {
+            0    => None,
+            time => Some(time)
+        }
+    }
+
+    /// Get the value returned by `try_main` if the core finished
+    pub fn result(&self) -> Option<usize> {
+        if self.state() != CoreState::Finished {
+            return None;
+        }
+
+        Some(self.result.load(Ordering::Relaxed) as usize)
+    }
+
+    /// Get the rendered panic location and message if the core panicked or ran out of
+    /// memory
+    pub fn panic_message(&self) -> Option<&str> {
+        if !matches!(self.state(), CoreState::Panicked | CoreState::OutOfMemory) {
+            return None;
+        }
+
+        // Safe since the core doesn't write the message again once it is done
+        let message = unsafe { &*self.panic_message.get() };
+        let len = self.panic_message_len.load(Ordering::Relaxed);
+        core::str::from_utf8(&message[..len.min(MAX_PANIC_MESSAGE_LEN)]).ok()
+    }
+}
+
+impl Default for CoreStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for CoreStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("CoreStatus")
+            .field("state", &self.state())
+            .field("result", &self.result())
+            .field("panic_message", &self.panic_message())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    /// Time counter of [`TestCpu`], increasing on every read
+    static TIME: AtomicU64 = AtomicU64::new(1);
+
+    /// CPU with a time counter that only ever increases
+    struct TestCpu;
+
+    impl CpuTrait for TestCpu {
+        fn read_page_table_addr() -> u64 {
+            0
+        }
+
+        fn set_page_table_addr(_addr: u64) {}
+
+        fn read_time_counter() -> u64 {
+            TIME.fetch_add(1, Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn test_finish() {
+        let mut status = CoreStatus::new();
+        assert_eq!(status.state(), CoreState::Offline);
+        assert_eq!(status.time(CoreState::Offline), None);
+
+        status.enter::<TestCpu>(CoreState::Booting);
+        status.enter::<TestCpu>(CoreState::Running);
+        assert_eq!(status.state(), CoreState::Running);
+        assert!(!status.state().is_done());
+        assert_eq!(status.result(), None);
+
+        status.finish::<TestCpu>(7);
+        assert_eq!(status.state(), CoreState::Finished);
+        assert_eq!(status.result(), Some(7));
+        assert_eq!(status.panic_message(), None);
+
+        // Every transition is timestamped in order
+        let booting  = status.time(CoreState::Booting).unwrap();
+        let running  = status.time(CoreState::Running).unwrap();
+        let finished = status.time(CoreState::Finished).unwrap();
+        assert!(booting < running && running < finished);
+        assert_eq!(status.time(CoreState::Panicked), None);
+
+        // A core that is done doesn't panic afterwards
+        status.panicked::<TestCpu>(None, &"late");
+        status.out_of_memory::<TestCpu>(0x10, 0x10);
+        assert_eq!(status.state(), CoreState::Finished);
+        assert_eq!(status.panic_message(), None);
+
+        status.reset();
+        assert_eq!(status.state(), CoreState::Offline);
+        assert_eq!(status.time(CoreState::Finished), None);
+        assert_eq!(status.result(), None);
+    }
+
+    #[test]
+    fn test_panicked() {
+        let status = CoreStatus::new();
+        status.enter::<TestCpu>(CoreState::Running);
+
+        let location = core::panic::Location::caller();
+        status.panicked::<TestCpu>(Some(location), &"boom");
+
+        assert_eq!(status.state(), CoreState::Panicked);
+        assert!(status.state().is_done());
+        assert!(status.time(CoreState::Panicked).is_some());
+        assert_eq!(status.result(), None);
+
+        let message = std::format!("[{}:{}:{}] boom", location.file(), location.line(),
+            location.column());
+        assert_eq!(status.panic_message(), Some(message.as_str()));
+
+        // The first panic is kept
+        status.panicked::<TestCpu>(None, &"again");
+        assert_eq!(status.panic_message(), Some(message.as_str()));
+    }
+
+    #[test]
+    fn test_out_of_memory() {
+        let status = CoreStatus::new();
+        status.enter::<TestCpu>(CoreState::Running);
+        status.out_of_memory::<TestCpu>(0x3000, 0x1000);
+
+        assert_eq!(status.state(), CoreState::OutOfMemory);
+        assert!(status.state().is_done());
+        assert_eq!(status.panic_message(),
+            Some("out of memory allocating 0x3000 bytes aligned to 0x1000"));
+    }
+
+    #[test]
+    fn test_panic_message_truncated() {
+        // The 'é' straddles the end of the buffer, so it is dropped along with the rest
+        let mut message = "a".repeat(MAX_PANIC_MESSAGE_LEN - 1);
+        message.push_str("é and more");
+
+        let status = CoreStatus::new();
+        status.panicked::<TestCpu>(None, &message);
+
+        let kept = status.panic_message().unwrap();
+        assert_eq!(kept.len(), MAX_PANIC_MESSAGE_LEN - 1);
+        assert!(kept.bytes().all(|byte| byte == b'a'));
+    }
+}
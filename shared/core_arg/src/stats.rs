@@ -1,12 +1,582 @@
-#[derive(Copy, Clone, Debug)]
+//! Performance stats of a core, updated by the kernel and read by the bootloader
+//!
+//! Stats are registered by name at runtime so that a reloaded kernel can add new ones
+//! without rebuilding the bootloader. Every stat lives in a fixed size slot of
+//! [`Stats`], and values are atomics, so the kernel updates them without locks while
+//! the bootloader reads them.
+
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+use cpu_trait::CpuTrait;
+use errchain::prelude::*;
+
+/// Maximum number of counters in a [`Stats`]
+pub const MAX_COUNTERS: usize = 16;
+
+/// Maximum number of timers in a [`Stats`]
+pub const MAX_TIMERS: usize = 8;
+
+/// Maximum number of histograms in a [`Stats`]
+pub const MAX_HISTOGRAMS: usize = 4;
+
+/// Maximum number of bytes in the name of a stat
+pub const MAX_STAT_NAME: usize = 24;
+
+/// Number of buckets in a histogram. Bucket `i` counts the values in `[2^i, 2^(i+1))`,
+/// except that the first bucket also counts `0` and the last counts everything above.
+pub const HISTOGRAM_BUCKETS: usize = 32;
+
+/// Errors from registering stats
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatsError {
+    /// All [`MAX_COUNTERS`] counters are registered
+    TooManyCounters,
+
+    /// All [`MAX_TIMERS`] timers are registered
+    TooManyTimers,
+
+    /// All [`MAX_HISTOGRAMS`] histograms are registered
+    TooManyHistograms,
+
+    /// The name is longer than [`MAX_STAT_NAME`] bytes
+    NameTooLong,
+}
+
+abi_layout! {
+/// Name of a registered stat
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct StatName {
+    /// Bytes of the name
+    bytes: [u8; MAX_STAT_NAME],
+
+    /// Number of valid bytes in `bytes`
+    len: u8
+}
+}
+
+impl StatName {
+    /// Create an empty name
+    pub const fn empty() -> Self {
+        Self { bytes: [0; MAX_STAT_NAME], len: 0 }
+    }
+
+    /// Create a name from `name`
+    ///
+    /// # Errors
+    ///
+    /// If `name` is longer than [`MAX_STAT_NAME`] bytes
+    pub fn new(name: &str) -> Result<Self> {
+        ensure!(name.len() <= MAX_STAT_NAME, &StatsError::NameTooLong);
+
+        let mut res = Self::empty();
+        res.bytes[..name.len()].copy_from_slice(name.as_bytes());
+        res.len = name.len() as u8;
+        Ok(res)
+    }
+
+    /// Get the name as a `str`
+    pub fn as_str(&self) -> &str {
+        let len = core::cmp::min(usize::from(self.len), MAX_STAT_NAME);
+        core::str::from_utf8(&self.bytes[..len]).unwrap_or("<invalid>")
+    }
+}
+
+impl core::fmt::Debug for StatName {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl core::fmt::Display for StatName {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Handle to a counter registered with [`Stats::register_counter`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CounterId(usize);
+
+/// Handle to a timer registered with [`Stats::register_timer`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TimerId(usize);
+
+/// Handle to a histogram registered with [`Stats::register_histogram`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HistogramId(usize);
+
+abi_layout! {
+/// A counter slot
+#[repr(C)]
+struct Counter {
+    /// Name of the counter
+    name: StatName,
+
+    /// Current value
+    value: AtomicU64
+}
+
+nested: StatName
+}
+
+abi_layout! {
+/// A timer slot
+#[repr(C)]
+struct Timer {
+    /// Name of the timer
+    name: StatName,
+
+    /// Total time counter ticks spent in the timer
+    ticks: AtomicU64,
+
+    /// Number of times the timer was started
+    count: AtomicU64
+}
+
+nested: StatName
+}
+
+abi_layout! {
+/// A histogram slot
+#[repr(C)]
+struct Histogram {
+    /// Name of the histogram
+    name: StatName,
+
+    /// Number of values recorded in each log2 bucket
+    buckets: [AtomicU64; HISTOGRAM_BUCKETS],
+
+    /// Sum of every recorded value
+    sum: AtomicU64
+}
+
+nested: StatName
+}
+
+/// Value of a timer read with [`Stats::timers`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct TimerValue {
+    /// Total time counter ticks spent in the timer
+    pub ticks: u64,
+
+    /// Number of times the timer was started
+    pub count: u64
+}
+
+/// Value of a histogram read with [`Stats::histograms`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct HistogramValue {
+    /// Number of values recorded in each log2 bucket
+    pub buckets: [u64; HISTOGRAM_BUCKETS],
+
+    /// Sum of every recorded value
+    pub sum: u64
+}
+
+impl HistogramValue {
+    /// Number of values recorded
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().fold(0, |acc, &count| acc.wrapping_add(count))
+    }
+
+    /// Add the buckets of `other` to this histogram
+    pub fn add(&mut self, other: &HistogramValue) {
+        for (bucket, count) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *bucket = bucket.wrapping_add(*count);
+        }
+
+        self.sum = self.sum.wrapping_add(other.sum);
+    }
+}
+
+/// Get the histogram bucket of `value`
+fn bucket(value: u64) -> usize {
+    let log2 = 63 - (value | 1).leading_zeros() as usize;
+    core::cmp::min(log2, HISTOGRAM_BUCKETS - 1)
+}
+
+// Used to create the arrays of slots, as atomics aren't `Copy`
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_COUNTER: Counter = Counter { name: StatName::empty(), value: ZERO };
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_TIMER: Timer = Timer { name: StatName::empty(), ticks: ZERO, count: ZERO };
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_HISTOGRAM: Histogram = Histogram {
+    name:    StatName::empty(),
+    buckets: [ZERO; HISTOGRAM_BUCKETS],
+    sum:     ZERO
+};
+
+abi_layout! {
+/// Performance stats of a core
+#[repr(C)]
 pub struct Stats {
-    pub start_time: usize
+    /// Time counter of the core when the kernel started
+    pub start_time: usize,
+
+    /// Number of registered counters. Slots are filled in before this is increased.
+    counters_len: AtomicU32,
+
+    /// Number of registered timers
+    timers_len: AtomicU32,
+
+    /// Number of registered histograms
+    histograms_len: AtomicU32,
+
+    /// Counter slots
+    counters: [Counter; MAX_COUNTERS],
+
+    /// Timer slots
+    timers: [Timer; MAX_TIMERS],
+
+    /// Histogram slots
+    histograms: [Histogram; MAX_HISTOGRAMS]
+}
+
+nested: Counter, Timer, Histogram
 }
 
 impl Stats {
+    /// Create an empty [`Stats`]
     pub const fn new() -> Self {
         Self {
-            start_time: 0
+            start_time:     0,
+            counters_len:   AtomicU32::new(0),
+            timers_len:     AtomicU32::new(0),
+            histograms_len: AtomicU32::new(0),
+            counters:       [EMPTY_COUNTER; MAX_COUNTERS],
+            timers:         [EMPTY_TIMER; MAX_TIMERS],
+            histograms:     [EMPTY_HISTOGRAM; MAX_HISTOGRAMS]
+        }
+    }
+
+    /// Remove every registered stat
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Zero every registered stat, keeping the registrations
+    pub fn clear(&self) {
+        for counter in &self.counters {
+            counter.value.store(0, Ordering::Relaxed);
+        }
+
+        for timer in &self.timers {
+            timer.ticks.store(0, Ordering::Relaxed);
+            timer.count.store(0, Ordering::Relaxed);
+        }
+
+        for histogram in &self.histograms {
+            for bucket in &histogram.buckets {
+                bucket.store(0, Ordering::Relaxed);
+            }
+            histogram.sum.store(0, Ordering::Relaxed);
         }
     }
+
+    /// Find the slot named `name` in the first `len` of `slots`, or fill in the next
+    /// slot and publish it by increasing `len`
+    fn register<T>(slots: &mut [T], slot_name: fn(&mut T) -> &mut StatName,
+            len: &AtomicU32, name: &str, full: &'static StatsError) -> Result<usize> {
+        let name = StatName::new(name)?;
+        let used = core::cmp::min(len.load(Ordering::Acquire) as usize, slots.len());
+
+        // Registering a name again gives the same slot
+        let existing = slots[..used].iter_mut().position(|slot| *slot_name(slot) == name);
+        if let Some(index) = existing {
+            return Ok(index);
+        }
+
+        ensure!(used < slots.len(), full);
+
+        *slot_name(&mut slots[used]) = name;
+        len.store(used as u32 + 1, Ordering::Release);
+        Ok(used)
+    }
+
+    /// Register a counter named `name`, starting at zero. Registering the same name
+    /// again returns the existing counter.
+    ///
+    /// # Errors
+    ///
+    /// If `name` is too long or [`MAX_COUNTERS`] counters are already registered
+    pub fn register_counter(&mut self, name: &str) -> Result<CounterId> {
+        Self::register(&mut self.counters, |slot| &mut slot.name, &self.counters_len,
+            name, &StatsError::TooManyCounters).map(CounterId)
+    }
+
+    /// Register a timer named `name`. Registering the same name again returns the
+    /// existing timer.
+    ///
+    /// # Errors
+    ///
+    /// If `name` is too long or [`MAX_TIMERS`] timers are already registered
+    pub fn register_timer(&mut self, name: &str) -> Result<TimerId> {
+        Self::register(&mut self.timers, |slot| &mut slot.name, &self.timers_len,
+            name, &StatsError::TooManyTimers).map(TimerId)
+    }
+
+    /// Register a histogram named `name`. Registering the same name again returns the
+    /// existing histogram.
+    ///
+    /// # Errors
+    ///
+    /// If `name` is too long or [`MAX_HISTOGRAMS`] histograms are already registered
+    pub fn register_histogram(&mut self, name: &str) -> Result<HistogramId> {
+        Self::register(&mut self.histograms, |slot| &mut slot.name, &self.histograms_len,
+            name, &StatsError::TooManyHistograms).map(HistogramId)
+    }
+
+    /// Add `value` to a counter
+    pub fn add(&self, counter: CounterId, value: u64) {
+        self.counters[counter.0].value.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Add one to a counter
+    pub fn increment(&self, counter: CounterId) {
+        self.add(counter, 1);
+    }
+
+    /// Add a value to a histogram
+    pub fn record(&self, histogram: HistogramId, value: u64) {
+        let histogram = &self.histograms[histogram.0];
+        histogram.buckets[bucket(value)].fetch_add(1, Ordering::Relaxed);
+        histogram.sum.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Add `ticks` of the time counter to a timer
+    pub fn add_time(&self, timer: TimerId, ticks: u64) {
+        let timer = &self.timers[timer.0];
+        timer.ticks.fetch_add(ticks, Ordering::Relaxed);
+        timer.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Start timing with the time counter of `C` until the returned [`ScopedTimer`] is
+    /// dropped
+    pub fn time<C: CpuTrait>(&self, timer: TimerId) -> ScopedTimer<'_, C> {
+        ScopedTimer {
+            stats:     self,
+            timer,
+            histogram: None,
+            start:     C::read_time_counter(),
+            _cpu:      PhantomData
+        }
+    }
+
+    /// Get the name and value of every registered counter
+    pub fn counters(&self) -> impl Iterator<Item = (StatName, u64)> + '_ {
+        let len = self.counters_len.load(Ordering::Acquire) as usize;
+
+        self.counters[..core::cmp::min(len, MAX_COUNTERS)].iter()
+            .map(|counter| (counter.name, counter.value.load(Ordering::Relaxed)))
+    }
+
+    /// Get the name and value of every registered timer
+    pub fn timers(&self) -> impl Iterator<Item = (StatName, TimerValue)> + '_ {
+        let len = self.timers_len.load(Ordering::Acquire) as usize;
+
+        self.timers[..core::cmp::min(len, MAX_TIMERS)].iter().map(|timer| {
+            (timer.name, TimerValue {
+                ticks: timer.ticks.load(Ordering::Relaxed),
+                count: timer.count.load(Ordering::Relaxed)
+            })
+        })
+    }
+
+    /// Get the name and value of every registered histogram
+    pub fn histograms(&self) -> impl Iterator<Item = (StatName, HistogramValue)> + '_ {
+        let len = self.histograms_len.load(Ordering::Acquire) as usize;
+
+        self.histograms[..core::cmp::min(len, MAX_HISTOGRAMS)].iter().map(|histogram| {
+            let mut value = HistogramValue::default();
+            for (dst, src) in value.buckets.iter_mut().zip(histogram.buckets.iter()) {
+                *dst = src.load(Ordering::Relaxed);
+            }
+            value.sum = histogram.sum.load(Ordering::Relaxed);
+
+            (histogram.name, value)
+        })
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Stats {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Stats")
+            .field("start_time", &self.start_time)
+            .field("counters", &StatList(|| self.counters()))
+            .field("timers", &StatList(|| self.timers()))
+            .field("histograms", &StatList(|| self.histograms()))
+            .finish()
+    }
+}
+
+/// Prints the stats returned by a function as a map from their names
+struct StatList<F>(F);
+
+impl<F, I, T> core::fmt::Debug for StatList<F>
+        where F: Fn() -> I, I: Iterator<Item = (StatName, T)>, T: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_map().entries((self.0)()).finish()
+    }
+}
+
+/// Adds the time from its creation to its drop to a timer, returned by [`Stats::time`]
+pub struct ScopedTimer<'a, C: CpuTrait> {
+    /// Stats holding the timer
+    stats: &'a Stats,
+
+    /// Timer to add the time to
+    timer: TimerId,
+
+    /// Histogram to also record the time in
+    histogram: Option<HistogramId>,
+
+    /// Time counter when timing started
+    start: u64,
+
+    /// The time counter is read from `C`
+    _cpu: PhantomData<C>
+}
+
+impl<'a, C: CpuTrait> ScopedTimer<'a, C> {
+    /// Also record the time in `histogram`
+    pub fn with_histogram(mut self, histogram: HistogramId) -> Self {
+        self.histogram = Some(histogram);
+        self
+    }
+}
+
+impl<'a, C: CpuTrait> Drop for ScopedTimer<'a, C> {
+    fn drop(&mut self) {
+        let elapsed = C::read_time_counter().wrapping_sub(self.start);
+
+        self.stats.add_time(self.timer, elapsed);
+        if let Some(histogram) = self.histogram {
+            self.stats.record(histogram, elapsed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the one letter name of the `index`th stat
+    fn name(index: usize) -> [u8; 1] {
+        [b'a' + index as u8]
+    }
+
+    #[test]
+    fn test_register() {
+        let mut stats = Stats::new();
+
+        let first  = stats.register_counter("first").unwrap();
+        let second = stats.register_counter("second").unwrap();
+        assert_ne!(first, second);
+
+        // Registering a name again gives the same counter
+        assert_eq!(stats.register_counter("first").unwrap(), first);
+
+        stats.add(first, 5);
+        stats.increment(second);
+        let counters: [(StatName, u64); 2] = [
+            (StatName::new("first").unwrap(), 5),
+            (StatName::new("second").unwrap(), 1)
+        ];
+        assert!(stats.counters().eq(counters.iter().copied()));
+
+        // Clearing keeps the registrations
+        stats.clear();
+        assert!(stats.counters().all(|(_, value)| value == 0));
+        assert_eq!(stats.counters().count(), 2);
+
+        let long = [b'x'; MAX_STAT_NAME + 1];
+        let long = core::str::from_utf8(&long).unwrap();
+        let err  = stats.register_counter(long).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(StatsError::NameTooLong)));
+    }
+
+    #[test]
+    fn test_register_full() {
+        let mut stats = Stats::new();
+
+        for index in 0..MAX_COUNTERS {
+            let name = name(index);
+            stats.register_counter(core::str::from_utf8(&name).unwrap()).unwrap();
+        }
+        for index in 0..MAX_TIMERS {
+            let name = name(index);
+            stats.register_timer(core::str::from_utf8(&name).unwrap()).unwrap();
+        }
+        for index in 0..MAX_HISTOGRAMS {
+            let name = name(index);
+            stats.register_histogram(core::str::from_utf8(&name).unwrap()).unwrap();
+        }
+
+        // Existing names are still found once every slot is used
+        assert!(stats.register_counter("a").is_ok());
+
+        let err = stats.register_counter("full").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(StatsError::TooManyCounters)));
+        let err = stats.register_timer("full").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(StatsError::TooManyTimers)));
+        let err = stats.register_histogram("full").unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(StatsError::TooManyHistograms)));
+
+        assert_eq!(stats.counters().count(), MAX_COUNTERS);
+        assert_eq!(stats.timers().count(), MAX_TIMERS);
+        assert_eq!(stats.histograms().count(), MAX_HISTOGRAMS);
+    }
+
+    #[test]
+    fn test_bucket() {
+        assert_eq!(bucket(0), 0);
+        assert_eq!(bucket(1), 0);
+        assert_eq!(bucket(2), 1);
+        assert_eq!(bucket(3), 1);
+        assert_eq!(bucket(4), 2);
+        assert_eq!(bucket((1 << 31) - 1), 30);
+        assert_eq!(bucket(1 << 31), HISTOGRAM_BUCKETS - 1);
+
+        // Everything above the last bucket is counted in it
+        assert_eq!(bucket(1 << 40), HISTOGRAM_BUCKETS - 1);
+        assert_eq!(bucket(u64::MAX), HISTOGRAM_BUCKETS - 1);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut stats = Stats::new();
+        let histogram = stats.register_histogram("cycles").unwrap();
+
+        for value in [0, 1, 5, 6, u64::MAX] {
+            stats.record(histogram, value);
+        }
+
+        let (_, mut value) = stats.histograms().next().unwrap();
+        assert_eq!(value.count(), 5);
+        assert_eq!(value.buckets[0], 2);
+        assert_eq!(value.buckets[2], 2);
+        assert_eq!(value.buckets[HISTOGRAM_BUCKETS - 1], 1);
+        assert_eq!(value.sum, 12u64.wrapping_add(u64::MAX));
+
+        // Adding histograms adds their buckets, wrapping like the atomics do
+        let other = value;
+        value.add(&other);
+        assert_eq!(value.count(), 10);
+        assert_eq!(value.buckets[2], 4);
+        assert_eq!(value.sum, 22);
+    }
 }
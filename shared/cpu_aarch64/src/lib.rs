@@ -1,9 +1,9 @@
 //! Specific `aarch64` architecture functionality
 
 #![no_std]
-#![feature(asm)]
 #![cfg(target_arch="x86_64")]
 
+use core::arch::asm;
 use core::convert::TryInto;
 
 /// Read the page table address from `ttbr0`
@@ -1,9 +1,9 @@
 //! Specific x86_64 architecture functionality
 
 #![no_std]
-#![feature(asm)]
 #![cfg(target_arch="x86_64")]
 
+use core::arch::asm;
 use core::convert::TryInto;
 pub use cpu_trait::CpuTrait;
 
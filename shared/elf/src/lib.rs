@@ -0,0 +1,751 @@
+//! Minimalistic, no-copy ELF64 parser used to extract loadable segments
+//!
+//! Mirrors the [`pe`](../pe/index.html) parser so that the bootloader can load a kernel
+//! built for `x86_64-unknown-none` or `aarch64-unknown-none` without a custom target.
+//!
+//! Reference: [`ELF-64 Object File Format`](https://uclibc.org/docs/elf-64-gen.pdf)
+
+#![no_std]
+// The `ErrorChain` is stored inline since there is no allocator to box it with
+#![allow(clippy::result_large_err)]
+
+use errchain::*;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Error {
+    /// `\x7fELF` magic missing from the beginning of the file
+    InvalidElfHeader,
+
+    /// The file is not a 64-bit ELF (`EI_CLASS` is not `ELFCLASS64`)
+    NotElf64,
+
+    /// The file is not little endian (`EI_DATA` is not `ELFDATA2LSB`)
+    NotLittleEndian,
+
+    /// The file is neither an executable nor a shared object
+    InvalidType,
+
+    /// The `e_machine` field is not a [`Machine`] known by this parser
+    UnknownMachine,
+
+    /// A header, segment, or section points outside of the file
+    OutOfBounds,
+
+    /// The size of a program header entry does not match `Elf64_Phdr`
+    InvalidProgramHeaderSize,
+
+    /// The size of a section header entry does not match `Elf64_Shdr`
+    InvalidSectionHeaderSize,
+
+    /// The size of a relocation entry does not match `Elf64_Rela`
+    InvalidRelocationSize,
+
+    /// A loadable segment has a larger size on disk than in memory
+    InvalidSegmentSize,
+
+    /// Parsed ELF has too many loadable segments for this implementation to parse.
+    /// Increase the `NUM_SEGMENTS` value to parse everything properly.
+    TooManySegments,
+
+    /// Parsed ELF has too many relocation tables for this implementation to parse.
+    /// Increase the `NUM_RELA_TABLES` value to parse everything properly.
+    TooManyRelocationTables,
+
+    /// The ELF does not contain any loadable segments
+    NoLoadableSegments,
+
+    /// A relocation type that is not supported by [`relocate`]
+    UnsupportedRelocation,
+
+    /// A relocation target that is not backed by file data of a loadable segment
+    RelocationOutOfBounds,
+}
+
+/// The architecture the image was built for
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Machine {
+    X86_64  = 62,
+    Aarch64 = 183
+}
+
+impl Machine {
+    /// Get the [`Machine`] for the raw `e_machine` value
+    fn from_raw(machine: u16) -> Option<Machine> {
+        match machine {
+            62  => Some(Machine::X86_64),
+            183 => Some(Machine::Aarch64),
+            _   => None
+        }
+    }
+
+    /// The relocation type that only adds the load bias to the addend
+    /// (`R_X86_64_RELATIVE` or `R_AARCH64_RELATIVE`)
+    fn relative_relocation(self) -> u32 {
+        match self {
+            Machine::X86_64  => 8,
+            Machine::Aarch64 => 1027,
+        }
+    }
+}
+
+/// Segment permissions parsed from the [`Flags`] of a program header
+#[derive(Debug, Copy, Clone)]
+pub struct SegmentPermissions {
+    /// This segment is executable
+    pub executable: bool,
+
+    /// This segment is readable
+    pub readable:   bool,
+
+    /// This segment is writable
+    pub writable:   bool
+}
+
+/// Segment [`flags`](https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.pheader.html)
+/// found in `p_flags`
+pub enum Flags {
+    /// This segment is executable
+    Execute = 0x1,
+
+    /// This segment is writable
+    Write = 0x2,
+
+    /// This segment is readable
+    Read = 0x4,
+}
+
+/// `e_ident` magic at the beginning of every ELF file
+const ELF_MAGIC: &[u8; 4] = b"\x7fELF";
+
+/// `EI_CLASS` value for 64-bit objects
+const ELFCLASS64: u8 = 2;
+
+/// `EI_DATA` value for little endian objects
+const ELFDATA2LSB: u8 = 1;
+
+/// `e_type` of an executable file
+const ET_EXEC: u16 = 2;
+
+/// `e_type` of a shared object (position independent executable)
+const ET_DYN: u16 = 3;
+
+/// `p_type` of a loadable segment
+const PT_LOAD: u32 = 1;
+
+/// `sh_type` of a relocation table with explicit addends
+const SHT_RELA: u32 = 4;
+
+/// `sh_flags` bit set for sections that occupy memory during execution
+const SHF_ALLOC: u64 = 0x2;
+
+/// Relocation type that does nothing for all supported machines
+const R_NONE: u32 = 0;
+
+/// ELF header from [`Elf64_Ehdr`](https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.eheader.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+struct ElfHeader {
+    /// Magic, class, data encoding, version, and ABI of the file
+    ident: [u8; 16],
+
+    /// Object file type
+    elf_type: u16,
+
+    /// The architecture the file was built for
+    machine: u16,
+
+    /// Object file version
+    version: u32,
+
+    /// Virtual address of the entry point
+    entry: u64,
+
+    /// File offset of the program header table
+    phoff: u64,
+
+    /// File offset of the section header table
+    shoff: u64,
+
+    /// Processor specific flags
+    flags: u32,
+
+    /// Size of this header
+    ehsize: u16,
+
+    /// Size of one program header entry
+    phentsize: u16,
+
+    /// Number of program header entries
+    phnum: u16,
+
+    /// Size of one section header entry
+    shentsize: u16,
+
+    /// Number of section header entries
+    shnum: u16,
+
+    /// Index of the section holding the section names
+    shstrndx: u16,
+}
+
+/// Program header from [`Elf64_Phdr`](https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.pheader.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+struct ProgramHeader {
+    /// Kind of segment described by this header
+    segment_type: u32,
+
+    /// Permissions of the segment
+    flags: u32,
+
+    /// File offset of the first byte of the segment
+    offset: u64,
+
+    /// Virtual address of the first byte of the segment in memory
+    vaddr: u64,
+
+    /// Physical address of the segment, where relevant
+    paddr: u64,
+
+    /// Number of bytes of the segment in the file
+    filesz: u64,
+
+    /// Number of bytes of the segment in memory. The bytes past `filesz` are zeroed.
+    memsz: u64,
+
+    /// Alignment of the segment in the file and in memory
+    align: u64,
+}
+
+impl ProgramHeader {
+    /// Get the [`SegmentPermissions`] for this segment
+    fn permissions(&self) -> SegmentPermissions {
+        SegmentPermissions {
+            executable: self.flags & Flags::Execute as u32 > 0,
+            readable:   self.flags & Flags::Read    as u32 > 0,
+            writable:   self.flags & Flags::Write   as u32 > 0
+        }
+    }
+}
+
+/// Section header from [`Elf64_Shdr`](https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.sheader.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+struct SectionHeader {
+    /// Offset of the name of the section in the section name string table
+    name: u32,
+
+    /// Kind of section described by this header
+    section_type: u32,
+
+    /// Attributes of the section
+    flags: u64,
+
+    /// Virtual address of the section in memory
+    addr: u64,
+
+    /// File offset of the section
+    offset: u64,
+
+    /// Size of the section in bytes
+    size: u64,
+
+    /// Section index of an associated section
+    link: u32,
+
+    /// Extra information about the section
+    info: u32,
+
+    /// Alignment of the section
+    addralign: u64,
+
+    /// Size of each entry for sections containing fixed size entries
+    entsize: u64,
+}
+
+/// Relocation entry from [`Elf64_Rela`](https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.reloc.html)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+struct RawRela {
+    /// Virtual address of the storage unit affected by the relocation
+    offset: u64,
+
+    /// Symbol index (upper 32 bits) and relocation type (lower 32 bits)
+    info: u64,
+
+    /// Constant addend used to compute the relocated value
+    addend: i64,
+}
+
+/// A relocation with an explicit addend found in a `SHT_RELA` section
+#[derive(Debug, Copy, Clone)]
+pub struct Rela {
+    /// Virtual address of the storage unit affected by the relocation
+    pub offset: u64,
+
+    /// Machine specific relocation type
+    pub kind: u32,
+
+    /// Index of the symbol the relocation is made against
+    pub symbol: u32,
+
+    /// Constant addend used to compute the relocated value
+    pub addend: i64,
+}
+
+/// Parsed information from a given ELF file
+pub struct Parsed<'a> {
+    /// Parsed loadable segments with their address relative to `image_base` and their
+    /// permissions
+    pub segments: [Option<(&'a [u8], u64, SegmentPermissions)>; NUM_SEGMENTS],
+
+    /// Page aligned lowest virtual address of all loadable segments
+    pub image_base: u64,
+
+    /// Entry point of the ELF file
+    pub entry_point: u64,
+
+    /// The architecture the ELF file was built for
+    pub machine: Machine,
+
+    /// The raw ELF file
+    data: &'a [u8],
+
+    /// Program headers of the loadable segments, used to translate virtual addresses
+    /// back into file offsets
+    load_headers: [Option<ProgramHeader>; NUM_SEGMENTS],
+
+    /// File offset and size of each allocated `SHT_RELA` table
+    rela_tables: [Option<(u64, u64)>; NUM_RELA_TABLES],
+}
+
+impl<'a> Parsed<'a> {
+    /// Iterate over the [`Rela`] entries of all allocated `SHT_RELA` sections
+    pub fn relocations(&self) -> impl Iterator<Item = Rela> + 'a {
+        let data        = self.data;
+        let rela_tables = self.rela_tables;
+
+        (0..NUM_RELA_TABLES).filter_map(move |table| rela_tables[table])
+            .flat_map(move |(offset, size)| {
+                let entry_size = core::mem::size_of::<RawRela>() as u64;
+
+                // The table bounds were checked during `parse`, so the reads can't fail
+                (0..size / entry_size).filter_map(move |index| {
+                    read::<RawRela>(data, offset + index * entry_size).ok()
+                })
+            })
+            .map(|raw| Rela {
+                offset: raw.offset,
+                kind:   raw.info as u32,
+                symbol: (raw.info >> 32) as u32,
+                addend: raw.addend
+            })
+    }
+
+    /// Translate the given virtual address into an offset into the ELF file if the
+    /// address is backed by file data of a loadable segment
+    pub fn file_offset(&self, vaddr: u64) -> Option<u64> {
+        file_offset(&self.load_headers, vaddr, 1)
+    }
+}
+
+/// Translate the given virtual address into an offset into the ELF file using the
+/// given loadable segment headers if all `size` bytes at the address are backed by file
+/// data of the same loadable segment
+fn file_offset(load_headers: &[Option<ProgramHeader>], vaddr: u64, size: u64)
+        -> Option<u64> {
+    load_headers.iter().flatten()
+        .find(|header| {
+            vaddr >= header.vaddr
+                && header.filesz.checked_sub(vaddr - header.vaddr)
+                    .is_some_and(|left| left >= size)
+        })
+        .map(|header| header.offset + (vaddr - header.vaddr))
+}
+
+/// Number of loadable segments that can be parsed and returned
+const NUM_SEGMENTS: usize = 8;
+
+/// Number of allocated relocation tables that can be parsed
+const NUM_RELA_TABLES: usize = 4;
+
+/// Get the `size` bytes at `offset` in `data`, checking that they are in bounds
+fn get_bytes(data: &[u8], offset: u64, size: u64) -> Result<&[u8]> {
+    let end = offset.checked_add(size);
+    ensure!(matches!(end, Some(end) if end <= data.len() as u64), &Error::OutOfBounds);
+
+    Ok(&data[offset as usize..end.unwrap() as usize])
+}
+
+/// Read a `T` at `offset` in `data`, checking that it is in bounds
+///
+/// `T` must only be made up of integers, so that every bit pattern is valid
+fn read<T: Copy>(data: &[u8], offset: u64) -> Result<T> {
+    let bytes = get_bytes(data, offset, core::mem::size_of::<T>() as u64)?;
+    Ok(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+}
+
+/// Parse the ELF file in `data`, validating every header, segment and relocation table
+/// that is used against the bounds of the file
+///
+/// # Errors
+///
+/// If `data` is not a little endian ELF64 executable for a known [`Machine`] or any of
+/// its headers are out of bounds
+pub fn parse(data: &[u8]) -> Result<Parsed<'_>> {
+    // Ensure the data begins with the ELF magic
+    ensure!(data.get(..4) == Some(&ELF_MAGIC[..]), &Error::InvalidElfHeader);
+
+    let header: ElfHeader = read(data, 0)?;
+
+    ensure!(header.ident[4] == ELFCLASS64,  &Error::NotElf64);
+    ensure!(header.ident[5] == ELFDATA2LSB, &Error::NotLittleEndian);
+    ensure!(header.elf_type == ET_EXEC || header.elf_type == ET_DYN, &Error::InvalidType);
+
+    let machine = match Machine::from_raw(header.machine) {
+        Some(machine) => machine,
+        None => return err!(&Error::UnknownMachine)
+    };
+
+    let phdr_size = core::mem::size_of::<ProgramHeader>() as u64;
+    ensure!(header.phnum == 0 || header.phentsize as u64 == phdr_size,
+        &Error::InvalidProgramHeaderSize);
+
+    // Ensure the entire program header table is in bounds before walking it
+    get_bytes(data, header.phoff, header.phnum as u64 * phdr_size)?;
+
+    // Init the returned parsed segments
+    let mut segments     = [None; NUM_SEGMENTS];
+    let mut load_headers = [None; NUM_SEGMENTS];
+    let mut num_segments = 0;
+    let mut image_base   = u64::MAX;
+
+    for phdr_num in 0..header.phnum as u64 {
+        let phdr: ProgramHeader = read(data, header.phoff + phdr_num * phdr_size)?;
+
+        // Only loadable segments are mapped by the bootloader
+        if phdr.segment_type != PT_LOAD {
+            continue;
+        }
+
+        ensure!(num_segments < NUM_SEGMENTS, &Error::TooManySegments);
+        ensure!(phdr.filesz <= phdr.memsz,    &Error::InvalidSegmentSize);
+        ensure!(phdr.vaddr.checked_add(phdr.memsz).is_some(), &Error::InvalidSegmentSize);
+
+        // Ensure the segment data is in bounds of the file
+        get_bytes(data, phdr.offset, phdr.filesz)?;
+
+        image_base = core::cmp::min(image_base, phdr.vaddr & !0xfff);
+        load_headers[num_segments] = Some(phdr);
+        num_segments += 1;
+    }
+
+    ensure!(num_segments > 0, &Error::NoLoadableSegments);
+
+    // Store the parsed segments relative to the image base
+    for (segment, phdr) in segments.iter_mut().zip(load_headers.iter()) {
+        if let Some(phdr) = phdr {
+            *segment = Some((
+                get_bytes(data, phdr.offset, phdr.filesz)?,
+                phdr.vaddr - image_base,
+                phdr.permissions()
+            ));
+        }
+    }
+
+    // Find the allocated relocation tables from the section headers
+    let mut rela_tables     = [None; NUM_RELA_TABLES];
+    let mut num_rela_tables = 0;
+
+    if header.shoff != 0 {
+        let shdr_size = core::mem::size_of::<SectionHeader>() as u64;
+        ensure!(header.shnum == 0 || header.shentsize as u64 == shdr_size,
+            &Error::InvalidSectionHeaderSize);
+
+        // Ensure the entire section header table is in bounds before walking it
+        get_bytes(data, header.shoff, header.shnum as u64 * shdr_size)?;
+
+        for shdr_num in 0..header.shnum as u64 {
+            let shdr: SectionHeader = read(data, header.shoff + shdr_num * shdr_size)?;
+
+            if shdr.section_type != SHT_RELA || shdr.flags & SHF_ALLOC == 0 {
+                continue;
+            }
+
+            let rela_size = core::mem::size_of::<RawRela>() as u64;
+            ensure!(shdr.entsize == rela_size && shdr.size.is_multiple_of(rela_size),
+                &Error::InvalidRelocationSize);
+            ensure!(num_rela_tables < NUM_RELA_TABLES, &Error::TooManyRelocationTables);
+
+            // Ensure the relocation table is in bounds of the file
+            get_bytes(data, shdr.offset, shdr.size)?;
+
+            rela_tables[num_rela_tables] = Some((shdr.offset, shdr.size));
+            num_rela_tables += 1;
+        }
+    }
+
+    Ok(Parsed {
+        segments,
+        image_base,
+        entry_point: header.entry,
+        machine,
+        data,
+        load_headers,
+        rela_tables
+    })
+}
+
+/// Apply the relocations of the ELF in `data` in place, assuming the image will be
+/// loaded `load_bias` bytes away from its linked addresses. Only the `RELATIVE`
+/// relocations emitted for static position independent executables are supported.
+///
+/// Returns the number of relocations applied
+///
+/// # Errors
+///
+/// If `data` is not a valid ELF, contains an unsupported relocation type, or has a
+/// relocation not backed by the file data of a loadable segment
+pub fn relocate(data: &mut [u8], load_bias: u64) -> Result<usize> {
+    // Only keep the copied header information so that `data` can be written to
+    let (machine, load_headers, rela_tables) = {
+        let parsed = parse(data)?;
+        (parsed.machine, parsed.load_headers, parsed.rela_tables)
+    };
+
+    let entry_size = core::mem::size_of::<RawRela>() as u64;
+    let mut applied = 0;
+
+    for &(offset, size) in rela_tables.iter().flatten() {
+        for index in 0..size / entry_size {
+            let rela: RawRela = read(data, offset + index * entry_size)?;
+
+            // Relocation type is stored in the lower 32 bits of the info
+            let kind = rela.info as u32;
+
+            if kind == R_NONE {
+                continue;
+            }
+
+            ensure!(kind == machine.relative_relocation(), &Error::UnsupportedRelocation);
+
+            // Ensure the entire relocated value is backed by the file data of a segment
+            let file_offset = match file_offset(&load_headers, rela.offset, 8) {
+                Some(file_offset) => file_offset,
+                None => return err!(&Error::RelocationOutOfBounds)
+            };
+
+            let value = load_bias.wrapping_add(rela.addend as u64);
+            let file_offset = file_offset as usize;
+            data[file_offset..file_offset + 8].copy_from_slice(&value.to_le_bytes());
+
+            applied += 1;
+        }
+    }
+
+    Ok(applied)
+}
+
+/// Returns `true` if the given data starts with the ELF magic
+pub fn is_elf(data: &[u8]) -> bool {
+    data.get(..4) == Some(&ELF_MAGIC[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// Virtual address the test image is linked at
+    const BASE: u64 = 0xffff_8888_0000_0000;
+
+    /// File offset and size of the file data of the loadable segment
+    const SEGMENT_OFFSET: u64 = 0x100;
+    const SEGMENT_SIZE:   u64 = 0x20;
+
+    /// File offsets of the relocation table and the section header table
+    const RELA_OFFSET: u64 = 0x120;
+    const SHDR_OFFSET: u64 = 0x140;
+
+    /// Size of the whole test image
+    const IMAGE_SIZE: usize = 0x180;
+
+    /// Copy the bytes of the integer only `value` into `data` at `offset`
+    fn write<T: Copy>(data: &mut [u8], offset: u64, value: T) {
+        let size  = core::mem::size_of::<T>();
+        let bytes = unsafe {
+            core::slice::from_raw_parts(&value as *const T as *const u8, size)
+        };
+        data[offset as usize..offset as usize + size].copy_from_slice(bytes);
+    }
+
+    /// Build a position independent executable with one loadable segment and one
+    /// relocation of `kind` at `target`, relative to the start of the segment
+    fn image(kind: u32, target: u64) -> Vec<u8> {
+        let mut data = std::vec![0u8; IMAGE_SIZE];
+
+        let mut ident = [0u8; 16];
+        ident[..4].copy_from_slice(ELF_MAGIC);
+        ident[4] = ELFCLASS64;
+        ident[5] = ELFDATA2LSB;
+
+        write(&mut data, 0, ElfHeader {
+            ident,
+            elf_type:  ET_DYN,
+            machine:   Machine::X86_64 as u16,
+            version:   1,
+            entry:     BASE + 0x1010,
+            phoff:     0x40,
+            shoff:     SHDR_OFFSET,
+            flags:     0,
+            ehsize:    0x40,
+            phentsize: core::mem::size_of::<ProgramHeader>() as u16,
+            phnum:     1,
+            shentsize: core::mem::size_of::<SectionHeader>() as u16,
+            shnum:     1,
+            shstrndx:  0
+        });
+
+        write(&mut data, 0x40, ProgramHeader {
+            segment_type: PT_LOAD,
+            flags:        Flags::Read as u32 | Flags::Execute as u32,
+            offset:       SEGMENT_OFFSET,
+            vaddr:        BASE + 0x1000,
+            paddr:        0,
+            filesz:       SEGMENT_SIZE,
+            memsz:        0x40,
+            align:        0x1000
+        });
+
+        write(&mut data, RELA_OFFSET, RawRela {
+            offset: BASE + 0x1000 + target,
+            info:   kind as u64,
+            addend: 0x1018
+        });
+
+        write(&mut data, SHDR_OFFSET, SectionHeader {
+            name:         0,
+            section_type: SHT_RELA,
+            flags:        SHF_ALLOC,
+            addr:         0,
+            offset:       RELA_OFFSET,
+            size:         core::mem::size_of::<RawRela>() as u64,
+            link:         0,
+            info:         0,
+            addralign:    8,
+            entsize:      core::mem::size_of::<RawRela>() as u64
+        });
+
+        data
+    }
+
+    /// Get the [`Error`] that started the chain of `result`
+    fn error<T>(result: Result<T>) -> Option<Error> {
+        result.err().and_then(|err| err.downcast_ref::<Error>().copied())
+    }
+
+    #[test]
+    fn test_parse() {
+        let data = image(8, 0);
+        let parsed = parse(&data).unwrap();
+
+        assert_eq!(parsed.machine, Machine::X86_64);
+        assert_eq!(parsed.entry_point, BASE + 0x1010);
+        assert_eq!(parsed.image_base, BASE + 0x1000);
+
+        let (bytes, offset, perms) = parsed.segments[0].unwrap();
+        assert_eq!(bytes.len() as u64, SEGMENT_SIZE);
+        assert_eq!(offset, 0);
+        assert!(perms.readable && perms.executable && !perms.writable);
+        assert!(parsed.segments[1..].iter().all(Option::is_none));
+
+        let relocations: Vec<Rela> = parsed.relocations().collect();
+        assert_eq!(relocations.len(), 1);
+        assert_eq!(relocations[0].offset, BASE + 0x1000);
+        assert_eq!(relocations[0].kind, 8);
+        assert_eq!(relocations[0].addend, 0x1018);
+
+        assert_eq!(parsed.file_offset(BASE + 0x1008), Some(SEGMENT_OFFSET + 8));
+        assert_eq!(parsed.file_offset(BASE + 0x1000 + SEGMENT_SIZE), None);
+        assert!(is_elf(&data));
+    }
+
+    #[test]
+    fn test_bad_header() {
+        let mut data = image(8, 0);
+        data[0] = 0;
+        assert!(!is_elf(&data));
+        assert!(matches!(error(parse(&data)), Some(Error::InvalidElfHeader)));
+
+        let mut data = image(8, 0);
+        data[4] = 1;
+        assert!(matches!(error(parse(&data)), Some(Error::NotElf64)));
+
+        let mut data = image(8, 0);
+        write(&mut data, 0x12, 3u16);
+        assert!(matches!(error(parse(&data)), Some(Error::UnknownMachine)));
+
+        // Truncated in the middle of the ELF header
+        assert!(matches!(error(parse(&data[..0x20])), Some(Error::OutOfBounds)));
+    }
+
+    #[test]
+    fn test_bad_program_headers() {
+        // Program header table past the end of the file
+        let mut data = image(8, 0);
+        write(&mut data, 0x20, IMAGE_SIZE as u64);
+        assert!(matches!(error(parse(&data)), Some(Error::OutOfBounds)));
+
+        // Segment data past the end of the file
+        let mut data = image(8, 0);
+        write(&mut data, 0x40 + 0x08, IMAGE_SIZE as u64);
+        assert!(matches!(error(parse(&data)), Some(Error::OutOfBounds)));
+
+        // Larger on disk than in memory
+        let mut data = image(8, 0);
+        write(&mut data, 0x40 + 0x28, 0x10u64);
+        assert!(matches!(error(parse(&data)), Some(Error::InvalidSegmentSize)));
+
+        // No loadable segment
+        let mut data = image(8, 0);
+        write(&mut data, 0x40, 0u32);
+        assert!(matches!(error(parse(&data)), Some(Error::NoLoadableSegments)));
+    }
+
+    #[test]
+    fn test_relocate() {
+        let mut data = image(8, 8);
+        assert_eq!(relocate(&mut data, 0x5000).unwrap(), 1);
+
+        assert_eq!(read::<u64>(&data, SEGMENT_OFFSET + 8).unwrap(), 0x6018);
+
+        // `R_NONE` is skipped
+        let mut data = image(R_NONE, 8);
+        assert_eq!(relocate(&mut data, 0x5000).unwrap(), 0);
+
+        // `R_X86_64_64` needs a symbol
+        let mut data = image(1, 8);
+        assert!(matches!(error(relocate(&mut data, 0x5000)),
+            Some(Error::UnsupportedRelocation)));
+    }
+
+    #[test]
+    fn test_relocate_out_of_bounds() {
+        // The last 8 bytes of the file data can be relocated
+        let mut data = image(8, SEGMENT_SIZE - 8);
+        assert_eq!(relocate(&mut data, 0x5000).unwrap(), 1);
+
+        // A value crossing the end of the file data would write into the relocation table
+        let mut data = image(8, SEGMENT_SIZE - 4);
+        assert!(matches!(error(relocate(&mut data, 0x5000)),
+            Some(Error::RelocationOutOfBounds)));
+
+        // Only the zeroed part of the segment
+        let mut data = image(8, SEGMENT_SIZE);
+        assert!(matches!(error(relocate(&mut data, 0x5000)),
+            Some(Error::RelocationOutOfBounds)));
+    }
+}
@@ -0,0 +1,161 @@
+//! Return address capture by walking the frame pointer chain when an [`ErrorChain`] is
+//! started.
+//!
+//! The walk trusts every frame pointer it finds, so it is only sound in a binary built
+//! with `-C force-frame-pointers=yes` whose outermost frame has a zero frame pointer.
+//! Without frame pointers the walk reads through whatever the frame pointer register
+//! happens to hold.
+//!
+//! [`ErrorChain`]: crate::ErrorChain
+
+/// Maximum number of return addresses kept in a [`Backtrace`]
+pub const MAX_BACKTRACE_FRAMES: usize = 16;
+
+/// Return addresses of the call stack at the time of capture, innermost first
+#[derive(Copy, Clone)]
+pub struct Backtrace {
+    /// Captured return addresses
+    frames: [u64; MAX_BACKTRACE_FRAMES],
+
+    /// Number of valid addresses in `frames`
+    len: usize
+}
+
+impl Backtrace {
+    /// Walk the frame pointers of the current call stack
+    ///
+    /// # Safety
+    ///
+    /// Every frame pointer reachable from the caller must either be zero or point to a
+    /// readable frame record: the previous frame pointer followed by the return address.
+    /// This holds when every function on the call stack was built with
+    /// `-C force-frame-pointers=yes` and the outermost frame has a zero frame pointer.
+    #[inline(always)]
+    pub unsafe fn capture() -> Self {
+        // SAFETY: the frame pointer of the caller is reachable from the caller
+        Self::walk(frame_pointer())
+    }
+
+    /// Walk the frame records starting with the one at `frame`
+    ///
+    /// # Safety
+    ///
+    /// `frame` and every frame pointer reachable from it must either be zero or point
+    /// to a readable frame record
+    unsafe fn walk(mut frame: u64) -> Self {
+        let mut backtrace = Backtrace { frames: [0; MAX_BACKTRACE_FRAMES], len: 0 };
+
+        while backtrace.len < MAX_BACKTRACE_FRAMES && frame != 0 && frame & 7 == 0 {
+            // Both x86_64 and aarch64 store the previous frame pointer at the frame
+            // pointer, followed by the return address
+            let (next, ret) = {
+                let frame = frame as *const u64;
+                (frame.read(), frame.add(1).read())
+            };
+
+            if ret == 0 {
+                break;
+            }
+
+            backtrace.frames[backtrace.len] = ret;
+            backtrace.len += 1;
+
+            // The stack grows down, so the caller's frame must be above this one
+            if next <= frame {
+                break;
+            }
+
+            frame = next;
+        }
+
+        backtrace
+    }
+
+    /// Get the captured return addresses, innermost first
+    pub fn frames(&self) -> &[u64] {
+        &self.frames[..self.len]
+    }
+}
+
+/// Get the frame pointer of the caller
+#[inline(always)]
+fn frame_pointer() -> u64 {
+    let frame: u64;
+
+    #[cfg(target_arch = "x86_64")]
+    unsafe { core::arch::asm!("mov {}, rbp", out(reg) frame); }
+
+    #[cfg(target_arch = "aarch64")]
+    unsafe { core::arch::asm!("mov {}, x29", out(reg) frame); }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    { frame = 0; }
+
+    frame
+}
+
+impl core::fmt::Debug for Backtrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        writeln!(f, "Backtrace:")?;
+
+        for (i, frame) in self.frames().iter().enumerate() {
+            writeln!(f, "  {:2}: {:#018x}", i, frame)?;
+        }
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        // Three frame records, each the previous frame pointer and the return address,
+        // with the outermost one ending the chain
+        let mut stack = [0u64; 6];
+        let base = stack.as_ptr() as u64;
+        stack[0] = base + 16;
+        stack[1] = 0x1000;
+        stack[2] = base + 32;
+        stack[3] = 0x2000;
+        stack[4] = 0;
+        stack[5] = 0x3000;
+
+        // SAFETY: every frame pointer in the chain points into `stack` or is zero
+        let backtrace = unsafe { Backtrace::walk(stack.as_ptr() as u64) };
+        assert_eq!(backtrace.frames(), &[0x1000, 0x2000, 0x3000]);
+
+        let debug = format!("{:?}", backtrace);
+        let lines: std::vec::Vec<_> = debug.lines().collect();
+        assert_eq!(lines, ["Backtrace:", "   0: 0x0000000000001000",
+            "   1: 0x0000000000002000", "   2: 0x0000000000003000"]);
+
+        // A frame pointer pointing down the stack ends the walk
+        stack[2] = base;
+        let backtrace = unsafe { Backtrace::walk(stack.as_ptr() as u64) };
+        assert_eq!(backtrace.frames(), &[0x1000, 0x2000]);
+    }
+
+    #[test]
+    fn test_capture() {
+        /// Capture from a frame of its own so there is at least one return address
+        #[inline(never)]
+        fn capture() -> Backtrace {
+            // SAFETY: unoptimized test builds keep the frame pointers, and the thread
+            // the test runs on starts with a zero frame pointer
+            unsafe { Backtrace::capture() }
+        }
+
+        let backtrace = capture();
+        assert!(!backtrace.frames().is_empty());
+        assert!(backtrace.frames().iter().all(|&frame| frame != 0));
+
+        let debug = format!("{:?}", backtrace);
+        assert_eq!(debug.lines().count(), backtrace.frames().len() + 1);
+    }
+}
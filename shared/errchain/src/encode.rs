@@ -0,0 +1,399 @@
+//! Compact binary encoding of an [`ErrorChain`] so that a chain can be handed to another
+//! environment, such as from a kernel core to the bootloader or a host tool.
+//!
+//! Layout (all integers little endian):
+//!
+//! ```text
+//! magic:   [u8; 4] = b"ECHN"
+//! entries: u16
+//! elided:  u16
+//! dropped: u16
+//! entry:   line: u32, file_len: u16, file: [u8], message_len: u16, truncated: u8,
+//!          message: [u8]
+//! ```
+//!
+//! The message of each entry is the `Debug` rendering of the error at that link.
+//! `truncated` is non-zero if the end of the message didn't fit.
+//! `elided` counts the messages missing between the last two entries and `dropped`
+//! counts the messages missing after the last entry.
+
+use core::convert::{TryFrom, TryInto};
+use core::fmt::Write;
+
+use crate::{ErrorChain, Message, TruncatingWriter};
+
+/// Magic bytes at the start of every encoded chain
+const MAGIC: &[u8; 4] = b"ECHN";
+
+/// Size of the magic and the numbers of entries, elided and dropped messages
+const HEADER_LEN: usize = MAGIC.len() + 6;
+
+/// Offset of the number of elided messages in the header
+const ELIDED_OFFSET: usize = MAGIC.len() + 2;
+
+/// Offset of the number of dropped messages in the header
+const DROPPED_OFFSET: usize = MAGIC.len() + 4;
+
+impl<const N: usize> ErrorChain<N> {
+    /// Encode this chain into `buffer`, returning the number of bytes written. Entries
+    /// that don't fit are dropped from the end of the chain and messages that don't fit
+    /// are truncated. Returns `None` if `buffer` can't hold the header.
+    pub fn encode(&self, buffer: &mut [u8]) -> Option<usize> {
+        if buffer.len() < HEADER_LEN {
+            return None;
+        }
+
+        buffer[..MAGIC.len()].copy_from_slice(MAGIC);
+
+        let mut offset  = HEADER_LEN;
+        let mut entries = 0u16;
+
+        for Message { file, line, error } in self.iter() {
+            // Line, file length, file, the length of the message and its truncated flag
+            let fixed_len = 4 + 2 + file.len() + 2 + 1;
+            if buffer.len() - offset < fixed_len || file.len() > usize::from(u16::MAX) {
+                break;
+            }
+
+            buffer[offset..offset + 4].copy_from_slice(&line.to_le_bytes());
+            buffer[offset + 4..offset + 6]
+                .copy_from_slice(&(file.len() as u16).to_le_bytes());
+            buffer[offset + 6..offset + 6 + file.len()].copy_from_slice(file.as_bytes());
+            offset += 6 + file.len();
+
+            // Render the message directly after its length and truncated flag
+            let message_start = offset + 3;
+            let message_end   = core::cmp::min(buffer.len(),
+                message_start + usize::from(u16::MAX));
+            let mut writer =
+                TruncatingWriter::new(&mut buffer[message_start..message_end]);
+            let _ = write!(writer, "{:?}", error);
+            let (message_len, truncated) = (writer.len(), writer.is_truncated());
+
+            buffer[offset..offset + 2]
+                .copy_from_slice(&(message_len as u16).to_le_bytes());
+            buffer[offset + 2] = u8::from(truncated);
+            offset = message_start + message_len;
+
+            entries += 1;
+        }
+
+        // The elided messages come before the newest message of the chain, so they end
+        // up after the last entry once any entry didn't fit
+        let missing = self.iter().len() - usize::from(entries);
+        let (elided, dropped) = match (self.iter().len() < 2, missing) {
+            (false, 0) => (self.elided(), 0),
+            _          => (0, self.elided() + missing)
+        };
+        let elided  = u16::try_from(elided).unwrap_or(u16::MAX);
+        let dropped = u16::try_from(dropped).unwrap_or(u16::MAX);
+
+        buffer[MAGIC.len()..ELIDED_OFFSET].copy_from_slice(&entries.to_le_bytes());
+        buffer[ELIDED_OFFSET..DROPPED_OFFSET].copy_from_slice(&elided.to_le_bytes());
+        buffer[DROPPED_OFFSET..HEADER_LEN].copy_from_slice(&dropped.to_le_bytes());
+
+        Some(offset)
+    }
+}
+
+/// One decoded link of an [`EncodedChain`]
+#[derive(Debug, Copy, Clone)]
+pub struct EncodedMessage<'a> {
+    /// File name of this link in the chain
+    pub file: &'a str,
+
+    /// Line in the file of this link in the chain
+    pub line: u32,
+
+    /// Rendered error of this link in the chain
+    pub message: &'a str,
+
+    /// Set if the end of `message` didn't fit in the encoded chain
+    pub truncated: bool
+}
+
+/// A chain decoded from the bytes written by [`ErrorChain::encode`]
+#[derive(Copy, Clone)]
+pub struct EncodedChain<'a> {
+    /// Encoded entries following the header
+    data: &'a [u8],
+
+    /// Number of entries in `data`
+    entries: usize,
+
+    /// Number of messages missing between the last two entries
+    elided: usize,
+
+    /// Number of messages missing after the last entry
+    dropped: usize
+}
+
+impl<'a> EncodedChain<'a> {
+    /// Decode the chain found at the start of `data`. Returns `None` if `data` is not a
+    /// valid encoded chain.
+    pub fn decode(data: &'a [u8]) -> Option<Self> {
+        if data.get(..MAGIC.len())? != MAGIC {
+            return None;
+        }
+
+        let entries = data.get(MAGIC.len()..ELIDED_OFFSET)?.try_into().ok()?;
+        let entries = usize::from(u16::from_le_bytes(entries));
+        let elided  = data.get(ELIDED_OFFSET..DROPPED_OFFSET)?.try_into().ok()?;
+        let elided  = usize::from(u16::from_le_bytes(elided));
+        let dropped = data.get(DROPPED_OFFSET..HEADER_LEN)?.try_into().ok()?;
+        let dropped = usize::from(u16::from_le_bytes(dropped));
+        let chain   = EncodedChain {
+            data: &data[HEADER_LEN..], entries, elided, dropped
+        };
+
+        // Validate every entry up front so iterating can't fail
+        let mut remaining = chain.data;
+        for _ in 0..chain.entries {
+            let (_, rest) = read_message(remaining)?;
+            remaining = rest;
+        }
+
+        Some(chain)
+    }
+
+    /// Number of links in the chain
+    pub fn len(&self) -> usize {
+        self.entries
+    }
+
+    /// Returns `true` if the chain has no links
+    pub fn is_empty(&self) -> bool {
+        self.entries == 0
+    }
+
+    /// Number of messages missing between the last two links of the chain
+    pub fn elided(&self) -> usize {
+        self.elided
+    }
+
+    /// Number of messages missing after the last link of the chain
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Iterate over the links of the chain, starting from the root cause
+    pub fn iter(&self) -> EncodedMessages<'a> {
+        EncodedMessages { data: self.data, remaining: self.entries }
+    }
+}
+
+/// Read one encoded entry from the start of `data`, returning the entry and the bytes
+/// following it
+fn read_message(data: &[u8]) -> Option<(EncodedMessage<'_>, &[u8])> {
+    let line     = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+    let file_len = usize::from(u16::from_le_bytes(data.get(4..6)?.try_into().ok()?));
+    let file     = core::str::from_utf8(data.get(6..6 + file_len)?).ok()?;
+
+    let data        = &data[6 + file_len..];
+    let message_len = usize::from(u16::from_le_bytes(data.get(..2)?.try_into().ok()?));
+    let truncated   = *data.get(2)? != 0;
+    let message     = core::str::from_utf8(data.get(3..3 + message_len)?).ok()?;
+
+    Some((EncodedMessage { file, line, message, truncated }, &data[3 + message_len..]))
+}
+
+/// Iterator over the links of an [`EncodedChain`]
+pub struct EncodedMessages<'a> {
+    /// Entries not yet returned
+    data: &'a [u8],
+
+    /// Number of entries not yet returned
+    remaining: usize
+}
+
+impl<'a> Iterator for EncodedMessages<'a> {
+    type Item = EncodedMessage<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let (message, rest) = read_message(self.data)?;
+        self.data       = rest;
+        self.remaining -= 1;
+
+        Some(message)
+    }
+}
+
+impl<'a> core::fmt::Debug for EncodedChain<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let max_padding = self.iter()
+            .map(|message| message.file.len() + crate::line_len(message.line))
+            .max()
+            .unwrap_or(0);
+
+        let _ = writeln!(f);
+
+        for (i, EncodedMessage { file, line, message, truncated })
+                in self.iter().enumerate() {
+            // The elided messages were between the last two messages of the chain
+            if self.elided > 0 && i + 1 == self.entries && i > 0 {
+                crate::write_elided(f, max_padding, self.elided);
+            }
+
+            let marker = if truncated { crate::format::TRUNCATED_MARKER } else { "" };
+            crate::write_message(f, max_padding, file, line,
+                format_args!("{}{}", message, marker));
+        }
+
+        // A chain with fewer than two links has nothing to put elided messages between
+        let trailing = match self.entries {
+            0 | 1 => self.elided + self.dropped,
+            _     => self.dropped
+        };
+
+        if trailing > 0 {
+            crate::write_elided(f, max_padding, trailing);
+        }
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::format;
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::Context;
+
+    /// Create a chain holding up to `N` links from a root cause and `contexts` contexts
+    fn chain<const N: usize>(contexts: usize) -> ErrorChain<N> {
+        let mut result: core::result::Result<(), ErrorChain<N>> =
+            Err(ErrorChain::new_with_debug("root.rs", 1, &"root"));
+
+        for _ in 0..contexts {
+            result = result.context(&"context");
+        }
+
+        result.unwrap_err()
+    }
+
+    /// Get the lines of the `Debug` output of `chain`
+    fn lines(chain: &dyn core::fmt::Debug) -> Vec<std::string::String> {
+        format!("{:?}", chain).lines().map(Into::into).collect()
+    }
+
+    #[test]
+    fn test_elided() {
+        let chain = chain::<3>(4);
+        assert_eq!(chain.elided(), 2);
+
+        let mut buffer = [0u8; 512];
+        let len     = chain.encode(&mut buffer).unwrap();
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded.elided(), 2);
+        assert_eq!(decoded.dropped(), 0);
+
+        // The original chain may be followed by its backtrace
+        assert_eq!(lines(&decoded).len(), 5);
+
+        // The marker sits between the last two links in both renderings
+        for lines in [lines(&chain), lines(&decoded)] {
+            assert!(lines[3].ends_with("... 2 frames elided"));
+            assert!(lines[4].ends_with("\"context\""));
+        }
+    }
+
+    #[test]
+    fn test_dropped() {
+        let chain = chain::<3>(4);
+
+        // Only room for the root cause
+        let mut buffer = [0u8; HEADER_LEN + 4 + 2 + 7 + 3 + 6];
+        let len     = chain.encode(&mut buffer).unwrap();
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded.elided(), 0);
+        assert_eq!(decoded.dropped(), 4);
+
+        let lines = lines(&decoded);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].ends_with("\"root\""));
+        assert!(lines[2].ends_with("... 4 frames elided"));
+    }
+
+    #[test]
+    fn test_single_link() {
+        let chain = chain::<1>(2);
+        assert_eq!(chain.elided(), 2);
+
+        let mut buffer = [0u8; 512];
+        let len     = chain.encode(&mut buffer).unwrap();
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        assert_eq!(decoded.elided(), 0);
+        assert_eq!(decoded.dropped(), 2);
+
+        // The original chain may be followed by its backtrace
+        assert_eq!(lines(&decoded).len(), 3);
+
+        // Nothing to put the marker between, so it follows the only link
+        for lines in [lines(&chain), lines(&decoded)] {
+            assert!(lines[1].ends_with("\"root\""));
+            assert!(lines[2].ends_with("... 2 frames elided"));
+        }
+    }
+
+    /// Error whose `Debug` output is written in several pieces
+    struct Pieces;
+
+    impl core::fmt::Debug for Pieces {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            for piece in ["aaaa", "é", "c"] {
+                f.write_str(piece)?;
+            }
+
+            core::fmt::Result::Ok(())
+        }
+    }
+
+    #[test]
+    fn test_truncated_message() {
+        let chain: ErrorChain<1> = ErrorChain::new_with_debug("root.rs", 1, &Pieces);
+
+        // Room for "aaaa" and one byte of the 'é'
+        let mut buffer = [0u8; HEADER_LEN + 4 + 2 + 7 + 3 + 5];
+        let len     = chain.encode(&mut buffer).unwrap();
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        // The "c" after the cut 'é' is dropped as well
+        let message = decoded.iter().next().unwrap();
+        assert_eq!(message.message, "aaaa");
+        assert!(message.truncated);
+        assert!(lines(&decoded)[1].ends_with("aaaa..."));
+
+        let mut buffer = [0u8; 512];
+        let len     = chain.encode(&mut buffer).unwrap();
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        let message = decoded.iter().next().unwrap();
+        assert_eq!(message.message, "aaaaéc");
+        assert!(!message.truncated);
+    }
+
+    #[test]
+    fn test_large_line() {
+        let mut buffer = [0u8; 512];
+        let len = chain::<1>(0).encode(&mut buffer).unwrap();
+
+        // Corrupt the line of the root cause, which is the first field of its entry
+        buffer[HEADER_LEN..HEADER_LEN + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let decoded = EncodedChain::decode(&buffer[..len]).unwrap();
+
+        assert!(lines(&decoded)[1].starts_with("root.rs:4294967295:"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Fixed size buffer used to store messages formatted at runtime without an allocator
+
+use core::fmt::Write;
+
+/// Maximum number of bytes of a formatted message. Longer messages are truncated.
+pub const MAX_MESSAGE_LEN: usize = 64;
+
+/// Marker written after a formatted message that did not fit in the buffer
+pub(crate) const TRUNCATED_MARKER: &str = "...";
+
+/// Writes formatted text into a byte slice. Whatever doesn't fit is dropped on a `char`
+/// boundary, and once a write was cut short every later write is dropped as well, so
+/// the text never loses characters from its middle.
+pub struct TruncatingWriter<'a> {
+    /// Buffer written to
+    buffer: &'a mut [u8],
+
+    /// Number of valid bytes in `buffer`
+    len: usize,
+
+    /// Set once a write didn't fit
+    truncated: bool
+}
+
+impl<'a> TruncatingWriter<'a> {
+    /// Create a writer starting at the beginning of `buffer`
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0, truncated: false }
+    }
+
+    /// Number of bytes written to the buffer
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was written to the buffer
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if part of the text did not fit in the buffer
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        // Text after a dropped piece would read as if nothing was missing
+        if self.truncated {
+            return core::fmt::Result::Ok(());
+        }
+
+        let remaining = self.buffer.len() - self.len;
+
+        // Find the longest prefix of `string` that fits without splitting a `char`
+        let mut to_copy = core::cmp::min(remaining, string.len());
+        while !string.is_char_boundary(to_copy) {
+            to_copy -= 1;
+        }
+
+        if to_copy < string.len() {
+            self.truncated = true;
+        }
+
+        self.buffer[self.len..self.len + to_copy]
+            .copy_from_slice(&string.as_bytes()[..to_copy]);
+        self.len += to_copy;
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+/// A message formatted into an inline buffer. Formatting never fails: anything that
+/// doesn't fit is dropped on a `char` boundary so the buffer is always valid UTF-8.
+#[derive(Copy, Clone)]
+pub struct FormatBuffer {
+    /// Formatted bytes of the message
+    data: [u8; MAX_MESSAGE_LEN],
+
+    /// Number of valid bytes in `data`
+    len: usize,
+
+    /// Set if part of the message was dropped
+    truncated: bool
+}
+
+impl FormatBuffer {
+    /// Create an empty [`FormatBuffer`]
+    pub const fn new() -> Self {
+        Self {
+            data:      [0; MAX_MESSAGE_LEN],
+            len:       0,
+            truncated: false
+        }
+    }
+
+    /// Format the given arguments into a new [`FormatBuffer`]
+    pub fn from_args(args: core::fmt::Arguments) -> Self {
+        let mut buffer = Self::new();
+
+        // Writing into the buffer can't fail, it only truncates
+        let _ = buffer.write_fmt(args);
+
+        buffer
+    }
+
+    /// Get the formatted message
+    pub fn as_str(&self) -> &str {
+        // Only whole `char`s are ever copied into the buffer
+        core::str::from_utf8(&self.data[..self.len]).unwrap_or("")
+    }
+
+    /// Returns `true` if part of the message did not fit in the buffer
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl Default for FormatBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for FormatBuffer {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        let mut writer = TruncatingWriter {
+            buffer:    &mut self.data,
+            len:       self.len,
+            truncated: self.truncated
+        };
+
+        writer.write_str(string)?;
+
+        self.len       = writer.len;
+        self.truncated = writer.truncated;
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+impl core::fmt::Debug for FormatBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())?;
+
+        if self.truncated {
+            write!(f, "{}", TRUNCATED_MARKER)?;
+        }
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        let mut buffer = FormatBuffer::new();
+        for _ in 0..MAX_MESSAGE_LEN - 1 {
+            buffer.write_str("a").unwrap();
+        }
+
+        // The 'é' doesn't fit, so the 'z' after it is dropped as well
+        buffer.write_str("é").unwrap();
+        buffer.write_str("z").unwrap();
+
+        assert_eq!(buffer.as_str().len(), MAX_MESSAGE_LEN - 1);
+        assert!(buffer.as_str().bytes().all(|byte| byte == b'a'));
+        assert!(buffer.is_truncated());
+
+        let mut data = [0u8; 4];
+        let mut writer = TruncatingWriter::new(&mut data);
+        for piece in ["abc", "de", "f"] {
+            writer.write_str(piece).unwrap();
+        }
+        assert_eq!((writer.len(), writer.is_truncated()), (4, true));
+        assert_eq!(&data, b"abcd");
+    }
+}
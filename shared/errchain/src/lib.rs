@@ -1,14 +1,28 @@
 //! Error chaining library for the kernel that enables a call stack of where the error
 //! occurs.
 //!
-//! This module implements a custom `Result` type called [`ErrorChainResult`]. This is
-//! because we can't overwrite the [`Try`] impl for [`core::result::Result`], so we had to
-//! create our own type. 
+//! The [`Result`] of this crate is a [`core::result::Result`] with an [`ErrorChain`] as
+//! the error, so `?` works as usual. The location of each link in the chain is recorded
+//! when the chain is started (`err!`, `ensure!`, `add!`, ...) and each time
+//! [`Context::context`] is called on the way back up the call stack.
+//!
+//! A bare `?` does not add a link. `core::result::Result` passes an [`ErrorChain`] on
+//! with the identity `From` implemented by `core`, which can't be made `#[track_caller]`,
+//! so there is nothing to record where the `?` was. Recording every `?` would need a
+//! result type of its own built on the `Try` trait, which is unstable, and would give up
+//! the methods of `core::result::Result` the other crates rely on. A function only shows
+//! up in the chain if it adds a context to the error.
 //!
 //! ## Example
 //!
-//! ```
-//! use crate::errchain::{Err, Ok, Result, Context, ErrorChain, ErrorType};
+//! ```should_panic
+//! use errchain::prelude::*;
+//!
+//! #[derive(Debug)]
+//! enum Error {
+//!     TestCrashFailed,
+//!     MainFailed,
+//! }
 //!
 //! /// Safely add two numbers together. 
 //! pub fn safe() -> Result<u64> {
@@ -23,27 +37,21 @@
 //! }
 //!
 //! pub fn test_crash() -> Result<u64> {
-//!     // Example of adding a comment to the call stack
-//!     Ok(crash().context_str("crash function failed")?)
+//!     // Example of adding an error to the call stack
+//!     crash().context(&Error::TestCrashFailed)
 //! }
 //!
 //! /// Main entry point that can return a [Result]
 //! fn try_main() -> Result<()> {
-//!     // Example of using the `context` syntax to add a message into the `ErrorChain`
-//!     safe().context_str("Crashed during the safe call!")?;
+//!     safe()?;
 //!
-//!     // No context being used will just store the file!() and line!() of this crash on Err
-//!     test_crash()?;
+//!     test_crash().context(&Error::MainFailed)?;
 //!
 //!     print!("We didn't crash!\n");
 //!
 //!     Ok(())
 //! }
 //!
-//! pub fn always_err() -> Result<()> {
-//!     return Err(err_str!("This always errors"));
-//! }
-//!
 //! fn main() {
 //!     match try_main() {
 //!         Err(err) => {
@@ -60,32 +68,40 @@
 //!
 //! ```text
 //! --- MAIN ERROR ---
-//! src/main.rs:10: Arithmetic(SubUnderflow)
-//! src/main.rs:14: crash function failed
-//! src/main.rs:23: ...
+//! src/main.rs:17: SubUnderflow
+//! src/main.rs:22: TestCrashFailed
+//! src/main.rs:29: MainFailed
 //!
-//! thread 'main' panicked at 'MAIN ERROR', src/main.rs:35:13
+//! thread 'main' panicked at 'MAIN ERROR', src/main.rs:41:13
 //! ```
 
 #![no_std]
-#![feature(trait_alias)]
-#![feature(try_trait)]
-#![feature(associated_type_bounds)]
-#![feature(const_fn_unsize)]
-#![feature(const_fn_trait_bound)]
+// The `ErrorChain` is stored inline since there is no allocator to box it with
+#![allow(clippy::result_large_err)]
 
-use core::ops::Try;
 use core::fmt::Debug;
+use core::any::TypeId;
 
 pub mod prelude;
 
 mod types;
 pub use types::NumericalError;
 
+mod format;
+pub use format::{FormatBuffer, TruncatingWriter, MAX_MESSAGE_LEN};
+
+mod encode;
+pub use encode::{EncodedChain, EncodedMessage, EncodedMessages};
+
+#[cfg(feature = "backtrace")]
+mod backtrace;
+#[cfg(feature = "backtrace")]
+pub use backtrace::{Backtrace, MAX_BACKTRACE_FRAMES};
+
 
 // pub use types::*;
 
-/// Maximum length call stack
+/// Default maximum length call stack
 pub const MAX_CHAIN_LEN: usize = 8;
 
 #[derive(Copy, Clone)]
@@ -105,6 +121,62 @@ pub trait ErrorType: core::fmt::Debug {}
 impl ErrorType for str {}
 impl ErrorType for Error {}
 
+/// The error stored in each [`Message`] of the chain. Only created through
+/// [`Payload::new`] and the formatting macros, so the recorded type always matches the
+/// stored error.
+pub struct Payload(PayloadKind);
+
+/// The kinds of error a [`Payload`] can hold
+enum PayloadKind {
+    /// An error that lives for the rest of the program
+    Static {
+        /// The error itself
+        error: &'static dyn Debug,
+
+        /// Returns the [`TypeId`] of the concrete type behind `error`. Stored as a
+        /// function so that a [`Payload`] can still be created in `const` contexts.
+        type_id: fn() -> TypeId
+    },
+
+    /// A message formatted at runtime, such as with [`err_fmt!`]
+    Formatted(FormatBuffer)
+}
+
+impl Payload {
+    /// Create a [`Payload`] for the given error, remembering its type
+    pub fn new<E: Debug + 'static>(error: &'static E) -> Self {
+        Payload(PayloadKind::Static { error, type_id: TypeId::of::<E> })
+    }
+
+    /// Create a [`Payload`] holding a message formatted from `args`
+    fn formatted(args: core::fmt::Arguments) -> Self {
+        Payload(PayloadKind::Formatted(FormatBuffer::from_args(args)))
+    }
+
+    /// Create the [`Payload`] of an unused link of the chain
+    const fn empty() -> Self {
+        Payload(PayloadKind::Static { error: &Error::Empty, type_id: TypeId::of::<Error> })
+    }
+
+    /// Get the [`TypeId`] of the stored error. Formatted messages are a 
+    /// [`FormatBuffer`].
+    fn type_id(&self) -> TypeId {
+        match &self.0 {
+            PayloadKind::Static { type_id, .. } => type_id(),
+            PayloadKind::Formatted(_)           => TypeId::of::<FormatBuffer>()
+        }
+    }
+}
+
+impl core::fmt::Debug for Payload {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match &self.0 {
+            PayloadKind::Static { error, .. } => write!(f, "{:?}", error),
+            PayloadKind::Formatted(buffer)    => write!(f, "{:?}", buffer)
+        }
+    }
+}
+
 /// Each of the descriptions for the locations of a given error
 #[derive(Debug)]
 pub struct Message {
@@ -115,7 +187,7 @@ pub struct Message {
     line:  u32,
 
     /// Actual error
-    error: &'static dyn Debug
+    error: Payload
 }
 
 impl Message {
@@ -123,30 +195,79 @@ impl Message {
         Self {
             file:  "",
             line:  0,
-            error: &Error::Empty
+            error: Payload::empty()
+        }
+    }
+
+    /// File name of this link in the chain
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Line in the file of this link in the chain
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The error recorded at this link in the chain
+    pub fn error(&self) -> &Payload {
+        &self.error
+    }
+
+    /// Returns `true` if the error at this link is a `T`
+    pub fn is<T: 'static>(&self) -> bool {
+        self.error.type_id() == TypeId::of::<T>()
+    }
+
+    /// Get the error at this link as a `T` if it is a `T`
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        if !self.is::<T>() {
+            return None;
+        }
+
+        // Safe since the type of `error` was checked to be `T` above, and the type is
+        // only ever recorded along with an error of that type
+        match &self.error.0 {
+            PayloadKind::Static { error, .. } => {
+                Some(unsafe { &*(*error as *const dyn Debug as *const T) })
+            }
+            PayloadKind::Formatted(buffer) => {
+                Some(unsafe { &*(buffer as *const FormatBuffer as *const T) })
+            }
         }
     }
 }
 
-/// Error struct that holds the current chain of contexts that caused the given error
-pub struct ErrorChain {
+/// Error struct that holds the current chain of contexts that caused the given error.
+///
+/// At most `N` messages are kept. Once the chain is full, the root cause and the first
+/// contexts are kept, the newest message replaces the last one, and the number of
+/// messages dropped in between is shown as an elided marker.
+pub struct ErrorChain<const N: usize = MAX_CHAIN_LEN> {
     /// Chain of messages 
-    chain: [Message; MAX_CHAIN_LEN],
+    chain: [Message; N],
 
     /// Current length of the error chain
     chain_len: usize,
 
+    /// Number of messages dropped from the chain because it was full
+    elided: usize,
+
     /// Maximum length of `[file:line]` string for the current chain. Used in padding the 
     /// format string
     max_padding: usize,
+
+    /// Return addresses of the call stack where the chain was started
+    #[cfg(feature = "backtrace")]
+    backtrace: Backtrace,
 }
 
-impl ErrorChain {
+impl<const N: usize> ErrorChain<N> {
 
     /// Create a new chain using the current `Location` information
     #[track_caller]
     #[allow(dead_code)]
-    pub fn new(error: &'static dyn Debug) -> Self {
+    pub fn new<E: Debug + 'static>(error: &'static E) -> Self {
         let caller = core::panic::Location::caller();
 
         Self::new_with_debug(caller.file(), caller.line(), error)
@@ -154,33 +275,48 @@ impl ErrorChain {
 
     /// Create a new chain using the given `file`, `line`, and [`ErrorType`]
     // pub fn new_with_debug(file: &'static str, line: u32, error: &'static ErrorType) -> Self {
-    pub fn new_with_debug(file: &'static str, line: u32, error: &'static dyn Debug) -> Self {
+    pub fn new_with_debug<E: Debug + 'static>(file: &'static str, line: u32, 
+            error: &'static E) -> Self {
+        Self::new_with_payload(file, line, Payload::new(error))
+    }
+
+    /// Create a new chain using the given `file`, `line`, and message formatted from
+    /// `args`. The message is truncated to [`MAX_MESSAGE_LEN`] bytes.
+    pub fn new_with_fmt(file: &'static str, line: u32, args: core::fmt::Arguments)
+        -> Self {
+        Self::new_with_payload(file, line, Payload::formatted(args))
+    }
+
+    /// Create a new chain using the given `file`, `line`, and [`Payload`]
+    fn new_with_payload(file: &'static str, line: u32, error: Payload) -> Self {
         const VAL: Message = Message::empty();
 
         // Create a new chain using debug information
         // let mut chain = [Message::empty(); MAX_CHAIN_LEN];
-        let mut chain = [VAL; MAX_CHAIN_LEN];
+        let mut chain = [VAL; N];
 
         // Insert the given error into the chain
-        chain[0] = Message { file, line, error };
-
-        // Calculate the number of digits in the line to know the padding needed to
-        // pretty print the call stack on panic
-        let line_len = match line {
-                  0..=9       => 1,
-                 10..=99      => 2,
-                100..=999     => 3,
-               1000..=9999    => 4,
-              10000..=99999   => 5,
-            100_000..=999_999 => 6,
-            _ => panic!("Why do you have a file with 1000000 lines?!")
+        let chain_len = match chain.first_mut() {
+            Some(first) => {
+                *first = Message { file, line, error };
+                1
+            }
+            None => 0
         };
 
-        // Return the newly created error
+        // Return the newly created error. The padding is used to pretty print the call
+        // stack on panic
         ErrorChain { 
             chain, 
-            chain_len: 1, 
-            max_padding: file.len() + line_len 
+            chain_len,
+            // A chain that can't hold any message still counts the root cause
+            elided: 1 - chain_len,
+            max_padding: file.len() + line_len(line),
+            // SAFETY: enabling the `backtrace` feature is the promise that every frame
+            // pointer on the call stack is valid, as required by `Backtrace::capture`.
+            // See the feature in `Cargo.toml`.
+            #[cfg(feature = "backtrace")]
+            backtrace: unsafe { Backtrace::capture() }
         }
     }
 
@@ -206,203 +342,192 @@ impl ErrorChain {
         Some(&self.chain[0])
     }
 
+    /// Iterate over the messages of the chain, starting from the root cause. Messages
+    /// that were elided from a full chain are skipped.
+    pub fn iter(&self) -> core::slice::Iter<'_, Message> {
+        self.chain[..self.chain_len].iter()
+    }
+
+    /// Number of messages dropped from the chain because it was full
+    pub fn elided(&self) -> usize {
+        self.elided
+    }
+
+    /// Return addresses of the call stack where the chain was started
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Get the message of the error that started the chain
+    pub fn root_cause(&self) -> Option<&Message> {
+        self.first()
+    }
+
+    /// Returns `true` if the error that started the chain is a `T`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use errchain::prelude::*;
+    /// # #[derive(Debug)] enum RangeSetError { Full }
+    /// # let err: ErrorChain = ErrorChain::new(&RangeSetError::Full);
+    /// if err.root_cause_is::<RangeSetError>() {
+    ///     // Handle the error
+    /// }
+    /// # assert!(err.root_cause_is::<RangeSetError>());
+    /// ```
+    pub fn root_cause_is<T: 'static>(&self) -> bool {
+        self.root_cause().map_or(false, Message::is::<T>)
+    }
+
+    /// Get the first error in the chain, starting from the root cause, that is a `T`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use errchain::prelude::*;
+    /// # #[derive(Debug)] enum RangeSetError { Full }
+    /// # let err: ErrorChain = ErrorChain::new(&RangeSetError::Full);
+    /// if let Some(RangeSetError::Full) = err.downcast_ref::<RangeSetError>() {
+    ///     // Handle the error
+    /// }
+    /// # assert!(err.downcast_ref::<RangeSetError>().is_some());
+    /// ```
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.iter().find_map(Message::downcast_ref::<T>)
+    }
+
     #[track_caller]
-    fn extend_chain(mut self, file: &'static str, line: u32, error: &'static dyn Debug) 
-        -> ErrorChain {
-        // If the chain is full, we can't add anymore, return what we have thus far
-        if self.chain_len == MAX_CHAIN_LEN {
-            return self;
-        }
+    fn extend_chain(mut self, file: &'static str, line: u32, error: Payload) 
+        -> ErrorChain<N> {
+        if self.chain_len == N {
+            // If the chain is full, replace the newest message to keep both the root
+            // cause and the outermost context. The root cause is never replaced.
+            self.elided += 1;
+            if N < 2 {
+                return self;
+            }
 
-        // Add the new message to the chain
-        self.chain[self.chain_len] = Message { file, line, error };
-
-        // Increase the length of the chain
-        self.chain_len += 1;
-
-        // Calculate the length of the line number since we can't allocate in errchain
-        let line_len = match line {
-                  0..=9       => 1,
-                 10..=99      => 2,
-                100..=999     => 3,
-               1000..=9999    => 4,
-              10000..=99999   => 5,
-            100_000..=999_999 => 6,
-            _ => panic!("Why do you have a file with 1000000 lines?!")
-        };
+            self.chain[N - 1] = Message { file, line, error };
+        } else {
+            // Add the new message to the chain
+            self.chain[self.chain_len] = Message { file, line, error };
+
+            // Increase the length of the chain
+            self.chain_len += 1;
+        }
 
         // Adjust the max padding if the new element is largest thus far
-        self.max_padding = core::cmp::max(self.max_padding, file.len() + line_len);
+        self.max_padding = core::cmp::max(self.max_padding, file.len() + line_len(line));
 
         // Return the newly modify error
         self
     }
 }
 
-impl core::fmt::Debug for ErrorChain {
+impl<const N: usize> core::fmt::Debug for ErrorChain<N> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        let _ = write!(f, "\n");
+        let _ = writeln!(f);
 
         // Take only the messages from the chain len
-        for Message { file, line, error } in self.chain.iter().take(self.chain_len) {
-            // Write the file:line prefix
-            let _ = write!(f, "{}:{}:", file, line);
-
-            // Calculate the length of the line number
-            let line_len = match line {
-                0..=9 => 1,
-                10..=99 => 2,
-                100..=999 => 3,
-                1000..=9999 => 4,
-                10000..=99999 => 5,
-                100_000..=999_999 => 6,
-                _ => panic!("Why do you have a file with 1000000 lines?!")
-            };
-
-            let max_len = self.max_padding
-                                .saturating_sub(file.len())
-                                .saturating_sub(line_len) + 1;
-
-            // Write the padding to vertically align the messages
-            for _ in 0..max_len {
-                let _ = write!(f, " ");
+        for (i, Message { file, line, error }) in self.iter().enumerate() {
+            // The elided messages were between the last two messages of the chain
+            if self.elided > 0 && i + 1 == self.chain_len && i > 0 {
+                write_elided(f, self.max_padding, self.elided);
             }
 
-            // Write the message
-            let _ = write!(f, "{:?}\n", error);
+            write_message(f, self.max_padding, file, *line, format_args!("{:?}", error));
+        }
+
+        // A chain with fewer than two links has nothing to put elided messages between
+        if self.elided > 0 && self.chain_len < 2 {
+            write_elided(f, self.max_padding, self.elided);
         }
 
+        #[cfg(feature = "backtrace")]
+        let _ = write!(f, "{:?}", self.backtrace);
+
         core::fmt::Result::Ok(())
     }
 }
 
-/// [`Result`] type that represents success or starts an `ErrorChain` on failure
-#[allow(clippy::large_enum_variant)]
-pub enum ErrorChainResult<T> {
-    /// Success type
-    Ok(T),
-
-    /// Failure type
-    Err(ErrorChain)
+/// Calculate the length of the line number since we can't allocate in errchain
+fn line_len(line: u32) -> usize {
+    line.checked_ilog10().map_or(1, |digits| digits as usize + 1)
 }
 
-impl<T> ErrorChainResult<T> {
-    /// Returns the contained `Ok` value, consuming the `self` value. Panics on `Err`
-    pub fn expect(self, error_str: &str) -> T {
-        match self {
-            Ok(t)    => t,
-            Err(err) => panic!("{}\n{:?}", error_str, err)
-        }
+/// Write one `file:line: message` line of a chain, padding the message to line up with
+/// the longest `file:line` prefix of the chain
+fn write_message(f: &mut core::fmt::Formatter, max_padding: usize, file: &str, line: u32,
+        message: core::fmt::Arguments) {
+    // Write the file:line prefix
+    let _ = write!(f, "{}:{}:", file, line);
+
+    let max_len = max_padding
+                        .saturating_sub(file.len())
+                        .saturating_sub(line_len(line)) + 1;
+
+    // Write the padding to vertically align the messages
+    for _ in 0..max_len {
+        let _ = write!(f, " ");
     }
+
+    // Write the message
+    let _ = writeln!(f, "{}", message);
 }
 
-/// Custom trait in order to impl [`Try`]. We can't overwrite the impl [`Try`] on
-/// [`core::result::Result`], so a new [`Result`] type is needed
-pub type Result<T> = ErrorChainResult<T>;
+/// Write the marker for the messages dropped from a full chain, lined up with the
+/// messages of the chain
+fn write_elided(f: &mut core::fmt::Formatter, max_padding: usize, elided: usize) {
+    // Account for the two `:` and the padding space written by `write_message`
+    let _ = writeln!(f, "{:width$}... {} frames elided", "", elided, width = max_padding + 3);
+}
 
-/// Publically export [`ErrorChainResult::Ok`] so that [`Ok`] can be used as normal 
-pub use ErrorChainResult::Ok;
+/// [`Result`] type that represents success or holds the `ErrorChain` of the failure
+pub type Result<T> = core::result::Result<T, ErrorChain>;
 
-/// Publically export [`ErrorChainResult::Err`] so that [`Err`] can be used as normal 
-pub use ErrorChainResult::Err;
+/// Publically export [`core::result::Result::Ok`] so that the prelude can be glob
+/// imported without losing [`Ok`]
+pub use core::result::Result::Ok;
+
+/// Publically export [`core::result::Result::Err`] so that the prelude can be glob
+/// imported without losing [`Err`]
+pub use core::result::Result::Err;
 
 /// Implement the `Context` trait in order to give `Result` the `.context()` function.
 /// This function will take an error, add it to the current error chain in place, and 
 /// then return the modified Result to be propagated backward.
-pub trait Context<T> {
+pub trait Context<T, const N: usize = MAX_CHAIN_LEN> {
     /// Add the given [`ErrorType`] to the current [`ErrorChain`]
-    fn context(self, error: &'static dyn Debug) -> Result<T>;
+    fn context<E: Debug + 'static>(self, error: &'static E)
+        -> core::result::Result<T, ErrorChain<N>>;
+
+    /// Add a message formatted from `args` to the current [`ErrorChain`]. Used by
+    /// [`context_fmt!`].
+    fn context_fmt(self, args: core::fmt::Arguments)
+        -> core::result::Result<T, ErrorChain<N>>;
 
     // /// Add the given `str` to the current [`ErrorChain`]. This mostly has uses as adding
     // /// descriptions when handling `Error`s.
     // fn context_str<E: ErrorType>(self, error: &'static str) -> Result<T, E>;
 }
 
-/// Custom `Try` implementation to automatically add the context of the failing location
-/// in source. We can't  
-impl<T> core::ops::Try for ErrorChainResult<T> {
-    type Ok = T;
-    type Error = ErrorChain;
-
-    fn into_result(self) -> core::result::Result<<ErrorChainResult<T> as Try>::Ok, Self::Error> {
-        match self {
-            ErrorChainResult::Ok(val)  => core::result::Result::Ok(val),
-            ErrorChainResult::Err(val) => core::result::Result::Err(val),
-        }
-    }
-
-    #[track_caller]
-    fn from_error(err: Self::Error) -> Self {
-        let caller = core::panic::Location::caller();
-
-        let curr_file = caller.file();
-        let curr_line = caller.line();
-
-        // This function can be called twice when converting a `core::result::Result`
-        // into an `ErrorChainResult`. This can result in the same file/line being added
-        // twice to the chain. To prevent this, we check if the last element in the chain
-        // is the same file/line that we are attempting to add. If so, quick return out
-        // without adding a new dummy entry
-        if let Some(Message { file: last_file, line: last_line, ..}) = err.last() {
-            if *last_line == curr_line && *last_file == curr_file {
-                return ErrorChainResult::Err(err);
-            }
-        }
-
-        let err = err.extend_chain(curr_file, curr_line, &Error::Continue);
-        ErrorChainResult::Err(err)
-    }
-
-    #[track_caller]
-    fn from_ok(v: <ErrorChainResult<T> as Try>::Ok) -> Self {
-        ErrorChainResult::Ok(v)
-    }
-}
-
-impl<T> Context<T> for ErrorChainResult<T> {
+impl<T, const N: usize> Context<T, N> for core::result::Result<T, ErrorChain<N>> {
     #[track_caller]
-    fn context(self, error: &'static dyn Debug) -> Result<T> {
+    fn context<E: Debug + 'static>(self, error: &'static E)
+            -> core::result::Result<T, ErrorChain<N>> {
         let caller = core::panic::Location::caller();
-        match self {
-            ErrorChainResult::Ok(_)  => self,
-            ErrorChainResult::Err(err) => {
-                let err = err.extend_chain(caller.file(), caller.line(), error);
-                ErrorChainResult::Err(err)
-            }
-        }
-    }
-
-    /*
-    #[track_caller]
-    fn context_str(self, error: &'static str) -> Result<T, E> {
-        let caller = core::panic::Location::caller();
-        match self {
-            ErrorChainResult::Ok(_)  => self,
-            ErrorChainResult::Err(err) => {
-                // let error = ErrorType::String(error);
-                let err = err.extend_chain(caller.file(), caller.line(), error);
-                ErrorChainResult::Err(err)
-            }
-        }
+        self.map_err(|err| err.extend_chain(caller.file(), caller.line(),
+                Payload::new(error)))
     }
-    */
-}
-
-impl<T> From<core::result::Result<T, ErrorChain>> for ErrorChainResult<T> {
-    fn from(val: core::result::Result<T, ErrorChain>) -> ErrorChainResult<T> {
-        match val {
-            core::result::Result::Ok(val)  => ErrorChainResult::Ok(val),
-            core::result::Result::Err(val) => ErrorChainResult::Err(val),
-        }
-    }
-}
 
-impl<T> Context<T> for core::result::Result<T, ErrorChain> {
     #[track_caller]
-    fn context(self, error: &'static dyn Debug) -> Result<T> {
+    fn context_fmt(self, args: core::fmt::Arguments)
+            -> core::result::Result<T, ErrorChain<N>> {
         let caller = core::panic::Location::caller();
-        self.map_err(|err| err.extend_chain(caller.file(), caller.line(), error))
-            .into()
+        self.map_err(|err| err.extend_chain(caller.file(), caller.line(),
+                Payload::formatted(args)))
     }
 
     /*
@@ -416,26 +541,20 @@ impl<T> Context<T> for core::result::Result<T, ErrorChain> {
     */
 }
 
-/*
-impl<T> Context<T, E> for Option<T> {
+/// Start an [`ErrorChain`] from a `None`, replacing the removed `NoneError`
+impl<T> Context<T> for Option<T> {
     #[track_caller]
-    fn context(self, error: E) -> Result<T, E> {
+    fn context<E: Debug + 'static>(self, error: &'static E) -> Result<T> {
         let caller = core::panic::Location::caller();
-        self.ok_or_else(|| 
-                ErrorChain::new_with_debug(caller.file(), caller.line(), error))
-            .into()
+        self.ok_or_else(|| ErrorChain::new_with_debug(caller.file(), caller.line(), error))
     }
 
     #[track_caller]
-    fn context_str(self, error: &'static str) -> Result<T, E> {
+    fn context_fmt(self, args: core::fmt::Arguments) -> Result<T> {
         let caller = core::panic::Location::caller();
-        // let error = ErrorType::String(error);
-        self.ok_or_else(|| 
-                ErrorChain::new_with_debug(caller.file(), caller.line(), error))
-            .into()
+        self.ok_or_else(|| ErrorChain::new_with_fmt(caller.file(), caller.line(), args))
     }
 }
-*/
 
 /*
 impl From<page_table::Error> for ErrorChain {
@@ -453,14 +572,6 @@ impl From<pe::Error> for ErrorChain {
         ErrorChain::new_with_debug(caller.file(), caller.line(), ErrorType::Pe(err))
     }
 }
-
-impl From<core::option::NoneError> for ErrorChain {
-    #[track_caller]
-    fn from(_err: core::option::NoneError) -> ErrorChain {
-        let caller = core::panic::Location::caller();
-        ErrorChain::new_with_debug(caller.file(), caller.line(), ErrorType::NoneError)
-    }
-}
 */
 
 /// Shorter name for `ErrorChain::new`
@@ -471,6 +582,68 @@ macro_rules! err {
     }
 }
 
+/// Start an `ErrorChain` with a message formatted at runtime. The message is stored
+/// inline and truncated to [`MAX_MESSAGE_LEN`] bytes.
+///
+/// # Example
+///
+/// ```
+/// # use errchain::prelude::*;
+/// fn allocate(size: u64, addr: u64) -> Result<u64> {
+///     return err_fmt!("allocation of {:#x} bytes failed at {:#x}", size, addr);
+/// }
+/// # assert!(allocate(0x1000, 0x2000).is_err());
+/// ```
+#[macro_export]
+macro_rules! err_fmt {
+    ($($arg:tt)*) => {
+        Err(ErrorChain::new_with_fmt(file!(), line!(), format_args!($($arg)*)))
+    }
+}
+
+/// Add a message formatted at runtime to the `ErrorChain` of the given `Result`
+///
+/// # Example
+///
+/// ```
+/// # use errchain::prelude::*;
+/// # #[derive(Debug)] enum Error { OutOfMemory }
+/// # fn allocate(size: u64, align: u64) -> Result<u64> { err!(&Error::OutOfMemory) }
+/// fn core_memory(core_id: usize, size: u64) -> Result<u64> {
+///     let addr = context_fmt!(allocate(size, 0x1000), "core {} memory", core_id)?;
+///     Ok(addr)
+/// }
+/// # assert_eq!(core_memory(1, 0x1000).unwrap_err().iter().len(), 2);
+/// ```
+#[macro_export]
+macro_rules! context_fmt {
+    ($result:expr, $($arg:tt)*) => {
+        $crate::Context::context_fmt($result, format_args!($($arg)*))
+    }
+}
+
+/// Check the given condition. If the condition fails, create an `ErrorChain` with a
+/// message formatted at runtime as the first link in the chain
+///
+/// # Example
+///
+/// ```
+/// # use errchain::prelude::*;
+/// fn check(size: u64, max: u64) -> Result<()> {
+///     ensure_fmt!(size <= max, "size {:#x} larger than {:#x}", size, max);
+///     Ok(())
+/// }
+/// # assert!(check(2, 1).is_err());
+/// ```
+#[macro_export]
+macro_rules! ensure_fmt {
+    ($check:expr, $($arg:tt)*) => {
+        if !$check {
+            return Err(ErrorChain::new_with_fmt(file!(), line!(), format_args!($($arg)*)));
+        }
+    };
+}
+
 /*
 /// Shorter name for `ErrorChain::new` that always uses `ErrorType::String`
 #[macro_export]
@@ -486,13 +659,17 @@ macro_rules! err_str {
 /// # Example
 ///
 /// ```
-/// add!(1, u64::MAX)
+/// # use errchain::prelude::*;
+/// fn overflow() -> Result<u64> {
+///     Ok(add!(1u64, u64::MAX))
+/// }
+/// # assert!(overflow().is_err());
 /// ```
 #[macro_export]
 macro_rules! add {
     ($a:expr, $b:expr) => {
         $a.checked_add($b).ok_or_else(|| 
-            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::AddOverflow))?;
+            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::AddOverflow))?
     }
 }
 
@@ -502,13 +679,18 @@ macro_rules! add {
 /// # Example
 ///
 /// ```
-/// ensure!(table.is_valid(), "Invalid table");
+/// # use errchain::prelude::*;
+/// fn check(valid: bool) -> Result<()> {
+///     ensure_str!(valid, "Invalid table");
+///     Ok(())
+/// }
+/// # assert!(check(false).is_err());
 /// ```
 #[macro_export]
 macro_rules! ensure_str {
     ($check:expr, $msg:literal) => {
         if !$check {
-            return Err(ErrorChain::new_with_debug(file!(), line!(), ErrorType::String($msg)));
+            return Err(ErrorChain::new_with_debug(file!(), line!(), &$msg));
         }
     };
 }
@@ -527,13 +709,17 @@ macro_rules! ensure {
 /// # Example
 ///
 /// ```
-/// sub!(0xdeadbeef, u64::MAX)
+/// # use errchain::prelude::*;
+/// fn underflow() -> Result<u64> {
+///     Ok(sub!(0xdeadbeef_u64, u64::MAX))
+/// }
+/// # assert!(underflow().is_err());
 /// ```
 #[macro_export]
 macro_rules! sub {
     ($a:expr, $b:expr) => {
         $a.checked_sub($b).ok_or_else(|| 
-            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::SubUnderflow))?;
+            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::SubUnderflow))?
     }
 }
 
@@ -542,31 +728,82 @@ macro_rules! sub {
 /// # Example
 ///
 /// ```
-/// mul!(0xdeadbeef, u64::MAX)
+/// # use errchain::prelude::*;
+/// fn overflow() -> Result<u64> {
+///     Ok(mul!(0xdeadbeef_u64, u64::MAX))
+/// }
+/// # assert!(overflow().is_err());
 /// ```
 #[macro_export]
 macro_rules! mul {
     ($a:expr, $b:expr) => {
         $a.checked_mul($b).ok_or_else(|| 
-            ErrorChain::new_with_debug(file!(), line!(), 
-                ErrorType::Arithmetic(NumericalError::MulOverflow)))?;
+            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::MulOverflow))?
     }
 }
 
-/// Checked multiply wrapping the result in an `ErrorChain` context in case 
-/// of overflow
+/// Checked division wrapping the result in an `ErrorChain` context in case of a
+/// division by zero or an overflow
 ///
 /// # Example
 ///
 /// ```
-/// div!(0xdeadbeef, 0)
+/// # use errchain::prelude::*;
+/// fn divide_by_zero() -> Result<u64> {
+///     Ok(div!(0xdeadbeef_u64, 0))
+/// }
+/// # assert!(divide_by_zero().is_err());
 /// ```
 #[macro_export]
 macro_rules! div {
     ($a:expr, $b:expr) => {
         $a.checked_div($b).ok_or_else(|| 
-            ErrorChain::new_with_debug(file!(), line!(), 
-                ErrorType::Arithmetic(NumericalError::Div)))?;
+            ErrorChain::new_with_debug(file!(), line!(), &NumericalError::Div))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Root
+    }
+
+    #[test]
+    fn test_downcast() {
+        let result: Result<()> = err!(&TestError::Root);
+        let err = result.context(&5u8).context_fmt(format_args!("{}", 7)).unwrap_err();
+
+        assert!(err.root_cause_is::<TestError>());
+        assert_eq!(err.downcast_ref::<TestError>(), Some(&TestError::Root));
+        assert_eq!(err.downcast_ref::<u8>(), Some(&5));
+        assert_eq!(err.downcast_ref::<FormatBuffer>().map(FormatBuffer::as_str), Some("7"));
+
+        // Types that aren't in the chain are never handed out
+        assert!(err.downcast_ref::<[u64; 4]>().is_none());
+        assert!(err.iter().skip(1).all(|message| !message.is::<TestError>()));
+    }
+
+    /// Run the checked arithmetic and `ensure_str!` macros
+    fn checked(a: u64, b: u64) -> Result<u64> {
+        ensure_str!(a != 1, "one is not allowed");
+        let product = mul!(a, b);
+        Ok(div!(product, b))
+    }
+
+    #[test]
+    fn test_macros() {
+        assert_eq!(checked(6, 7).unwrap(), 6);
+
+        let err = checked(u64::MAX, 2).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(NumericalError::MulOverflow)));
+
+        let err = checked(6, 0).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(NumericalError::Div)));
 
+        let err = checked(1, 2).unwrap_err();
+        assert_eq!(err.downcast_ref::<&str>(), Some(&"one is not allowed"));
     }
 }
@@ -7,4 +7,10 @@ pub use crate::err;
 pub use crate::add;
 pub use crate::sub;
 pub use crate::mul;
+pub use crate::div;
 pub use crate::ensure;
+pub use crate::ensure_str;
+pub use crate::err_fmt;
+pub use crate::context_fmt;
+pub use crate::ensure_fmt;
+pub use crate::Context;
@@ -56,7 +56,10 @@ pub enum NumericalError {
     SubUnderflow,
 
     /// Error caused by an overflow during multiplication
-    MulOverflow
+    MulOverflow,
+
+    /// Error caused by a division by zero or an overflow during division
+    Div
 }
 
 impl ErrorType for NumericalError {}
@@ -0,0 +1,80 @@
+//! Fuzz the `Deserialize` implementations of `noodle` with arbitrary input
+//!
+//! Run with `cargo fuzz run deserialize` from `shared/noodle`
+
+#![no_main]
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use libfuzzer_sys::fuzz_target;
+use noodle::{Serialize, Deserialize, DeserializeLimits, SliceReader};
+
+/// Recursive type to exercise the nesting limit
+#[derive(Serialize, Deserialize)]
+enum Tree {
+    Leaf(u8),
+    Node(Box<Tree>, Box<Tree>),
+    Many(Vec<Tree>),
+}
+
+/// Derived structure with a trailing defaulted field
+#[derive(Serialize, Deserialize)]
+struct Record<'a> {
+    id:    u64,
+    name:  Cow<'a, str>,
+    tags:  BTreeSet<u32>,
+    #[noodle(default)]
+    extra: Option<Arc<[u16; 4]>>,
+}
+
+/// Deserialize a `T` from `data`, both within limits and without them, and make sure
+/// every value that deserializes serializes back to bytes that deserialize to the same
+/// value
+fn check<T: Serialize + Deserialize>(data: &[u8]) {
+    let limits = DeserializeLimits::new(64 * 1024, 4096, 16);
+
+    let limited = limits.deserialize::<T, _>(&mut SliceReader::new(data));
+    let unlimited = T::deserialize(&mut SliceReader::new(data));
+
+    // The limits can only reject input, never accept more of it
+    if unlimited.is_none() {
+        assert!(limited.is_err(), "Limits accepted input the reader rejected");
+    }
+
+    for val in limited.ok().into_iter().chain(unlimited) {
+        let mut first = Vec::new();
+        val.serialize(&mut first).expect("Failed to serialize deserialized value");
+
+        let again = T::deserialize(&mut SliceReader::new(&first))
+            .expect("Failed to deserialize serialized value");
+
+        let mut second = Vec::new();
+        again.serialize(&mut second).expect("Failed to serialize value again");
+        assert!(first == second, "Round trip changed the serialized value");
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    // The first byte picks the type to deserialize the rest of the input as
+    let (kind, data) = match data.split_first() {
+        Some((kind, data)) => (*kind, data),
+        None => return,
+    };
+
+    match kind % 12 {
+        0  => check::<u8>(data),
+        1  => check::<i128>(data),
+        2  => check::<bool>(data),
+        3  => check::<Option<u32>>(data),
+        4  => check::<String>(data),
+        5  => check::<Vec<u8>>(data),
+        6  => check::<Vec<Vec<u16>>>(data),
+        7  => check::<BTreeSet<i64>>(data),
+        8  => check::<Box<[usize; 3]>>(data),
+        9  => check::<Cow<str>>(data),
+        10 => check::<Tree>(data),
+        _  => check::<Record>(data),
+    }
+});
@@ -0,0 +1,208 @@
+//! Zero-copy deserialization borrowing strings and bytes from an in-memory buffer
+
+use crate::{Reader, Deserialize, SliceReader};
+
+#[cfg(feature = "alloc")] use crate::{nested, MAX_PREALLOCATION};
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+#[cfg(feature = "alloc")] use alloc::sync::Arc;
+#[cfg(feature = "alloc")] use alloc::boxed::Box;
+#[cfg(feature = "alloc")] use alloc::string::String;
+#[cfg(feature = "alloc")] use alloc::borrow::Cow;
+#[cfg(feature = "alloc")] use alloc::collections::BTreeSet;
+
+/// [`Reader`] over a buffer that lives for `'de`, which can hand out slices of the
+/// buffer instead of copying them
+pub trait BorrowedReader<'de>: Reader {
+    /// Read the next `len` bytes as a slice of the underlying buffer. Returns `None` if
+    /// there aren't `len` bytes left.
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]>;
+}
+
+/// Deserialize a `Self` which may borrow from the buffer of a [`BorrowedReader`]
+///
+/// This is the same wire format as [`Deserialize`], so anything serialized with
+/// [`Serialize`](crate::Serialize) can be deserialized either way.
+pub trait DeserializeBorrowed<'de>: Sized {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self>;
+}
+
+impl<'de> BorrowedReader<'de> for SliceReader<'de> {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        let borrowed = self.remaining().get(..len)?;
+        self.read += len;
+        Some(borrowed)
+    }
+}
+
+impl<'de> BorrowedReader<'de> for &'de [u8] {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        let borrowed = self.get(..len)?;
+        *self = &self[len..];
+        Some(borrowed)
+    }
+}
+
+/// Implement `DeserializeBorrowed` for types that never borrow by deserializing them
+/// as usual
+macro_rules! deserialize_owned {
+    ($($ty:ty),*) => {$(
+        impl<'de> DeserializeBorrowed<'de> for $ty {
+            fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R)
+                    -> Option<Self> {
+                <$ty as Deserialize>::deserialize(reader)
+            }
+        }
+    )*}
+}
+
+deserialize_owned!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool);
+deserialize_owned!(char, core::time::Duration);
+
+#[cfg(feature = "alloc")]
+deserialize_owned!(String);
+
+/// Borrow a length prefixed byte slice
+impl<'a, 'de: 'a> DeserializeBorrowed<'de> for &'a [u8] {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        let len = <usize as Deserialize>::deserialize(reader)?;
+        reader.elements(len)?;
+        reader.read_borrowed(len)
+    }
+}
+
+/// Borrow a string
+impl<'a, 'de: 'a> DeserializeBorrowed<'de> for &'a str {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        let bytes = <&[u8] as DeserializeBorrowed>::deserialize_borrowed(reader)?;
+        core::str::from_utf8(bytes).ok()
+    }
+}
+
+/// Borrow a string, which is never `Cow::Owned`
+#[cfg(feature = "alloc")]
+impl<'a, 'de: 'a> DeserializeBorrowed<'de> for Cow<'a, str> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        <&str as DeserializeBorrowed>::deserialize_borrowed(reader).map(Cow::Borrowed)
+    }
+}
+
+/// Borrow a byte slice, which is never `Cow::Owned`
+#[cfg(feature = "alloc")]
+impl<'a, 'de: 'a> DeserializeBorrowed<'de> for Cow<'a, [u8]> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        <&[u8] as DeserializeBorrowed>::deserialize_borrowed(reader).map(Cow::Borrowed)
+    }
+}
+
+impl<'de, T: DeserializeBorrowed<'de>> DeserializeBorrowed<'de> for Option<T> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        // Get if this option is a `Some` value
+        let is_some = <u8 as Deserialize>::deserialize(reader)? != 0;
+
+        let ret = if is_some {
+            Some(T::deserialize_borrowed(reader)?)
+        } else {
+            None
+        };
+
+        Some(ret)
+    }
+}
+
+impl<'de, T, E> DeserializeBorrowed<'de> for Result<T, E>
+        where T: DeserializeBorrowed<'de>, E: DeserializeBorrowed<'de> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        match <u8 as Deserialize>::deserialize(reader)? {
+            0 => Some(Ok(T::deserialize_borrowed(reader)?)),
+            1 => Some(Err(E::deserialize_borrowed(reader)?)),
+            _ => None,
+        }
+    }
+}
+
+/// Implement `DeserializeBorrowed` for tuples by deserializing each element in order
+macro_rules! deserialize_tuple {
+    ($($name:ident),*) => {
+        impl<'de, $($name: DeserializeBorrowed<'de>),*> DeserializeBorrowed<'de>
+                for ($($name,)*) {
+            #[allow(unused_variables)]
+            fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R)
+                    -> Option<Self> {
+                Some(($($name::deserialize_borrowed(reader)?,)*))
+            }
+        }
+    };
+}
+
+deserialize_tuple!();
+deserialize_tuple!(A);
+deserialize_tuple!(A, B);
+deserialize_tuple!(A, B, C);
+deserialize_tuple!(A, B, C, D);
+deserialize_tuple!(A, B, C, D, E);
+deserialize_tuple!(A, B, C, D, E, F);
+deserialize_tuple!(A, B, C, D, E, F, G);
+deserialize_tuple!(A, B, C, D, E, F, G, H);
+deserialize_tuple!(A, B, C, D, E, F, G, H, I);
+deserialize_tuple!(A, B, C, D, E, F, G, H, I, J);
+deserialize_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+deserialize_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl<'de, T: DeserializeBorrowed<'de>, const N: usize> DeserializeBorrowed<'de> for [T; N] {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        crate::deserialize_array(|| T::deserialize_borrowed(reader))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: DeserializeBorrowed<'de>> DeserializeBorrowed<'de> for Box<T> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        nested(reader, T::deserialize_borrowed).map(Box::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: DeserializeBorrowed<'de>> DeserializeBorrowed<'de> for Arc<T> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        nested(reader, T::deserialize_borrowed).map(Arc::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: DeserializeBorrowed<'de>> DeserializeBorrowed<'de> for Vec<T> {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        // Get the length of the vector in elements
+        let len = <usize as Deserialize>::deserialize(reader)?;
+        reader.elements(len)?;
+
+        let mut vec = Vec::with_capacity(core::cmp::min(len, MAX_PREALLOCATION));
+
+        nested(reader, |reader| {
+            for _ in 0..len {
+                vec.push(T::deserialize_borrowed(reader)?);
+            }
+
+            Some(vec)
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeBorrowed<'de> for BTreeSet<T>
+        where T: DeserializeBorrowed<'de> + core::cmp::Ord {
+    fn deserialize_borrowed<R: BorrowedReader<'de>>(reader: &mut R) -> Option<Self> {
+        // Get the length of the set in elements
+        let len = <usize as Deserialize>::deserialize(reader)?;
+        reader.elements(len)?;
+
+        let mut res = BTreeSet::new();
+
+        nested(reader, |reader| {
+            for _ in 0..len {
+                res.insert(T::deserialize_borrowed(reader)?);
+            }
+
+            Some(res)
+        })
+    }
+}
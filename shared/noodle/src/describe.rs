@@ -0,0 +1,675 @@
+//! Human-readable dumps of serialized data, given the type it was serialized from
+//!
+//! [`Describe`] walks the serialized bytes of a type the same way deserializing it
+//! would, but instead of building the value it reports each field, element and
+//! primitive to a [`Visitor`]. The derive and `noodle!` implement it using the names
+//! of the fields, so [`Text`] and [`Json`] can print any payload annotated with the
+//! fields it holds, even ones that don't implement `Debug` or can't be built on the
+//! host.
+
+use core::fmt::{self, Write};
+use core::time::Duration;
+
+use crate::{Reader, Deserialize, nested};
+
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+#[cfg(feature = "alloc")] use alloc::sync::Arc;
+#[cfg(feature = "alloc")] use alloc::boxed::Box;
+#[cfg(feature = "alloc")] use alloc::string::String;
+#[cfg(feature = "alloc")] use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")] use alloc::collections::{VecDeque, BTreeSet, BTreeMap};
+#[cfg(feature = "std")] use std::collections::{HashMap, HashSet};
+
+/// How the fields of a struct or enum variant are accessed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fields {
+    /// `{ name: T }`, described with [`Visitor::field`]
+    Named,
+
+    /// `(T)`, described with [`Visitor::element`]
+    Unnamed,
+
+    /// No fields at all
+    Unit,
+}
+
+/// A value made of other values, described between [`Visitor::begin`] and
+/// [`Visitor::end`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Compound<'a> {
+    /// A struct with its name
+    Struct(&'a str, Fields),
+
+    /// An enum variant with the name of the enum and of the variant
+    Variant(&'a str, &'a str, Fields),
+
+    /// A tuple, described with [`Visitor::element`]
+    Tuple,
+
+    /// A sequence with the number of elements, described with [`Visitor::element`]
+    Seq(usize),
+
+    /// A map with the number of entries, described with [`Visitor::key`] and
+    /// [`Visitor::value`]
+    Map(usize),
+
+    /// The value inside of an `Option::Some`
+    Some,
+
+    /// Bytes the type doesn't know how to describe, such as the fields of a newer
+    /// version of a versioned struct, given to [`Visitor::bytes`]
+    Unknown(usize),
+}
+
+/// Receives the values found by [`Describe`]. Returning `None` from any method stops
+/// the description.
+///
+/// Every value in a [`Compound`] is announced by [`Visitor::field`],
+/// [`Visitor::element`] or [`Visitor::key`] with its index in the compound, so
+/// visitors can place separators without keeping any state.
+pub trait Visitor {
+    /// An unsigned integer
+    fn unsigned(&mut self, val: u128) -> Option<()>;
+
+    /// A signed integer
+    fn signed(&mut self, val: i128) -> Option<()>;
+
+    /// A `bool`
+    fn bool(&mut self, val: bool) -> Option<()>;
+
+    /// A `char`
+    fn char(&mut self, val: char) -> Option<()>;
+
+    /// A string
+    fn str(&mut self, val: &str) -> Option<()>;
+
+    /// Some of the bytes of a [`Compound::Unknown`]. May be called several times for
+    /// one compound.
+    fn bytes(&mut self, val: &[u8]) -> Option<()>;
+
+    /// An `Option::None`
+    fn none(&mut self) -> Option<()>;
+
+    /// Start of a compound value
+    fn begin(&mut self, compound: &Compound) -> Option<()>;
+
+    /// The next value is the field `name` of a struct or variant with named fields
+    fn field(&mut self, index: usize, name: &str) -> Option<()>;
+
+    /// The next value is an element of a tuple, sequence, or struct or variant with
+    /// unnamed fields
+    fn element(&mut self, index: usize) -> Option<()>;
+
+    /// The next value is the key of a map entry
+    fn key(&mut self, index: usize) -> Option<()>;
+
+    /// The next value is the value of the map entry whose key was just described
+    fn value(&mut self) -> Option<()>;
+
+    /// End of `compound`, which held `count` fields, elements or entries
+    fn end(&mut self, compound: &Compound, count: usize) -> Option<()>;
+}
+
+/// Describe the serialized form of `Self` to a [`Visitor`]
+pub trait Describe {
+    /// Read a serialized `Self` from `reader`, reporting what it holds to `visitor`.
+    /// Returns `None` if the data is not a valid `Self`, in which case `visitor` has
+    /// seen everything up to the invalid value.
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()>;
+}
+
+/// [`Visitor`] printing indented, field annotated text similar to `{:#?}`
+pub struct Text<W: Write> {
+    /// Where the text goes
+    out: W,
+
+    /// Number of compounds the next line is nested in
+    depth: usize,
+}
+
+impl<W: Write> Text<W> {
+    /// Print values to `out`
+    pub fn new(out: W) -> Self {
+        Text { out, depth: 0 }
+    }
+
+    /// Get the output back
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Start a new line for the value at `index` in the current compound
+    fn line(&mut self, index: usize) -> fmt::Result {
+        if index > 0 {
+            self.out.write_char(',')?;
+        }
+
+        self.out.write_char('\n')?;
+        for _ in 0..self.depth {
+            self.out.write_str("    ")?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the opening delimiter of a compound and indent its contents
+    fn open(&mut self, delim: &str) -> Option<()> {
+        self.depth += 1;
+        self.out.write_str(delim).ok()
+    }
+}
+
+impl<W: Write> Visitor for Text<W> {
+    fn unsigned(&mut self, val: u128) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn signed(&mut self, val: i128) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn bool(&mut self, val: bool) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn char(&mut self, val: char) -> Option<()> {
+        write!(self.out, "{:?}", val).ok()
+    }
+
+    fn str(&mut self, val: &str) -> Option<()> {
+        write!(self.out, "{:?}", val).ok()
+    }
+
+    fn bytes(&mut self, val: &[u8]) -> Option<()> {
+        val.iter().try_for_each(|byte| write!(self.out, "{:02x}", byte)).ok()
+    }
+
+    fn none(&mut self) -> Option<()> {
+        self.out.write_str("None").ok()
+    }
+
+    fn begin(&mut self, compound: &Compound) -> Option<()> {
+        let fields = match *compound {
+            Compound::Struct(name, fields) => {
+                self.out.write_str(name).ok()?;
+                fields
+            }
+            Compound::Variant(ty, name, fields) => {
+                write!(self.out, "{}::{}", ty, name).ok()?;
+                fields
+            }
+            Compound::Tuple      => return self.open("("),
+            Compound::Seq(_)     => return self.open("["),
+            Compound::Map(_)     => return self.open("{"),
+            Compound::Some       => return self.out.write_str("Some(").ok(),
+            Compound::Unknown(_) => return self.out.write_str("<").ok(),
+        };
+
+        match fields {
+            Fields::Named   => self.open(" {"),
+            Fields::Unnamed => self.open("("),
+            Fields::Unit    => self.open(""),
+        }
+    }
+
+    fn field(&mut self, index: usize, name: &str) -> Option<()> {
+        self.line(index).ok()?;
+        write!(self.out, "{}: ", name).ok()
+    }
+
+    fn element(&mut self, index: usize) -> Option<()> {
+        self.line(index).ok()
+    }
+
+    fn key(&mut self, index: usize) -> Option<()> {
+        self.line(index).ok()
+    }
+
+    fn value(&mut self) -> Option<()> {
+        self.out.write_str(": ").ok()
+    }
+
+    fn end(&mut self, compound: &Compound, count: usize) -> Option<()> {
+        let close = match *compound {
+            Compound::Struct(_, fields) | Compound::Variant(_, _, fields) => match fields {
+                Fields::Named   => "}",
+                Fields::Unnamed => ")",
+                Fields::Unit    => "",
+            },
+            Compound::Tuple      => ")",
+            Compound::Seq(_)     => "]",
+            Compound::Map(_)     => "}",
+            Compound::Some       => return self.out.write_str(")").ok(),
+            Compound::Unknown(_) => return self.out.write_str(">").ok(),
+        };
+
+        // Put the closing delimiter on its own line, after a trailing comma
+        self.depth -= 1;
+        if count > 0 {
+            self.line(count).ok()?;
+        }
+
+        self.out.write_str(close).ok()
+    }
+}
+
+/// [`Visitor`] printing JSON
+///
+/// Structs with named fields are objects and tuples are arrays. Enum variants are
+/// the name of the variant if they have no fields, and otherwise an object with the
+/// variant's name as the only key. Maps are arrays of `[key, value]` arrays, as keys
+/// may not be strings. `None` is `null`, `Some` is its value, and unknown bytes are a
+/// hex string.
+pub struct Json<W: Write> {
+    /// Where the JSON goes
+    out: W,
+}
+
+impl<W: Write> Json<W> {
+    /// Print values to `out`
+    pub fn new(out: W) -> Self {
+        Json { out }
+    }
+
+    /// Get the output back
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Write `val` as a quoted JSON string
+    fn string(&mut self, val: &str) -> fmt::Result {
+        self.out.write_char('"')?;
+
+        for chr in val.chars() {
+            match chr {
+                '"'  => self.out.write_str("\\\"")?,
+                '\\' => self.out.write_str("\\\\")?,
+                '\n' => self.out.write_str("\\n")?,
+                '\r' => self.out.write_str("\\r")?,
+                '\t' => self.out.write_str("\\t")?,
+                chr if (chr as u32) < 0x20 => write!(self.out, "\\u{:04x}", chr as u32)?,
+                chr => self.out.write_char(chr)?,
+            }
+        }
+
+        self.out.write_char('"')
+    }
+}
+
+impl<W: Write> Visitor for Json<W> {
+    fn unsigned(&mut self, val: u128) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn signed(&mut self, val: i128) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn bool(&mut self, val: bool) -> Option<()> {
+        write!(self.out, "{}", val).ok()
+    }
+
+    fn char(&mut self, val: char) -> Option<()> {
+        let mut buf = [0u8; 4];
+        self.string(val.encode_utf8(&mut buf)).ok()
+    }
+
+    fn str(&mut self, val: &str) -> Option<()> {
+        self.string(val).ok()
+    }
+
+    fn bytes(&mut self, val: &[u8]) -> Option<()> {
+        val.iter().try_for_each(|byte| write!(self.out, "{:02x}", byte)).ok()
+    }
+
+    fn none(&mut self) -> Option<()> {
+        self.out.write_str("null").ok()
+    }
+
+    fn begin(&mut self, compound: &Compound) -> Option<()> {
+        let fields = match *compound {
+            Compound::Struct(_, fields) => fields,
+            Compound::Variant(_, name, Fields::Unit) => return self.string(name).ok(),
+            Compound::Variant(_, name, fields) => {
+                self.out.write_char('{').ok()?;
+                self.string(name).ok()?;
+                self.out.write_char(':').ok()?;
+                fields
+            }
+            Compound::Tuple | Compound::Seq(_) | Compound::Map(_) => Fields::Unnamed,
+            Compound::Some       => return Some(()),
+            Compound::Unknown(_) => return self.out.write_char('"').ok(),
+        };
+
+        match fields {
+            Fields::Named   => self.out.write_char('{').ok(),
+            Fields::Unnamed => self.out.write_char('[').ok(),
+            Fields::Unit    => self.out.write_str("null").ok(),
+        }
+    }
+
+    fn field(&mut self, index: usize, name: &str) -> Option<()> {
+        if index > 0 {
+            self.out.write_char(',').ok()?;
+        }
+
+        self.string(name).ok()?;
+        self.out.write_char(':').ok()
+    }
+
+    fn element(&mut self, index: usize) -> Option<()> {
+        if index > 0 {
+            self.out.write_char(',').ok()?;
+        }
+
+        Some(())
+    }
+
+    fn key(&mut self, index: usize) -> Option<()> {
+        // Close the previous entry
+        if index > 0 {
+            self.out.write_str("],").ok()?;
+        }
+
+        self.out.write_char('[').ok()
+    }
+
+    fn value(&mut self) -> Option<()> {
+        self.out.write_char(',').ok()
+    }
+
+    fn end(&mut self, compound: &Compound, count: usize) -> Option<()> {
+        let close = match *compound {
+            Compound::Struct(_, Fields::Named)      => "}",
+            Compound::Struct(_, Fields::Unnamed)    => "]",
+            Compound::Variant(_, _, Fields::Named)   => "}}",
+            Compound::Variant(_, _, Fields::Unnamed) => "]}",
+            Compound::Struct(_, Fields::Unit) | Compound::Variant(_, _, Fields::Unit) => "",
+            Compound::Tuple | Compound::Seq(_) => "]",
+            Compound::Map(_) if count > 0      => "]]",
+            Compound::Map(_)                   => "]",
+            Compound::Some                     => "",
+            Compound::Unknown(_)               => "\"",
+        };
+
+        self.out.write_str(close).ok()
+    }
+}
+
+/// Describe a `T` read from `reader` as indented text
+#[cfg(feature = "alloc")]
+pub fn to_text<T: Describe + ?Sized, R: Reader>(reader: &mut R) -> Option<String> {
+    let mut text = Text::new(String::new());
+    T::describe(reader, &mut text)?;
+    Some(text.into_inner())
+}
+
+/// Describe a `T` read from `reader` as JSON
+#[cfg(feature = "alloc")]
+pub fn to_json<T: Describe + ?Sized, R: Reader>(reader: &mut R) -> Option<String> {
+    let mut json = Json::new(String::new());
+    T::describe(reader, &mut json)?;
+    Some(json.into_inner())
+}
+
+/// Implement `Describe` for integers by deserializing them
+macro_rules! describe_int {
+    ($visit:ident, $wide:ty, $($ty:ty),*) => {$(
+        impl Describe for $ty {
+            fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V)
+                    -> Option<()> {
+                visitor.$visit(<$ty as Deserialize>::deserialize(reader)? as $wide)
+            }
+        }
+    )*}
+}
+
+describe_int!(unsigned, u128, u8, u16, u32, u64, u128, usize);
+describe_int!(signed,   i128, i8, i16, i32, i64, i128, isize);
+
+impl Describe for bool {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        visitor.bool(<bool as Deserialize>::deserialize(reader)?)
+    }
+}
+
+impl Describe for char {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        visitor.char(<char as Deserialize>::deserialize(reader)?)
+    }
+}
+
+/// Describe a `Duration` as a struct of its seconds and nanoseconds
+impl Describe for Duration {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        let duration = <Duration as Deserialize>::deserialize(reader)?;
+
+        let compound = Compound::Struct("Duration", Fields::Named);
+        visitor.begin(&compound)?;
+        visitor.field(0, "secs")?;
+        visitor.unsigned(u128::from(duration.as_secs()))?;
+        visitor.field(1, "nanos")?;
+        visitor.unsigned(u128::from(duration.subsec_nanos()))?;
+        visitor.end(&compound, 2)
+    }
+}
+
+/// Describe a string, which needs a buffer to check that it is UTF-8
+#[cfg(feature = "alloc")]
+impl Describe for str {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        visitor.str(&<String as Deserialize>::deserialize(reader)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Describe for String {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        str::describe(reader, visitor)
+    }
+}
+
+/// Describe references, such as `&str` and `&[u8]`, as what they point to
+impl<T: Describe + ?Sized> Describe for &T {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        T::describe(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: Describe + ToOwned + ?Sized> Describe for Cow<'a, T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        T::describe(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Describe + ?Sized> Describe for Box<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        nested(reader, |reader| T::describe(reader, visitor))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Describe + ?Sized> Describe for Arc<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        nested(reader, |reader| T::describe(reader, visitor))
+    }
+}
+
+impl<T: Describe> Describe for Option<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        // Get if this option is a `Some` value
+        if <u8 as Deserialize>::deserialize(reader)? == 0 {
+            return visitor.none();
+        }
+
+        visitor.begin(&Compound::Some)?;
+        T::describe(reader, visitor)?;
+        visitor.end(&Compound::Some, 1)
+    }
+}
+
+/// Describe a `Result` as an enum with `Ok` and `Err` variants
+impl<T: Describe, E: Describe> Describe for Result<T, E> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        let tag = <u8 as Deserialize>::deserialize(reader)?;
+        let compound = match tag {
+            0 => Compound::Variant("Result", "Ok",  Fields::Unnamed),
+            1 => Compound::Variant("Result", "Err", Fields::Unnamed),
+            _ => return None,
+        };
+
+        visitor.begin(&compound)?;
+        visitor.element(0)?;
+        if tag == 0 {
+            T::describe(reader, visitor)?;
+        } else {
+            E::describe(reader, visitor)?;
+        }
+        visitor.end(&compound, 1)
+    }
+}
+
+/// Implement `Describe` for tuples by describing each element in order
+macro_rules! describe_tuple {
+    ($count:expr $(, $name:ident: $index:tt)*) => {
+        impl<$($name: Describe),*> Describe for ($($name,)*) {
+            #[allow(unused_variables)]
+            fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V)
+                    -> Option<()> {
+                visitor.begin(&Compound::Tuple)?;
+                $(
+                    visitor.element($index)?;
+                    $name::describe(reader, visitor)?;
+                )*
+                visitor.end(&Compound::Tuple, $count)
+            }
+        }
+    };
+}
+
+describe_tuple!(0);
+describe_tuple!(1, A: 0);
+describe_tuple!(2, A: 0, B: 1);
+describe_tuple!(3, A: 0, B: 1, C: 2);
+describe_tuple!(4, A: 0, B: 1, C: 2, D: 3);
+describe_tuple!(5, A: 0, B: 1, C: 2, D: 3, E: 4);
+describe_tuple!(6, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+describe_tuple!(7, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+describe_tuple!(8, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+describe_tuple!(9, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+describe_tuple!(10, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+describe_tuple!(11, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+describe_tuple!(12, A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10,
+    L: 11);
+
+/// Describe an array as a sequence of its `N` elements, which have no length prefix
+impl<T: Describe, const N: usize> Describe for [T; N] {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        let compound = Compound::Seq(N);
+        visitor.begin(&compound)?;
+
+        for index in 0..N {
+            visitor.element(index)?;
+            T::describe(reader, visitor)?;
+        }
+
+        visitor.end(&compound, N)
+    }
+}
+
+/// Describe a length prefixed sequence of `T`s
+fn describe_seq<T, R, V>(reader: &mut R, visitor: &mut V) -> Option<()>
+        where T: Describe, R: Reader, V: Visitor {
+    // Get the length of the sequence in elements
+    let len = <usize as Deserialize>::deserialize(reader)?;
+    reader.elements(len)?;
+
+    let compound = Compound::Seq(len);
+    visitor.begin(&compound)?;
+
+    nested(reader, |reader| {
+        for index in 0..len {
+            visitor.element(index)?;
+            T::describe(reader, visitor)?;
+        }
+
+        Some(())
+    })?;
+
+    visitor.end(&compound, len)
+}
+
+/// Describe a length prefixed sequence of `(K, V)` entries as a map
+#[cfg(feature = "alloc")]
+fn describe_map<K, T, R, V>(reader: &mut R, visitor: &mut V) -> Option<()>
+        where K: Describe, T: Describe, R: Reader, V: Visitor {
+    // Get the length of the map in entries
+    let len = <usize as Deserialize>::deserialize(reader)?;
+    reader.elements(len)?;
+
+    let compound = Compound::Map(len);
+    visitor.begin(&compound)?;
+
+    nested(reader, |reader| {
+        for index in 0..len {
+            visitor.key(index)?;
+            K::describe(reader, visitor)?;
+            visitor.value()?;
+            T::describe(reader, visitor)?;
+        }
+
+        Some(())
+    })?;
+
+    visitor.end(&compound, len)
+}
+
+impl<T: Describe> Describe for [T] {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_seq::<T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Describe> Describe for Vec<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_seq::<T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Describe> Describe for VecDeque<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_seq::<T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Describe> Describe for BTreeSet<T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_seq::<T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Describe, T: Describe> Describe for BTreeMap<K, T> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_map::<K, T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Describe, S> Describe for HashSet<T, S> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_seq::<T, R, V>(reader, visitor)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Describe, T: Describe, S> Describe for HashMap<K, T, S> {
+    fn describe<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V) -> Option<()> {
+        describe_map::<K, T, R, V>(reader, visitor)
+    }
+}
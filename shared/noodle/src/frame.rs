@@ -0,0 +1,321 @@
+//! Framed, checksummed messages over any [`Reader`] + [`Writer`], such as a serial port,
+//! a UDP socket or a shared memory ring
+//!
+//! Every frame is:
+//!
+//! ```text
+//! magic:    [u8; 4] = b"NDLF"
+//! msg_type: u32
+//! len:      u32
+//! crc:      u32 (CRC32 of `msg_type`, `len` and `payload`)
+//! payload:  [u8; len]
+//! ```
+//!
+//! The header is always little endian. The payload is the serialized message. The
+//! receiver scans for the magic, so after a corrupted frame it resynchronizes on the
+//! next intact one.
+
+use core::convert::{TryFrom, TryInto};
+
+use crate::{Reader, Writer, Serialize, Deserialize, SliceReader, Compact};
+use crate::versioned::ByteCounter;
+use crate::describe::{Describe, Visitor};
+
+/// Magic bytes at the start of every frame
+const MAGIC: &[u8; 4] = b"NDLF";
+
+/// Size of the frame header
+pub const FRAME_HEADER_LEN: usize = 16;
+
+/// Table for the byte at a time CRC32 (IEEE 802.3)
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// Create the table for the reflected CRC32 polynomial `0xedb88320`
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+/// [`Writer`] computing the CRC32 of everything written to it
+pub struct Crc32 {
+    /// Running CRC, inverted
+    crc: u32,
+
+    /// Serialize with the compact encoding
+    compact: bool,
+}
+
+impl Crc32 {
+    /// Start a new CRC of a stream using the compact encoding if `compact` is set
+    pub fn new(compact: bool) -> Self {
+        Crc32 { crc: !0, compact }
+    }
+
+    /// CRC32 of the bytes written so far
+    pub fn finish(&self) -> u32 {
+        !self.crc
+    }
+
+    /// Add `buf` to the CRC
+    pub fn update(&mut self, buf: &[u8]) {
+        for &byte in buf {
+            self.crc = CRC32_TABLE[((self.crc ^ u32::from(byte)) & 0xff) as usize]
+                ^ (self.crc >> 8);
+        }
+    }
+}
+
+impl Writer for Crc32 {
+    fn write(&mut self, buf: &[u8]) -> Option<()> {
+        self.update(buf);
+        Some(())
+    }
+
+    fn compact(&self) -> bool {
+        self.compact
+    }
+}
+
+/// Type of a message sent through a [`Channel`]
+pub trait Message: Serialize + Deserialize {
+    /// Identifier of this message type in frame headers
+    const TYPE: u32;
+}
+
+/// A frame received from a [`Channel`]
+pub struct Frame<'a> {
+    /// Message type from the header
+    msg_type: u32,
+
+    /// Serialized message
+    payload: &'a [u8],
+
+    /// The payload uses the compact encoding
+    compact: bool,
+}
+
+impl<'a> Frame<'a> {
+    /// Message type from the frame header
+    pub fn msg_type(&self) -> u32 {
+        self.msg_type
+    }
+
+    /// Serialized message
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Returns `true` if this frame holds an `M`
+    pub fn is<M: Message>(&self) -> bool {
+        self.msg_type == M::TYPE
+    }
+
+    /// Deserialize the message in this frame. Returns `None` if the frame is not an `M`
+    /// or the payload is not exactly one valid `M`.
+    pub fn decode<M: Message>(&self) -> Option<M> {
+        if !self.is::<M>() {
+            return None;
+        }
+
+        let mut reader = SliceReader::new(self.payload);
+        let msg = if self.compact {
+            M::deserialize(&mut Compact::new(&mut reader))?
+        } else {
+            M::deserialize(&mut reader)?
+        };
+
+        // Trailing bytes mean the sender's message type doesn't match ours
+        if !reader.remaining().is_empty() {
+            return None;
+        }
+
+        Some(msg)
+    }
+
+    /// Describe the message in this frame to `visitor`, such as to print what was
+    /// actually received. Returns `None` if the frame is not an `M` or the payload
+    /// doesn't describe as an `M`.
+    pub fn describe<M: Message + Describe, V: Visitor>(&self, visitor: &mut V)
+            -> Option<()> {
+        if !self.is::<M>() {
+            return None;
+        }
+
+        let mut reader = SliceReader::new(self.payload);
+        if self.compact {
+            M::describe(&mut Compact::new(&mut reader), visitor)
+        } else {
+            M::describe(&mut reader, visitor)
+        }
+    }
+}
+
+/// Sends and receives framed messages over `T`, buffering received frames in a
+/// caller provided buffer so no allocator is needed
+pub struct Channel<'a, T: Reader + Writer> {
+    /// Transport for the frames
+    io: T,
+
+    /// Receive buffer, which limits the size of a received frame
+    buf: &'a mut [u8],
+
+    /// Number of bytes in `buf`
+    filled: usize,
+
+    /// Number of bytes at the start of `buf` used by the last received frame
+    consumed: usize,
+
+    /// Number of corrupted frames which were dropped
+    dropped: u64,
+}
+
+impl<'a, T: Reader + Writer> Channel<'a, T> {
+    /// Create a channel over `io`, receiving frames into `buf`. Frames with a payload
+    /// larger than `buf.len() - FRAME_HEADER_LEN` are dropped.
+    pub fn new(io: T, buf: &'a mut [u8]) -> Self {
+        Channel { io, buf, filled: 0, consumed: 0, dropped: 0 }
+    }
+
+    /// Get the transport, such as to flush a `BufferedIo`
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.io
+    }
+
+    /// Get the transport back, dropping any buffered bytes
+    pub fn into_inner(self) -> T {
+        self.io
+    }
+
+    /// Number of frames dropped because they were corrupted or too large
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Send `msg` in a single frame
+    pub fn send<M: Message>(&mut self, msg: &M) -> Option<()> {
+        let compact = Writer::compact(&self.io);
+
+        // Find the size and CRC of the payload before writing it
+        let mut counter = ByteCounter::new(compact);
+        msg.serialize(&mut counter)?;
+        let len = u32::try_from(counter.len()).ok()?;
+
+        let mut crc = Crc32::new(compact);
+        crc.update(&M::TYPE.to_le_bytes());
+        crc.update(&len.to_le_bytes());
+        msg.serialize(&mut crc)?;
+
+        let mut header = [0u8; FRAME_HEADER_LEN];
+        header[0..4].copy_from_slice(MAGIC);
+        header[4..8].copy_from_slice(&M::TYPE.to_le_bytes());
+        header[8..12].copy_from_slice(&len.to_le_bytes());
+        header[12..16].copy_from_slice(&crc.finish().to_le_bytes());
+
+        self.io.write(&header)?;
+        msg.serialize(&mut self.io)
+    }
+
+    /// Drop `len` bytes from the start of the receive buffer
+    fn discard(&mut self, len: usize) {
+        self.buf.copy_within(len..self.filled, 0);
+        self.filled -= len;
+    }
+
+    /// Read more bytes into the receive buffer, up to `len` bytes in total. Only the
+    /// bytes known to be needed are requested, as transports like `BufferedIo` or a
+    /// serial port block until the whole request is read. Returns `None` if the
+    /// transport failed.
+    fn fill(&mut self, len: usize) -> Option<()> {
+        let bread = self.io.read(&mut self.buf[self.filled..len])?;
+        if bread == 0 {
+            return None;
+        }
+
+        self.filled += bread;
+        Some(())
+    }
+
+    /// Receive the next intact frame, skipping over corrupted data. Returns `None` if
+    /// the transport failed.
+    pub fn recv(&mut self) -> Option<Frame<'_>> {
+        assert!(self.buf.len() > FRAME_HEADER_LEN, "Receive buffer too small for a frame");
+
+        // Drop the frame returned by the last call
+        self.discard(self.consumed);
+        self.consumed = 0;
+
+        loop {
+            // Drop everything before the next possible magic
+            let start = (0..self.filled)
+                .find(|&ii| {
+                    let len = core::cmp::min(MAGIC.len(), self.filled - ii);
+                    self.buf[ii..ii + len] == MAGIC[..len]
+                })
+                .unwrap_or(self.filled);
+            self.discard(start);
+
+            if self.filled < FRAME_HEADER_LEN {
+                self.fill(FRAME_HEADER_LEN)?;
+                continue;
+            }
+
+            let msg_type = u32::from_le_bytes(self.buf[4..8].try_into().unwrap());
+            let len      = u32::from_le_bytes(self.buf[8..12].try_into().unwrap());
+            let crc      = u32::from_le_bytes(self.buf[12..16].try_into().unwrap());
+
+            // A frame that can't fit is corrupt or too large, look for the next magic
+            let frame_len = FRAME_HEADER_LEN.checked_add(len as usize);
+            let frame_len = match frame_len {
+                Some(frame_len) if frame_len <= self.buf.len() => frame_len,
+                _ => {
+                    self.dropped += 1;
+                    self.discard(1);
+                    continue;
+                }
+            };
+
+            if self.filled < frame_len {
+                self.fill(frame_len)?;
+                continue;
+            }
+
+            let mut check = Crc32::new(false);
+            check.update(&self.buf[4..12]);
+            check.update(&self.buf[FRAME_HEADER_LEN..frame_len]);
+            if check.finish() != crc {
+                // The next frame may start inside of this one
+                self.dropped += 1;
+                self.discard(1);
+                continue;
+            }
+
+            self.consumed = frame_len;
+            return Some(Frame {
+                msg_type,
+                payload: &self.buf[FRAME_HEADER_LEN..frame_len],
+                compact: Reader::compact(&self.io),
+            });
+        }
+    }
+
+    /// Receive the next intact frame and deserialize it as an `M`. Returns `None` if
+    /// the transport failed or the frame is not an `M`.
+    pub fn recv_msg<M: Message>(&mut self) -> Option<M> {
+        self.recv()?.decode()
+    }
+}
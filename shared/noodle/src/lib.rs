@@ -1,27 +1,53 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(const_generics)]
-#![allow(incomplete_features)]
 
 extern crate core;
-extern crate alloc;
+#[cfg(feature = "alloc")] extern crate alloc;
 
-#[macro_use] pub mod tuple_match;
-#[macro_use] pub mod tuple_gen;
+// Allow the `::noodle` paths generated by `noodle_derive` to be used in this crate
+extern crate self as noodle;
+
+pub use noodle_derive::{Serialize, Deserialize, DeserializeBorrowed, Describe};
+
+mod limits;
+pub use limits::{DeserializeLimits, DeserializeError, LimitedReader};
+
+mod varint;
+pub use varint::Compact;
+
+pub mod versioned;
+
+mod borrowed;
+pub use borrowed::{BorrowedReader, DeserializeBorrowed};
+
+pub mod describe;
+pub use describe::{Describe, Visitor};
+
+mod frame;
+pub use frame::{Channel, Frame, Message, Crc32, FRAME_HEADER_LEN};
 
 use core::mem::MaybeUninit;
 use core::convert::TryInto;
-use alloc::vec::Vec;
-use alloc::sync::Arc;
-use alloc::boxed::Box;
-use alloc::string::String;
-use alloc::borrow::{Cow, ToOwned};
-use alloc::collections::{VecDeque, BTreeSet};
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+#[cfg(feature = "alloc")] use alloc::sync::Arc;
+#[cfg(feature = "alloc")] use alloc::boxed::Box;
+#[cfg(feature = "alloc")] use alloc::string::String;
+#[cfg(feature = "alloc")] use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")] use alloc::collections::{VecDeque, BTreeSet, BTreeMap};
+#[cfg(feature = "std")] use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")] use std::hash::{Hash, BuildHasher};
+use core::time::Duration;
 
 /// Write the contents of `buf` into `self`. Used to allow custom adapters for
 /// writing during serialization. Return `None` if `buf` cannot be fully
 /// serialized
 pub trait Writer {
     fn write(&mut self, buf: &[u8]) -> Option<()>;
+
+    /// Return `true` to write integers and lengths with the compact varint encoding.
+    /// See [`Compact`].
+    fn compact(&self) -> bool {
+        false
+    }
 }
 
 /// Reader trait
@@ -30,14 +56,55 @@ pub trait Reader {
 
     fn read_exact(&mut self, mut buf: &mut [u8]) -> Option<()> {
         while buf.len() > 0 {
+            // A reader making no progress has reached the end of its data
             let bread = self.read(buf)?;
+            if bread == 0 {
+                return None;
+            }
             buf = &mut buf[bread..];
         }
         Some(())
     }
+
+    /// Return `true` to read integers and lengths with the compact varint encoding.
+    /// See [`Compact`].
+    fn compact(&self) -> bool {
+        false
+    }
+
+    /// Called before a container deserializes `count` elements. Return `None` to
+    /// reject the container, such as when it is larger than the input allows.
+    fn elements(&mut self, _count: usize) -> Option<()> {
+        Some(())
+    }
+
+    /// Called when a container starts deserializing its contents. Return `None` to
+    /// reject nesting this deep.
+    fn enter(&mut self) -> Option<()> {
+        Some(())
+    }
+
+    /// Called when a container entered with `enter` is done deserializing
+    fn leave(&mut self) {}
 }
 
+/// Deserialize the contents of a container with `func`, tracking the nesting depth in
+/// `reader`
+fn nested<R: Reader, T>(reader: &mut R, func: impl FnOnce(&mut R) -> Option<T>)
+        -> Option<T> {
+    reader.enter()?;
+    let ret = func(reader);
+    reader.leave();
+    ret
+}
+
+/// Maximum number of elements allocated for a container before any of them are
+/// deserialized, so a corrupt length fails on the missing data instead of allocating
+#[cfg(feature = "alloc")]
+const MAX_PREALLOCATION: usize = 4096;
+
 /// A buffered reader + writer
+#[cfg(feature = "alloc")]
 pub struct BufferedIo<T: Writer + Reader> {
     /// The type which we can read and write from
     inner: T,
@@ -49,6 +116,7 @@ pub struct BufferedIo<T: Writer + Reader> {
     write: VecDeque<u8>,
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Writer + Reader> BufferedIo<T> {
     /// Create a new buffered I/O object
     pub fn new(inner: T) -> Self {
@@ -72,6 +140,7 @@ impl<T: Writer + Reader> BufferedIo<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Writer + Reader> Writer for BufferedIo<T> {
     /// Write the `buffer` contents to the buffered writer
     fn write(&mut self, buf: &[u8]) -> Option<()> {
@@ -92,6 +161,7 @@ impl<T: Writer + Reader> Writer for BufferedIo<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: Writer + Reader> Reader for BufferedIo<T> {
     fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
         let mut ptr = &mut buf[..];
@@ -156,7 +226,7 @@ impl Reader for &[u8] {
 }
 
 /// Basic `Writer` implementation for vectors of bytes
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 impl Writer for Vec<u8> {
     fn write(&mut self, buf: &[u8]) -> Option<()> {
         self.extend_from_slice(buf);
@@ -164,6 +234,90 @@ impl Writer for Vec<u8> {
     }
 }
 
+/// `Writer` serializing into a fixed buffer, for use without an allocator
+pub struct SliceWriter<'a> {
+    /// Buffer to serialize into
+    buf: &'a mut [u8],
+
+    /// Number of bytes of `buf` written so far
+    written: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Create a new writer which serializes into `buf`
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, written: 0 }
+    }
+
+    /// Number of bytes written into the buffer
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Number of bytes left in the buffer
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.written
+    }
+
+    /// Get the bytes written into the buffer
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buf[..self.written]
+    }
+}
+
+impl<'a> Writer for SliceWriter<'a> {
+    /// Write `buf` into the buffer. Fails without writing anything if `buf` doesn't
+    /// fit.
+    fn write(&mut self, buf: &[u8]) -> Option<()> {
+        let end = self.written.checked_add(buf.len())?;
+        self.buf.get_mut(self.written..end)?.copy_from_slice(buf);
+        self.written = end;
+        Some(())
+    }
+}
+
+/// `Reader` deserializing out of a fixed buffer, for use without an allocator
+pub struct SliceReader<'a> {
+    /// Buffer to deserialize from
+    buf: &'a [u8],
+
+    /// Number of bytes of `buf` read so far
+    read: usize,
+}
+
+impl<'a> SliceReader<'a> {
+    /// Create a new reader which deserializes from `buf`
+    pub fn new(buf: &'a [u8]) -> Self {
+        SliceReader { buf, read: 0 }
+    }
+
+    /// Number of bytes read from the buffer
+    pub fn consumed(&self) -> usize {
+        self.read
+    }
+
+    /// Get the bytes not yet read from the buffer
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.buf[self.read..]
+    }
+}
+
+impl<'a> Reader for SliceReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        // Determine number of bytes we can read
+        let remaining = self.remaining();
+        let to_read   = core::cmp::min(buf.len(), remaining.len());
+
+        // Nothing left to read is an error
+        if to_read == 0 { return None; }
+
+        buf[..to_read].copy_from_slice(&remaining[..to_read]);
+        self.read += to_read;
+
+        Some(to_read)
+    }
+}
+
 /// Serialize a `self` into a writer
 pub trait Serialize {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()>;
@@ -214,21 +368,68 @@ macro_rules! serialize_le {
     ($input_type:ty) => {
         serialize_le!($input_type, $input_type);
     };
+
+    // Serialize an `$input_type` as an `$wire_type`, or as a LEB128 varint for compact
+    // streams. `$to_varint` and `$from_varint` convert between the `$wire_type` and the
+    // `u128` that is encoded.
+    ($input_type:ty, $wire_type:ty, $to_varint:expr, $from_varint:expr) => {
+        impl Serialize for $input_type {
+            fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+                let wire: $wire_type = (*self).try_into()
+                    .expect("Should never happen, input type to wire type");
+
+                if writer.compact() {
+                    varint::write_unsigned(writer, $to_varint(wire))
+                } else {
+                    writer.write(&wire.to_le_bytes())
+                }
+            }
+        }
+
+        impl Deserialize for $input_type {
+            fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+                let wire_val: $wire_type = if reader.compact() {
+                    // Read the varint and make sure it fits in the `$wire_type`
+                    $from_varint(varint::read_unsigned(reader)?).try_into().ok()?
+                } else {
+                    // Read in the bytes for this type
+                    let mut arr = [0u8; core::mem::size_of::<$wire_type>()];
+                    reader.read_exact(&mut arr)?;
+                    <$wire_type>::from_le_bytes(arr)
+                };
+
+                // Try to convert the wire-format type into the desired type
+                wire_val.try_into().ok()
+            }
+        }
+    };
+
+    // Serialize an unsigned `$input_type` as LEB128 in compact streams
+    ($input_type:ty, $wire_type:ty, unsigned) => {
+        serialize_le!($input_type, $wire_type, u128::from, |val| val);
+    };
+
+    // Serialize a signed `$input_type` as zigzag LEB128 in compact streams
+    ($input_type:ty, $wire_type:ty, signed) => {
+        serialize_le!($input_type, $wire_type,
+            |wire| varint::zigzag(i128::from(wire)), varint::unzigzag);
+    };
 }
 
-// Implement serialization for all of the primitive types
+// Implement serialization for all of the primitive types. Bytes are always a single
+// byte, even in compact streams.
 serialize_le!(u8);
-serialize_le!(u16);
-serialize_le!(u32);
-serialize_le!(u64);
-serialize_le!(u128);
+serialize_le!(u16,   u16,  unsigned);
+serialize_le!(u32,   u32,  unsigned);
+serialize_le!(u64,   u64,  unsigned);
+serialize_le!(u128,  u128, unsigned);
 serialize_le!(i8);
-serialize_le!(i16);
-serialize_le!(i32);
-serialize_le!(i64);
-serialize_le!(i128);
-serialize_le!(usize, u64);
-serialize_le!(isize, i64);
+serialize_le!(i16,   i16,  signed);
+serialize_le!(i32,   i32,  signed);
+serialize_le!(i64,   i64,  signed);
+serialize_le!(i128,  i128, signed);
+serialize_le!(usize, u64,  unsigned);
+serialize_le!(isize, i64,  signed);
 
 impl Serialize for bool {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
@@ -253,11 +454,10 @@ impl Serialize for str {
     }
 }
 
-/// Implement serialize for `&str`
-impl Serialize for &str {
+/// Implement serialize for references, such as `&str` and `&[u8]`
+impl<T: Serialize + ?Sized> Serialize for &T {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
-        // Serialize the underlying bytes of the string
-        Serialize::serialize(self.as_bytes(), writer)
+        Serialize::serialize(*self, writer)
     }
 }
 
@@ -306,6 +506,7 @@ impl<T: Deserialize> Deserialize for Option<T> {
 }
 
 /// Implement `Serialize` for `String`
+#[cfg(feature = "alloc")]
 impl Serialize for String {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
         // Serialize the underlying bytes of the string
@@ -314,6 +515,7 @@ impl Serialize for String {
 }
 
 /// Implement `Deserialize` for `String`
+#[cfg(feature = "alloc")]
 impl Deserialize for String {
     fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
         // Deserialize a vector of bytes
@@ -327,6 +529,7 @@ impl Deserialize for String {
 }
 
 /// Implement `Serialize` for types which can be `Cow`ed
+#[cfg(feature = "alloc")]
 impl<'a, T: 'a> Serialize for Cow<'a, T>
         where T: Serialize + ToOwned + ?Sized {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
@@ -335,6 +538,7 @@ impl<'a, T: 'a> Serialize for Cow<'a, T>
 }
 
 /// Implement `Deserialize` for types which can be `Cow`ed
+#[cfg(feature = "alloc")]
 impl<'a, T: 'a> Deserialize for Cow<'a, T>
         where T: ToOwned + ?Sized,
               <T as ToOwned>::Owned: Deserialize {
@@ -347,6 +551,7 @@ impl<'a, T: 'a> Deserialize for Cow<'a, T>
 }
 
 /// Implement `Serialize` for `Box`
+#[cfg(feature = "alloc")]
 impl<T: Serialize> Serialize for Box<T> {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
         Serialize::serialize(self.as_ref(), writer)
@@ -354,14 +559,16 @@ impl<T: Serialize> Serialize for Box<T> {
 }
 
 /// Implement `Deserialize` for `Box`
+#[cfg(feature = "alloc")]
 impl<T: Deserialize> Deserialize for Box<T> {
     fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
-        let thing: T = Deserialize::deserialize(reader)?;
+        let thing: T = nested(reader, Deserialize::deserialize)?;
         Some(Box::new(thing))
     }
 }
 
 /// Implement `Serialize` for `Arc`
+#[cfg(feature = "alloc")]
 impl<T: Serialize> Serialize for Arc<T> {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
         Serialize::serialize(self.as_ref(), writer)
@@ -369,14 +576,16 @@ impl<T: Serialize> Serialize for Arc<T> {
 }
 
 /// Implement `Deserialize` for `Arc`
+#[cfg(feature = "alloc")]
 impl<T: Deserialize> Deserialize for Arc<T> {
     fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
-        let thing: T = Deserialize::deserialize(reader)?;
+        let thing: T = nested(reader, Deserialize::deserialize)?;
         Some(Arc::new(thing))
     }
 }
 
 /// Implement `Serialize` for `Vec<T>`
+#[cfg(feature = "alloc")]
 impl<T: Serialize> Serialize for Vec<T> {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
         // Serialize the number of elements
@@ -388,24 +597,29 @@ impl<T: Serialize> Serialize for Vec<T> {
 }
 
 /// Implement `Deserialize` for `Vec`s that contain all `Deserialize` types
+#[cfg(feature = "alloc")]
 impl<T: Deserialize> Deserialize for Vec<T> {
     fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
         // Get the length of the vector in elements
         let len = <usize as Deserialize>::deserialize(reader)?;
+        reader.elements(len)?;
 
         // Allocate the vector we're going to return
-        let mut vec = Vec::with_capacity(len);
+        let mut vec = Vec::with_capacity(core::cmp::min(len, MAX_PREALLOCATION));
 
         // Deserialize all the components
-        for _ in 0..len {
-            vec.push(<T as Deserialize>::deserialize(reader)?);
-        }
+        nested(reader, |reader| {
+            for _ in 0..len {
+                vec.push(<T as Deserialize>::deserialize(reader)?);
+            }
 
-        Some(vec)
+            Some(vec)
+        })
     }
 }
 
 /// Implement `Serialize` for `BTreeSet<T>`
+#[cfg(feature = "alloc")]
 impl<T: Serialize> Serialize for BTreeSet<T> {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
         // Serialize the number of elements
@@ -417,23 +631,236 @@ impl<T: Serialize> Serialize for BTreeSet<T> {
 }
 
 /// Implement `Deserialize` for `BTreeSet`s that contain all `Deserialize` types
+#[cfg(feature = "alloc")]
 impl<T: Deserialize + core::cmp::Ord> Deserialize for BTreeSet<T> {
     fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
         // Get the length of the vector in elements
         let len = <usize as Deserialize>::deserialize(reader)?;
+        reader.elements(len)?;
 
         // Allocate the vector we're going to return
         let mut res = BTreeSet::new();
 
         // Deserialize all the components
+        nested(reader, |reader| {
+            for _ in 0..len {
+                res.insert(<T as Deserialize>::deserialize(reader)?);
+            }
+
+            Some(res)
+        })
+    }
+}
+
+/// Deserialize a length prefixed sequence of `T`s, handing each one to `insert`
+#[cfg(feature = "alloc")]
+fn deserialize_seq<R, T>(reader: &mut R, mut insert: impl FnMut(T)) -> Option<()>
+        where R: Reader, T: Deserialize {
+    // Get the length of the sequence in elements
+    let len = <usize as Deserialize>::deserialize(reader)?;
+    reader.elements(len)?;
+
+    // Deserialize all the components
+    nested(reader, |reader| {
         for _ in 0..len {
-            res.insert(<T as Deserialize>::deserialize(reader)?);
+            insert(<T as Deserialize>::deserialize(reader)?);
         }
 
+        Some(())
+    })
+}
+
+/// Implement `Serialize` for `VecDeque<T>`
+#[cfg(feature = "alloc")]
+impl<T: Serialize> Serialize for VecDeque<T> {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        // Serialize the number of elements
+        Serialize::serialize(&self.len(), writer)?;
+
+        // Serialize all of the values
+        self.iter().try_for_each(|x| Serialize::serialize(x, writer))
+    }
+}
+
+/// Implement `Deserialize` for `VecDeque`s that contain all `Deserialize` types
+#[cfg(feature = "alloc")]
+impl<T: Deserialize> Deserialize for VecDeque<T> {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        let mut res = VecDeque::new();
+        deserialize_seq(reader, |x| res.push_back(x))?;
+        Some(res)
+    }
+}
+
+/// Implement `Serialize` for `BTreeMap<K, V>` as a sequence of key and value pairs
+#[cfg(feature = "alloc")]
+impl<K: Serialize, V: Serialize> Serialize for BTreeMap<K, V> {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        // Serialize the number of entries
+        Serialize::serialize(&self.len(), writer)?;
+
+        // Serialize all of the entries
+        self.iter().try_for_each(|entry| Serialize::serialize(&entry, writer))
+    }
+}
+
+/// Implement `Deserialize` for `BTreeMap`s with `Deserialize` keys and values
+#[cfg(feature = "alloc")]
+impl<K, V> Deserialize for BTreeMap<K, V>
+        where K: Deserialize + core::cmp::Ord, V: Deserialize {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        let mut res = BTreeMap::new();
+        deserialize_seq(reader, |(key, val)| { res.insert(key, val); })?;
+        Some(res)
+    }
+}
+
+/// Implement `Serialize` for `HashMap<K, V>` as a sequence of key and value pairs
+#[cfg(feature = "std")]
+impl<K: Serialize, V: Serialize, S> Serialize for HashMap<K, V, S> {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        // Serialize the number of entries
+        Serialize::serialize(&self.len(), writer)?;
+
+        // Serialize all of the entries
+        self.iter().try_for_each(|entry| Serialize::serialize(&entry, writer))
+    }
+}
+
+/// Implement `Deserialize` for `HashMap`s with `Deserialize` keys and values
+#[cfg(feature = "std")]
+impl<K, V, S> Deserialize for HashMap<K, V, S>
+        where K: Deserialize + Eq + Hash, V: Deserialize, S: BuildHasher + Default {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        let mut res = HashMap::default();
+        deserialize_seq(reader, |(key, val)| { res.insert(key, val); })?;
+        Some(res)
+    }
+}
+
+/// Implement `Serialize` for `HashSet<T>`
+#[cfg(feature = "std")]
+impl<T: Serialize, S> Serialize for HashSet<T, S> {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        // Serialize the number of elements
+        Serialize::serialize(&self.len(), writer)?;
+
+        // Serialize all of the values
+        self.iter().try_for_each(|x| Serialize::serialize(x, writer))
+    }
+}
+
+/// Implement `Deserialize` for `HashSet`s that contain all `Deserialize` types
+#[cfg(feature = "std")]
+impl<T, S> Deserialize for HashSet<T, S>
+        where T: Deserialize + Eq + Hash, S: BuildHasher + Default {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        let mut res = HashSet::default();
+        deserialize_seq(reader, |x| { res.insert(x); })?;
         Some(res)
     }
 }
 
+/// Implement `Serialize` for `char` as its `u32` code point
+impl Serialize for char {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        Serialize::serialize(&u32::from(*self), writer)
+    }
+}
+
+/// Implement `Deserialize` for `char`, failing on invalid code points
+impl Deserialize for char {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        core::char::from_u32(<u32 as Deserialize>::deserialize(reader)?)
+    }
+}
+
+/// Implement `Serialize` for `Duration` as its seconds followed by its nanoseconds
+impl Serialize for Duration {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        Serialize::serialize(&self.as_secs(), writer)?;
+        Serialize::serialize(&self.subsec_nanos(), writer)
+    }
+}
+
+/// Implement `Deserialize` for `Duration`, failing if the nanoseconds are a second or
+/// more
+impl Deserialize for Duration {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        let secs  = <u64 as Deserialize>::deserialize(reader)?;
+        let nanos = <u32 as Deserialize>::deserialize(reader)?;
+
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+
+        Some(Duration::new(secs, nanos))
+    }
+}
+
+/// Implement `Serialize` for `Result` the same way as `Option`, with a `0` tag for
+/// `Ok` and a `1` tag for `Err`
+impl<T: Serialize, E: Serialize> Serialize for Result<T, E> {
+    fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+        match self {
+            Ok(val) => {
+                writer.write(&[0])?;
+                Serialize::serialize(val, writer)
+            }
+            Err(err) => {
+                writer.write(&[1])?;
+                Serialize::serialize(err, writer)
+            }
+        }
+    }
+}
+
+/// Implement `Deserialize` for `Result`
+impl<T: Deserialize, E: Deserialize> Deserialize for Result<T, E> {
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        match <u8 as Deserialize>::deserialize(reader)? {
+            0 => Some(Ok(<T as Deserialize>::deserialize(reader)?)),
+            1 => Some(Err(<E as Deserialize>::deserialize(reader)?)),
+            _ => None,
+        }
+    }
+}
+
+/// Implement `Serialize` and `Deserialize` for tuples by serializing each element in
+/// order
+macro_rules! serialize_tuple {
+    ($($name:ident: $index:tt),*) => {
+        impl<$($name: Serialize),*> Serialize for ($($name,)*) {
+            #[allow(unused_variables)]
+            fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
+                $(Serialize::serialize(&self.$index, writer)?;)*
+                Some(())
+            }
+        }
+
+        impl<$($name: Deserialize),*> Deserialize for ($($name,)*) {
+            #[allow(unused_variables)]
+            fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+                Some(($(<$name as Deserialize>::deserialize(reader)?,)*))
+            }
+        }
+    };
+}
+
+serialize_tuple!();
+serialize_tuple!(A: 0);
+serialize_tuple!(A: 0, B: 1);
+serialize_tuple!(A: 0, B: 1, C: 2);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+serialize_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
 /// Implement `Serialize` trait for arrays of types which implement `Serialize`
 impl<T: Serialize, const N: usize> Serialize for [T; N] {
     fn serialize<W: Writer>(&self, writer: &mut W) -> Option<()> {
@@ -443,40 +870,47 @@ impl<T: Serialize, const N: usize> Serialize for [T; N] {
 }
 
 impl<T: Deserialize, const N: usize> Deserialize for [T; N] {
-    fn deserialize<R: Reader>(_reader: &mut R) -> Option<Self> {
-        // Deserialize the array
-        let mut arr: MaybeUninit<[T; N]> = MaybeUninit::uninit();
-
-        unsafe {
-            // Get mutable access to the array
-            let ptr: *mut T = arr.as_mut_ptr() as *mut T;
-
-            // Deserialize each element
-            let mut deserialized = 0;
-            for ii in 0..N {
-                if let Some(x) = Deserialize::deserialize(_reader) {
-                    ptr.offset(ii as isize).write(x);
-                    deserialized += 1;
-                } else {
-                    // Failed to deserialize, break out
-                    break;
-                }
-            }
-
-            // Check if we deserialized everything
-            if deserialized != N {
-                // Drop things that were partially deserialized
-                for ii in 0..deserialized {
-                    core::ptr::drop_in_place(ptr.offset(ii as isize));
-                }
+    fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
+        deserialize_array(|| Deserialize::deserialize(reader))
+    }
+}
 
-                // Return failure
-                return None;
+/// Create an array by calling `deserialize` for each element, dropping the elements
+/// created so far if one fails
+fn deserialize_array<T, const N: usize>(mut deserialize: impl FnMut() -> Option<T>)
+        -> Option<[T; N]> {
+    // Deserialize the array
+    let mut arr: MaybeUninit<[T; N]> = MaybeUninit::uninit();
+
+    unsafe {
+        // Get mutable access to the array
+        let ptr: *mut T = arr.as_mut_ptr() as *mut T;
+
+        // Deserialize each element
+        let mut deserialized = 0;
+        for ii in 0..N {
+            if let Some(x) = deserialize() {
+                ptr.offset(ii as isize).write(x);
+                deserialized += 1;
+            } else {
+                // Failed to deserialize, break out
+                break;
             }
         }
 
-        Some(unsafe { arr.assume_init() })
+        // Check if we deserialized everything
+        if deserialized != N {
+            // Drop things that were partially deserialized
+            for ii in 0..deserialized {
+                core::ptr::drop_in_place(ptr.offset(ii as isize));
+            }
+
+            // Return failure
+            return None;
+        }
     }
+
+    Some(unsafe { arr.assume_init() })
 }
 
 /// Implement serialize and deserialize on an enum or structure definition.
@@ -490,12 +924,10 @@ impl<T: Deserialize, const N: usize> Deserialize for [T; N] {
 /// field in the structure (or enum variant) and serializing it out in
 /// definition order.
 ///
-/// This all looks really complicated, but it's really just a lot of copied
-/// and pasted code that can represent a structure or enum shape in macros.
-/// These macros destruct all possible structs and enums and gives us "access"
-/// to the inner field names, ordering, and types. This allows us to invoke
-/// the `serialize` or `deserialize` routines for every member of the
-/// structure. It's that simple!
+/// The definition is passed through as is with
+/// `#[derive(Serialize, Deserialize, Describe)]` added, so this is equivalent to using
+/// the derives from `noodle_derive` directly. The macro only normalizes the shapes the
+/// derives can't parse on their own, such as `Variant(,)`.
 #[macro_export]
 macro_rules! noodle {
     // Create a new struct with serialize and deserialize implemented
@@ -520,40 +952,7 @@ macro_rules! noodle {
             $(;)?
     ) => {
         noodle!(define_struct,
-            $(#[$attr])* $vis struct $structname $(<$($generic),*>)?
-            // Named struct
-            $({
-                $(
-                    $(#[$named_attr])*
-                        $named_vis $named_field: $named_type
-                ),*
-            })?
-
-            // Named tuple
-            $((
-                $(
-                    $(#[$tuple_meta])* $tuple_vis $tuple_typ
-                ),*
-            );)?
-        );
-        noodle!(impl_serialize_struct,
-            $(#[$attr])* $vis struct $structname $(<$($generic),*>)?
-            // Named struct
-            $({
-                $(
-                    $(#[$named_attr])*
-                        $named_vis $named_field: $named_type
-                ),*
-            })?
-
-            // Named tuple
-            $((
-                $(
-                    $(#[$tuple_meta])* $tuple_vis $tuple_typ
-                ),*
-            );)?
-        );
-        noodle!(impl_deserialize_struct,
+            #[derive($crate::Serialize, $crate::Deserialize, $crate::Describe)]
             $(#[$attr])* $vis struct $structname $(<$($generic),*>)?
             // Named struct
             $({
@@ -614,109 +1013,13 @@ macro_rules! noodle {
         );)?
     };
 
-    // Implement serialization for a structure
-    (impl_serialize_struct,
-        $(#[$attr:meta])* $vis:vis struct $structname:ident $(<$($generic:tt),*>)?
-            // Named struct
-            $({
-                $(
-                    $(#[$named_attr:meta])*
-                        $named_vis:vis $named_field:ident: $named_type:ty
-                ),*$(,)?
-            })?
-
-            // Named tuple
-            $((
-                $(
-                    $(#[$tuple_meta:meta])* $tuple_vis:vis $tuple_typ:ty
-                ),*$(,)? 
-            );)?
-    ) => {
-        impl $(<$($generic),*>)? Serialize for $structname $(<$($generic),*>)? {
-            fn serialize<W: Writer>(&self, _writer: &mut W) -> Option<()> {
-                // Named struct
-                $(
-                    $(
-                        Serialize::serialize(&self.$named_field, _writer)?;
-                    )*
-                )?
-
-                // Named tuple
-                handle_serialize_named_tuple!(
-                    self, _writer $($(, $tuple_typ)*)?);
-
-                Some(())
-            }
-        }
-    };
-
-    // Implement deserialization for a field-less structs
-    (impl_deserialize_struct,
-        $(#[$attr:meta])* $vis:vis struct $structname:ident $(<$($generic:tt),*>)?
-    ) => {
-        impl $(<$($generic),*>)? Deserialize for $structname $(<$($generic),*>)? {
-            fn deserialize<R: Reader>(_reader: &mut R) -> Option<Self> {
-                Some($structname)
-            }
-        }
-    };
-
-    // Implement deserialization for a structure
-    (impl_deserialize_struct,
-        $(#[$attr:meta])* $vis:vis struct $structname:ident $(<$($generic:tt),*>)?
-            // Named struct
-            $({
-                $(
-                    $(#[$named_attr:meta])*
-                        $named_vis:vis $named_field:ident: $named_type:ty
-                ),*$(,)?
-            })?
-
-            // Named tuple
-            $((
-                $(
-                    $(#[$tuple_meta:meta])* $tuple_vis:vis $tuple_typ:ty
-                ),*$(,)? 
-            );)?
-    ) => {
-        impl $(<$($generic),*>)? Deserialize for $structname $(<$($generic),*>)? {
-            fn deserialize<R: Reader>(_reader: &mut R) -> Option<Self> {
-                // Named struct
-                $(if true {
-                    
-                    let ret = $structname {
-                        $(
-                            $named_field: Deserialize::deserialize(_reader)?,
-                        )*
-                    };
-
-                    return Some(ret);
-                })?
-
-                // Named tuple
-                $(if true {
-                    let ret = $structname(
-                        $(
-                            <$tuple_typ as Deserialize>::deserialize(_reader)?,
-                        )*
-                    );
-
-                    return Some(ret);
-                })?
-
-                // Not reachable
-                unreachable!("How'd you get here?");
-            }
-        }
-    };
-
-    // Create a new enum with serialize and deserialize implemented
-    (serialize, deserialize,
-        $(#[$attr:meta])* $vis:vis enum $enumname:ident $(<$($generic:tt),*>)? {
-            // Go through each variant in the enum
-            $(
-                // Variant attributes
-                $(#[$variant_attr:meta])*
+    // Create a new enum with serialize and deserialize implemented
+    (serialize, deserialize,
+        $(#[$attr:meta])* $vis:vis enum $enumname:ident $(<$($generic:tt),*>)? {
+            // Go through each variant in the enum
+            $(
+                // Variant attributes
+                $(#[$variant_attr:meta])*
 
                 // Identifier for the enum variant, always present
                 $variant_ident:ident
@@ -742,62 +1045,7 @@ macro_rules! noodle {
         }
     ) => {
         noodle!(define_enum,
-            $(#[$attr])* $vis enum $enumname $(<$($generic),*>)? {
-                // Go through each variant in the enum
-                $(
-                    // Variant attributes
-                    $(#[$variant_attr])*
-
-                    // Identifier for the enum variant, always present
-                    $variant_ident
-                    
-                    // An enum item struct
-                    $({
-                        $(
-                            $(#[$named_attr])* $named_field: $named_type
-                        ),*
-                    })?
-
-                    // An enum item tuple
-                    $((
-                        $(
-                            $(#[$tuple_meta])* $tuple_typ
-                        ),*
-                    ))?
-
-                    // An enum discriminant
-                    $(= $expr)?
-                ),*
-            });
-        noodle!(impl_serialize_enum,
-            $(#[$attr])* $vis enum $enumname $(<$($generic),*>)? {
-                // Go through each variant in the enum
-                $(
-                    // Variant attributes
-                    $(#[$variant_attr])*
-
-                    // Identifier for the enum variant, always present
-                    $variant_ident
-                    
-                    // An enum item struct
-                    $({
-                        $(
-                            $(#[$named_attr])* $named_field: $named_type
-                        ),*
-                    })?
-
-                    // An enum item tuple
-                    $((
-                        $(
-                            $(#[$tuple_meta])* $tuple_typ
-                        ),*
-                    ))?
-
-                    // An enum discriminant
-                    $(= $expr)?
-                ),*
-            });
-        noodle!(impl_deserialize_enum,
+            #[derive($crate::Serialize, $crate::Deserialize, $crate::Describe)]
             $(#[$attr])* $vis enum $enumname $(<$($generic),*>)? {
                 // Go through each variant in the enum
                 $(
@@ -885,189 +1133,343 @@ macro_rules! noodle {
                 ),*
             }
     };
+}
 
-    (impl_serialize_enum,
-        $(#[$attr:meta])* $vis:vis enum $enumname:ident $(<$($generic:tt),*>)? {
-            // Go through each variant in the enum
-            $(
-                // Variant attributes
-                $(#[$variant_attr:meta])*
+#[cfg(test)]
+mod test_slice {
+    use crate::*;
 
-                // Identifier for the enum variant, always present
-                $variant_ident:ident
-                
-                // An enum item struct
-                $({
-                    $(
-                        $(#[$named_attr:meta])*
-                            $named_field:ident: $named_type:ty
-                    ),*$(,)?
-                })?
+    #[derive(PartialEq, Debug, Serialize, Deserialize)]
+    enum TestA {
+        Apples([u16; 2]),
+        Bananas { x: Option<u8>, y: bool },
+    }
 
-                // An enum item tuple
-                $((
-                    $(
-                        $(#[$tuple_meta:meta])* $tuple_typ:ty
-                    ),*$(,)? 
-                ))?
+    #[test]
+    fn test_slice_writer() {
+        let mut buf = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut buf);
+
+        TestA::Apples([1, 0x203]).serialize(&mut writer).unwrap();
+        assert!(writer.written() == 8);
+        assert!(writer.remaining() == 8);
+        assert!(writer.into_written() == [0, 0, 0, 0, 1, 0, 3, 2]);
+
+        // Writes that don't fit fail and leave the written bytes alone
+        let mut buf = [0u8; 6];
+        let mut writer = SliceWriter::new(&mut buf);
+        assert!(TestA::Apples([1, 2]).serialize(&mut writer).is_none());
+        assert!(writer.written() == 6);
+    }
 
-                // An enum discriminant
-                $(= $expr:expr)?
-            ),*$(,)?
-        }) => {
-        impl $(<$($generic),*>)? Serialize for $enumname $(<$($generic),*>)? {
-            fn serialize<W: Writer>(&self, _writer: &mut W) -> Option<()> {
-                let mut _count = 0u32;
+    #[test]
+    fn test_slice_reader() {
+        let mut buf = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut buf);
+        let payload = TestA::Bananas { x: Some(5), y: true };
+        payload.serialize(&mut writer).unwrap();
+        payload.serialize(&mut writer).unwrap();
+        let written = writer.written();
+
+        let mut reader = SliceReader::new(&buf[..written]);
+        assert!(TestA::deserialize(&mut reader) == Some(payload));
+        assert!(reader.consumed() == written / 2);
+        assert!(TestA::deserialize(&mut reader).is_some());
+        assert!(reader.remaining().is_empty());
+
+        // Reading past the end of the buffer fails
+        assert!(TestA::deserialize(&mut reader).is_none());
+    }
 
-                // Go through each variant
-                $(
-                    handle_serialize_enum_variants!(
-                        self, $enumname, $variant_ident, _writer, &_count,
-                        $({$($named_field),*})? $(($($tuple_typ),*))?);
+    /// Serialize `val` with or without the compact encoding, check that it deserializes
+    /// to the same value, and return the number of bytes it took
+    fn round_trip<T>(val: T, compact: bool) -> usize
+            where T: Serialize + Deserialize + PartialEq + core::fmt::Debug {
+        let mut buf = [0u8; 128];
+        let mut writer = SliceWriter::new(&mut buf);
+        if compact {
+            val.serialize(&mut Compact::new(&mut writer)).unwrap();
+        } else {
+            val.serialize(&mut writer).unwrap();
+        }
+        let written = writer.written();
 
-                    _count += 1;
-                )*
+        let mut reader = SliceReader::new(&buf[..written]);
+        let deser = if compact {
+            T::deserialize(&mut Compact::new(&mut reader))
+        } else {
+            T::deserialize(&mut reader)
+        };
+        assert_eq!(deser, Some(val));
+        assert!(reader.remaining().is_empty());
 
-                Some(())
-            }
+        written
+    }
+
+    #[test]
+    fn test_compact_primitives() {
+        macro_rules! test_primitive {
+            ($($ty:ty),*) => {$(
+                for &val in &[<$ty>::MIN, <$ty>::MAX, 0, 1, 63, 64, 127] {
+                    assert!(round_trip(val, false) == core::mem::size_of::<$ty>());
+                    round_trip(val, true);
+                }
+            )*}
         }
-    };
+        test_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
-    (impl_deserialize_enum,
-        $(#[$attr:meta])* $vis:vis enum $enumname:ident  $(<$($generic:tt),*>)? {
-            // Go through each variant in the enum
-            $(
-                // Variant attributes
-                $(#[$variant_attr:meta])*
+        for &val in &[false, true] {
+            assert!(round_trip(val, true) == 1);
+        }
 
-                // Identifier for the enum variant, always present
-                $variant_ident:ident
-                
-                // An enum item struct
-                $({
-                    $(
-                        $(#[$named_attr:meta])*
-                            $named_field:ident: $named_type:ty
-                    ),*$(,)?
-                })?
+        // Small values are small, and signed values are zigzagged
+        assert!(round_trip(0u64, true) == 1);
+        assert!(round_trip(127u32, true) == 1);
+        assert!(round_trip(128u32, true) == 2);
+        assert!(round_trip(-1i64, true) == 1);
+        assert!(round_trip(-64i16, true) == 1);
+        assert!(round_trip(-65i16, true) == 2);
+        assert!(round_trip(255u8, true) == 1);
+        assert!(round_trip(u128::MAX, true) == 19);
+        assert!(round_trip(i128::MIN, true) == 19);
+
+        // Lengths and variant indices are varints too
+        assert!(round_trip(TestA::Bananas { x: Some(5), y: true }, true) == 4);
+        assert!(round_trip(TestA::Apples([300, 3]), true) == 4);
+    }
 
-                // An enum item tuple
-                $((
-                    $(
-                        $(#[$tuple_meta:meta])* $tuple_typ:ty
-                    ),*$(,)? 
-                ))?
+    #[test]
+    fn test_core_types() {
+        use core::time::Duration;
+
+        for &compact in &[false, true] {
+            round_trip('a', compact);
+            round_trip('\u{1f980}', compact);
+            round_trip(Duration::new(5, 999_999_999), compact);
+            round_trip(Ok::<u32, i8>(5), compact);
+            round_trip(Err::<u32, i8>(-5), compact);
+            round_trip((), compact);
+            round_trip((1u8,), compact);
+            round_trip((1u8, -2i64, 'c', Some(true)), compact);
+            round_trip((0u8, 1u16, 2u32, 3u64, 4u128, 5usize, 6i8, 7i16, 8i32, 9i64, 10i128,
+                11isize), compact);
+        }
 
-                // An enum discriminant
-                $(= $expr:expr)?
-            ),*$(,)?
-        }) => {
-        impl $(<$($generic),*>)? Deserialize for $enumname $(<$($generic),*>)? {
-            fn deserialize<R: Reader>(reader: &mut R) -> Option<Self> {
-                // Count tracking enum variants
-                let mut _count = 0u32;
+        assert!(round_trip('a', false) == 4);
+        assert!(round_trip(Duration::from_millis(1500), false) == 12);
+        assert!(round_trip(Err::<u32, u8>(9), false) == 2);
+        assert!(round_trip(((), (1u8, 2u16)), false) == 3);
+
+        // Surrogates aren't chars
+        let mut reader = SliceReader::new(&[0x00, 0xd8, 0, 0]);
+        assert!(char::deserialize(&mut reader).is_none());
+
+        // Nanoseconds past a second aren't normalized
+        let mut reader = SliceReader::new(&[0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0xca, 0x9a, 0x3b]);
+        assert!(Duration::deserialize(&mut reader).is_none());
+
+        // Results only have two variants
+        let mut reader = SliceReader::new(&[2, 0]);
+        assert!(Result::<u8, u8>::deserialize(&mut reader).is_none());
+
+        // Tuples borrow each element
+        let mut buf = [0u8; 32];
+        let mut writer = SliceWriter::new(&mut buf);
+        (7u8, "abc", Ok::<&str, u8>("d")).serialize(&mut writer).unwrap();
+        let written = writer.written();
+        let mut reader = SliceReader::new(&buf[..written]);
+        assert!(<(u8, &str, Result<&str, u8>)>::deserialize_borrowed(&mut reader)
+            == Some((7, "abc", Ok("d"))));
+    }
 
-                // Get the enum variant
-                let _variant = u32::deserialize(reader)?;
+    #[test]
+    fn test_compact_invalid() {
+        // Varints that don't fit the type fail
+        let mut reader = SliceReader::new(&[0x80, 0x80, 0x04]);
+        assert!(u16::deserialize(&mut Compact::new(&mut reader)).is_none());
+
+        let mut reader = SliceReader::new(&[0x7f]);
+        assert!(i8::deserialize(&mut Compact::new(&mut reader)) == Some(0x7f));
+
+        // Varints longer than a `u128` fail
+        let mut reader = SliceReader::new(&[0xff; 20]);
+        assert!(u128::deserialize(&mut Compact::new(&mut reader)).is_none());
+
+        let mut buf = [0xffu8; 19];
+        buf[18] = 0x04;
+        let mut reader = SliceReader::new(&buf);
+        assert!(u128::deserialize(&mut Compact::new(&mut reader)).is_none());
+    }
 
-                // Go through each variant
-                $(
-                    handle_deserialize_enum_variants!(
-                        _variant, $enumname, $variant_ident,
-                        reader, _count,
-                        $({$($named_field),*})? $(($($tuple_typ),*))?);
+    #[test]
+    fn test_versioned() {
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        #[noodle(version = 1)]
+        struct StatsV1 {
+            cycles: u64,
+            #[noodle(skip)]
+            cached: u8,
+            faults: u16,
+        }
 
-                    _count += 1;
-                )*
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        #[noodle(version = 2)]
+        struct StatsV2 {
+            cycles: u64,
+            #[noodle(skip)]
+            cached: u8,
+            faults: u16,
+            #[noodle(default)]
+            resets: u32,
+            #[noodle(default = "new_flags")]
+            flags: [bool; 2],
+        }
 
-                // Failed to find a matching variant, return `None`
-                None
-            }
+        fn new_flags() -> [bool; 2] {
+            [true, false]
         }
-    };
-}
 
-/// Handles serializing of the 3 different enum variant types. Enum struct
-/// variants, enum tuple variants, and enum discriminant/bare variants
-#[macro_export]
-macro_rules! handle_serialize_enum_variants {
-    // Named enum variants
-    ($self:ident, $enumname:ident, $variant_ident:ident,
-            $buf:expr, $count:expr, {$($named_field:ident),*}) => {
-        if let $enumname::$variant_ident { $($named_field),* } = $self {
-            // Serialize the variant ID
-            Serialize::serialize($count, $buf)?;
-
-            // Serialize all fields
-            $(
-                Serialize::serialize($named_field, $buf)?;
-            )*
+        // Version, field count, then each field after its length
+        let mut buf = [0u8; 64];
+        let mut writer = SliceWriter::new(&mut buf);
+        StatsV1 { cycles: 1, cached: 2, faults: 3 }.serialize(&mut writer).unwrap();
+        let written = writer.written();
+        assert!(buf[..written] == [1, 0, 0, 0, 2, 0, 0, 0,
+            8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            2, 0, 0, 0, 0, 0, 0, 0, 3, 0]);
+
+        // New readers fill in the fields missing from old data
+        let mut reader = SliceReader::new(&buf[..written]);
+        assert!(StatsV2::deserialize(&mut reader) == Some(StatsV2 {
+            cycles: 1, cached: 0, faults: 3, resets: 0, flags: [true, false]
+        }));
+        assert!(reader.remaining().is_empty());
+
+        // Old readers skip the fields added after them
+        let val = StatsV2 { cycles: 4, cached: 5, faults: 6, resets: 7, flags: [false; 2] };
+        let mut buf = [0u8; 128];
+        let mut writer = SliceWriter::new(&mut buf);
+        val.serialize(&mut writer).unwrap();
+        val.serialize(&mut writer).unwrap();
+        let written = writer.written();
+
+        let mut reader = SliceReader::new(&buf[..written]);
+        for _ in 0..2 {
+            assert!(StatsV1::deserialize(&mut reader)
+                == Some(StatsV1 { cycles: 4, cached: 0, faults: 6 }));
+        }
+        assert!(reader.remaining().is_empty());
+
+        // Versioned structs in compact streams
+        let val = StatsV2 { cycles: 4, cached: 0, faults: 6, resets: 7, flags: [true; 2] };
+        assert!(round_trip(val, true) == 1 + 1 + 2 + 2 + 2 + 3);
+
+        // Fields without a default can't be missing
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        #[noodle(version = 3)]
+        struct StatsV3 {
+            cycles: u64,
+            faults: u16,
+            resets: u32,
         }
-    };
 
-    // Tuple enum variants
-    ($self:ident, $enumname:ident, $variant_ident:ident,
-            $buf:expr, $count:expr, ($($tuple_typ:ty),*)) => {
-        handle_serialize_tuple_match!($self, $count, $buf, $enumname,
-            $variant_ident $(, $tuple_typ)*);
-    };
+        let mut buf = [0u8; 64];
+        let mut writer = SliceWriter::new(&mut buf);
+        StatsV1 { cycles: 1, cached: 2, faults: 3 }.serialize(&mut writer).unwrap();
+        let written = writer.written();
+
+        let mut reader = SliceReader::new(&buf[..written]);
+        assert!(StatsV3::deserialize(&mut reader).is_none());
+    }
 
-    // Discriminant or empty enum variants
-    ($self:ident, $enumname:ident, $variant_ident:ident,
-            $buf:expr, $count:expr,) => {
-        if let $enumname::$variant_ident = $self {
-            // Serialize the variant ID
-            Serialize::serialize($count, $buf)?;
+    #[test]
+    fn test_borrowed() {
+        #[derive(PartialEq, Debug, Clone, Copy, Serialize, DeserializeBorrowed)]
+        struct Page<'a, 'b, T> {
+            name:  &'a str,
+            data:  &'b [u8],
+            next:  Option<&'a str>,
+            extra: T,
         }
-    };
-}
 
-/// Handles deserializing of the 3 different enum variant types. Enum struct
-/// variants, enum tuple variants, and enum discriminant/bare variants
-#[macro_export]
-macro_rules! handle_deserialize_enum_variants {
-    // Named enum variants
-    ($variant:ident, $enumname:ident, $variant_ident:ident, $reader:expr,
-            $count:expr, {$($named_field:ident),*}) => {
-        if $count == $variant {
-            // Construct the enum
-            let ret = $enumname::$variant_ident {
-                $(
-                    $named_field: Deserialize::deserialize($reader)?,
-                )*
-            };
+        #[derive(PartialEq, Debug, Serialize, DeserializeBorrowed)]
+        enum Message<'a> {
+            Empty,
+            Pages([Page<'a, 'a, u16>; 2]),
+            #[allow(dead_code)]
+            Name { #[noodle(varint)] id: u64, name: &'a str },
+        }
 
-            return Some(ret);
+        let page = Page { name: "kernel", data: &[1, 2, 3], next: Some("b"), extra: 5u16 };
+        let val = Message::Pages([page, Page { next: None, ..page }]);
+
+        let mut buf = [0u8; 128];
+        let mut writer = SliceWriter::new(&mut buf);
+        val.serialize(&mut writer).unwrap();
+        Message::Empty.serialize(&mut writer).unwrap();
+        let written = writer.written();
+
+        // Strings and bytes point into the serialized buffer
+        let mut reader = SliceReader::new(&buf[..written]);
+        let deser = Message::deserialize_borrowed(&mut reader).unwrap();
+        assert!(deser == val);
+
+        let buf_range = buf.as_ptr_range();
+        match deser {
+            Message::Pages([page, _]) => {
+                assert!(buf_range.contains(&page.name.as_ptr()));
+                assert!(buf_range.contains(&page.data.as_ptr()));
+            }
+            _ => unreachable!(),
         }
-    };
 
-    // Tuple enum variants
-    ($variant:ident, $enumname:ident, $variant_ident:ident, $reader:expr,
-            $count:expr, ($($tuple_typ:ty),*)) => {
-        if $count == $variant {
-            // Construct the enum
-            let ret = $enumname::$variant_ident (
-                $(
-                    <$tuple_typ as Deserialize>::deserialize($reader)?,
-                )*
-            );
+        assert!(Message::deserialize_borrowed(&mut reader) == Some(Message::Empty));
+        assert!(reader.remaining().is_empty());
+
+        // Borrowed and owned deserialization read the same format
+        let mut reader = &buf[..written];
+        assert!(<[u32; 2]>::deserialize_borrowed(&mut reader)
+            == <[u32; 2]>::deserialize(&mut &buf[..written]));
 
-            return Some(ret);
+        // Invalid UTF-8 and lengths past the end of the buffer fail
+        let mut reader = SliceReader::new(&[1, 0, 0, 0, 0, 0, 0, 0, 0xff]);
+        assert!(<&str>::deserialize_borrowed(&mut reader).is_none());
+
+        let mut reader = SliceReader::new(&[4, 0, 0, 0, 0, 0, 0, 0, 1, 2]);
+        assert!(<&[u8]>::deserialize_borrowed(&mut reader).is_none());
+
+        // Limits apply to borrowed bytes
+        let limits = DeserializeLimits::new(12, 16, 4);
+        let mut reader = SliceReader::new(&buf[..written]);
+        assert!(limits.deserialize_borrowed::<Message, _>(&mut reader)
+            == Err(DeserializeError::TooManyBytes));
+    }
+
+    #[test]
+    fn test_compact_fields() {
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        struct TestB {
+            full: u32,
+            #[noodle(varint)]
+            compact: u32,
+            #[noodle(varint, default)]
+            offsets: [i64; 2],
         }
-    };
 
-    // Discriminant or empty enum variants
-    ($variant:ident, $enumname:ident, $variant_ident:ident, $reader:expr,
-            $count:expr,) => {
-        if $count == $variant {
-            // Construct the enum
-            let ret = $enumname::$variant_ident;
-            return Some(ret);
+        let val = TestB { full: 5, compact: 5, offsets: [-1, 1] };
+        assert!(round_trip(val, false) == 4 + 1 + 2);
+
+        // Fields outside of a compact stream still use their own encoding
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        enum TestC {
+            Apples(u64, #[noodle(varint)] u64),
         }
-    };
+        assert!(round_trip(TestC::Apples(1, 1), false) == 4 + 8 + 1);
+        assert!(round_trip(TestC::Apples(1, 1), true) == 1 + 1 + 1);
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test {
     use crate::*;
 
@@ -1251,5 +1653,542 @@ mod test {
         );
         test_serdes!(TestI, TestI(Cow::Borrowed("asdf"), Cow::Borrowed("a")));
     }
-}
 
+    /// Default used by the `default = "path"` attribute tests
+    fn default_tag() -> u16 {
+        0xbeef
+    }
+
+    #[test]
+    fn test_derive() {
+        // Derives on their own produce the same layout as `noodle!`
+        #[derive(PartialEq, Serialize, Deserialize)]
+        struct TestA {
+            foo: u32,
+            bar: i8,
+        }
+        test_serdes!(TestA, TestA { foo: 0x11223344, bar: -1 });
+
+        let mut ser = Vec::new();
+        TestA { foo: 0x11223344, bar: -1 }.serialize(&mut ser).unwrap();
+        assert!(ser == [0x44, 0x33, 0x22, 0x11, 0xff]);
+
+        // Enum variants are prefixed by their index, not their discriminant
+        #[derive(PartialEq, Serialize, Deserialize)]
+        #[repr(u8)]
+        enum TestB {
+            Apples = 6,
+            Bananas { x: u8 },
+            Cakes(u16, u8),
+        }
+        test_serdes!(TestB, TestB::Apples);
+        test_serdes!(TestB, TestB::Bananas { x: 3 });
+        test_serdes!(TestB, TestB::Cakes(9, 10));
+
+        let mut ser = Vec::new();
+        TestB::Cakes(0x0102, 3).serialize(&mut ser).unwrap();
+        assert!(ser == [2, 0, 0, 0, 2, 1, 3]);
+
+        // Unknown variants fail to deserialize
+        let mut ptr = &[3u8, 0, 0, 0][..];
+        assert!(TestB::deserialize(&mut ptr).is_none());
+
+        // Skipped fields are not serialized and are filled in with their default
+        #[derive(PartialEq, Serialize, Deserialize)]
+        struct TestC {
+            foo: u8,
+            #[noodle(skip)]
+            cache: Vec<u32>,
+            #[noodle(skip, default = "default_tag")]
+            tag: u16,
+            #[noodle(rename = "baz")]
+            bar: u8,
+        }
+
+        let mut ser = Vec::new();
+        TestC { foo: 1, cache: alloc::vec![5], tag: 7, bar: 2 }.serialize(&mut ser).unwrap();
+        assert!(ser == [1, 2]);
+
+        let mut ptr = &ser[..];
+        let deser = TestC::deserialize(&mut ptr).unwrap();
+        assert!(deser == TestC { foo: 1, cache: Vec::new(), tag: 0xbeef, bar: 2 });
+
+        // Skipped fields in enum variants
+        #[derive(PartialEq, Serialize, Deserialize)]
+        enum TestD {
+            Apples(#[noodle(skip)] u32, u8),
+            Bananas { #[noodle(skip)] x: u32, y: u8 },
+        }
+
+        let mut ser = Vec::new();
+        TestD::Bananas { x: 5, y: 6 }.serialize(&mut ser).unwrap();
+        assert!(ser == [1, 0, 0, 0, 6]);
+        test_serdes!(TestD, TestD::Apples(0, 4));
+
+        // Trailing fields with a default accept data written before they existed
+        #[derive(PartialEq, Serialize, Deserialize)]
+        struct TestE {
+            foo: u8,
+            #[noodle(default)]
+            bar: u32,
+            #[noodle(default = "default_tag")]
+            baz: u16,
+        }
+        test_serdes!(TestE, TestE { foo: 1, bar: 2, baz: 3 });
+
+        let mut ptr = &[9u8][..];
+        let deser = TestE::deserialize(&mut ptr).unwrap();
+        assert!(deser == TestE { foo: 9, bar: 0, baz: 0xbeef });
+
+        // Fields that fail after reading part of their bytes still fail the type, so
+        // truncated data isn't read from the middle of a field
+        let mut ptr = &[9u8, 1, 2][..];
+        assert!(TestE::deserialize(&mut ptr).is_none());
+
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        struct TestV {
+            a: u8,
+            #[noodle(default)]
+            b: u16,
+        }
+
+        let mut ser = Vec::new();
+        alloc::vec![TestV { a: 1, b: 2 }].serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..ser.len() - 1];
+        assert!(Vec::<TestV>::deserialize(&mut ptr).is_none());
+
+        // Generics get the trait bounds added, and where clauses are kept
+        #[derive(PartialEq, Serialize, Deserialize)]
+        struct TestF<'a, T, U>(T, Cow<'a, str>, Vec<U>) where U: Clone;
+        test_serdes!(TestF<u16, u8>,
+            TestF(5u16, Cow::Borrowed("asdf"), alloc::vec![1u8, 2]));
+
+        #[derive(PartialEq, Serialize, Deserialize)]
+        enum TestG<T> {
+            Nothing,
+            Something(T),
+        }
+        test_serdes!(TestG<u64>, TestG::Something(0x1337u64));
+        test_serdes!(TestG<u64>, TestG::<u64>::Nothing);
+    }
+
+    /// In-memory transport where everything written can be read back
+    #[derive(Default)]
+    struct Loopback {
+        data: VecDeque<u8>,
+    }
+
+    impl Writer for Loopback {
+        fn write(&mut self, buf: &[u8]) -> Option<()> {
+            self.data.extend(buf);
+            Some(())
+        }
+    }
+
+    impl Reader for Loopback {
+        fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+            // Hand out a few bytes at a time to exercise partial frames
+            let to_read = core::cmp::min(buf.len(), core::cmp::min(self.data.len(), 7));
+            if to_read == 0 { return None; }
+
+            for (dst, src) in buf.iter_mut().zip(self.data.drain(..to_read)) {
+                *dst = src;
+            }
+
+            Some(to_read)
+        }
+    }
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize, Describe)]
+    struct Hello {
+        core: u32,
+        build: String,
+    }
+
+    impl Message for Hello {
+        const TYPE: u32 = 1;
+    }
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize, Describe)]
+    enum Stats {
+        Cycles(u64),
+        Faults { count: u32, last: Option<u64> },
+    }
+
+    impl Message for Stats {
+        const TYPE: u32 = 2;
+    }
+
+    #[test]
+    fn test_crc32() {
+        let mut crc = Crc32::new(false);
+        crc.update(b"123456789");
+        assert!(crc.finish() == 0xcbf43926);
+    }
+
+    #[test]
+    fn test_channel() {
+        let mut buf = [0u8; 256];
+        let mut channel = Channel::new(Loopback::default(), &mut buf);
+
+        let hello = Hello { core: 3, build: String::from("paintbrush") };
+        channel.send(&hello).unwrap();
+        channel.send(&Stats::Cycles(0x1234)).unwrap();
+        channel.send(&Stats::Faults { count: 2, last: None }).unwrap();
+
+        // Dispatch on the type of each frame
+        let frame = channel.recv().unwrap();
+        assert!(frame.is::<Hello>() && !frame.is::<Stats>());
+        assert!(frame.decode::<Stats>().is_none());
+        assert!(frame.decode::<Hello>() == Some(hello));
+
+        assert!(channel.recv_msg::<Stats>() == Some(Stats::Cycles(0x1234)));
+        assert!(channel.recv_msg::<Stats>() == Some(Stats::Faults { count: 2, last: None }));
+
+        // Nothing left to receive
+        assert!(channel.recv().is_none());
+        assert!(channel.dropped() == 0);
+    }
+
+    #[test]
+    fn test_channel_corruption() {
+        let mut buf = [0u8; 256];
+        let mut channel = Channel::new(Loopback::default(), &mut buf);
+
+        // Garbage before the first frame, including a partial magic
+        channel.get_mut().write(b"\x00NDL\xffND").unwrap();
+        channel.send(&Stats::Cycles(1)).unwrap();
+
+        // Flip a bit in the payload of a frame
+        let start = channel.get_mut().data.len();
+        channel.send(&Stats::Cycles(2)).unwrap();
+        channel.get_mut().data[start + FRAME_HEADER_LEN + 5] ^= 0x10;
+
+        // Corrupt the length of a frame so it swallows the next one
+        let start = channel.get_mut().data.len();
+        channel.send(&Stats::Cycles(3)).unwrap();
+        channel.get_mut().data[start + 8] = 0x40;
+
+        channel.send(&Stats::Cycles(4)).unwrap();
+
+        // A frame too large for the receive buffer
+        channel.send(&Hello { core: 0, build: "x".repeat(300) }).unwrap();
+
+        // A frame cut short by a sender that went away before a complete frame
+        let start = channel.get_mut().data.len();
+        channel.send(&Stats::Cycles(5)).unwrap();
+        channel.get_mut().data.truncate(start + FRAME_HEADER_LEN - 1);
+
+        channel.send(&Stats::Cycles(6)).unwrap();
+
+        for expected in &[1, 4, 6] {
+            assert!(channel.recv_msg::<Stats>() == Some(Stats::Cycles(*expected)));
+        }
+        assert!(channel.recv().is_none());
+        assert!(channel.dropped() >= 3);
+    }
+
+    #[test]
+    fn test_channel_transports() {
+        // Compact transports carry compact payloads
+        let mut loopback = Loopback::default();
+        let mut buf = [0u8; 64];
+        let mut channel = Channel::new(Compact::new(&mut loopback), &mut buf);
+        channel.send(&Stats::Cycles(5)).unwrap();
+        assert!(Writer::compact(channel.get_mut()));
+        assert!(channel.recv_msg::<Stats>() == Some(Stats::Cycles(5)));
+        assert!(loopback.data.is_empty());
+
+        // Buffered transports need to be flushed
+        let mut buf = [0u8; 64];
+        let mut channel = Channel::new(BufferedIo::new(Loopback::default()), &mut buf);
+        channel.send(&Stats::Cycles(6)).unwrap();
+        channel.get_mut().flush().unwrap();
+        assert!(channel.recv_msg::<Stats>() == Some(Stats::Cycles(6)));
+    }
+
+    #[test]
+    fn test_collections() {
+        use alloc::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(0xffff_8000_0000_1000u64, String::from("kernel_main"));
+        map.insert(0xffff_8000_0000_0000u64, String::from("_start"));
+        test_serdes!(BTreeMap<u64, String>, map);
+
+        // The length, then each key and value in order
+        let mut map = BTreeMap::new();
+        map.insert(2u8, 'b');
+        map.insert(1u8, 'a');
+        let mut ser = Vec::new();
+        map.serialize(&mut ser).unwrap();
+        assert!(ser == [2, 0, 0, 0, 0, 0, 0, 0, 1, b'a', 0, 0, 0, 2, b'b', 0, 0, 0]);
+
+        let queue: VecDeque<(u32, Option<char>)> =
+            (0..10).map(|x| (x, core::char::from_digit(x, 10))).collect();
+        test_serdes!(VecDeque<(u32, Option<char>)>, queue);
+
+        // Entries count towards the element limit
+        let limits = DeserializeLimits::new(1024, 1, 4);
+        let mut ptr = &ser[..];
+        assert!(limits.deserialize::<BTreeMap<u8, char>, _>(&mut ptr)
+            == Err(DeserializeError::TooManyElements));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_collections() {
+        use std::collections::{HashMap, HashSet};
+
+        let map: HashMap<String, Vec<u32>> = (0..20u32)
+            .map(|x| (x.to_string(), alloc::vec![x; x as usize]))
+            .collect();
+        test_serdes!(HashMap<String, Vec<u32>>, map);
+
+        let set: HashSet<(i8, bool)> = (-10..10).map(|x| (x, x % 3 == 0)).collect();
+        test_serdes!(HashSet<(i8, bool)>, set);
+    }
+
+    #[test]
+    fn test_borrowed_alloc() {
+        #[derive(PartialEq, Debug, Serialize, DeserializeBorrowed)]
+        #[noodle(version = 1)]
+        struct Image<'a> {
+            name:     Cow<'a, str>,
+            sections: Vec<&'a [u8]>,
+            #[noodle(default)]
+            owned:    Option<Box<String>>,
+        }
+
+        let val = Image {
+            name:     Cow::Owned(String::from("paintbrush_x86.kernel")),
+            sections: alloc::vec![&[0xcc; 4][..], &[]],
+            owned:    Some(Box::new(String::from("owned"))),
+        };
+
+        let mut ser = Vec::new();
+        val.serialize(&mut ser).unwrap();
+
+        // `Cow`s borrow even if they were owned when serialized
+        let mut reader = SliceReader::new(&ser);
+        let deser = Image::deserialize_borrowed(&mut reader).unwrap();
+        assert!(deser == val);
+        assert!(matches!(deser.name, Cow::Borrowed(_)));
+        assert!(reader.remaining().is_empty());
+    }
+
+    #[test]
+    fn test_limits() {
+        let limits = DeserializeLimits::new(64, 16, 2);
+
+        // Values within the limits deserialize like normal
+        let mut ser = Vec::new();
+        alloc::vec![alloc::vec![1u8, 2], alloc::vec![3]].serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..];
+        let deser: Vec<Vec<u8>> = limits.deserialize(&mut ptr).unwrap();
+        assert!(deser == [[1, 2].as_ref(), [3].as_ref()]);
+
+        // A corrupt length is rejected before anything is allocated
+        let mut ptr = &[0xffu8; 8][..];
+        assert!(limits.deserialize::<Vec<u64>, _>(&mut ptr)
+            == Err(DeserializeError::TooManyElements));
+
+        // Elements are counted across every container
+        let mut ser = Vec::new();
+        alloc::vec![String::from("0123456789"), String::from("0123456789")]
+            .serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..];
+        assert!(limits.deserialize::<Vec<String>, _>(&mut ptr)
+            == Err(DeserializeError::TooManyElements));
+
+        // Reading more than the byte limit fails
+        let mut ser = Vec::new();
+        [0u64; 9].serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..];
+        assert!(limits.deserialize::<[u64; 9], _>(&mut ptr)
+            == Err(DeserializeError::TooManyBytes));
+
+        // Nesting deeper than the limit fails
+        let mut ser = Vec::new();
+        Box::new(alloc::vec![Box::new(1u8)]).serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..];
+        assert!(limits.deserialize::<Box<Vec<Box<u8>>>, _>(&mut ptr)
+            == Err(DeserializeError::TooDeep));
+
+        // Running out of input and invalid data are told apart
+        let mut ptr = &[1u8, 0][..];
+        assert!(limits.deserialize::<u32, _>(&mut ptr)
+            == Err(DeserializeError::UnexpectedEof));
+
+        let mut ptr = &[2u8, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff][..];
+        assert!(limits.deserialize::<String, _>(&mut ptr)
+            == Err(DeserializeError::Invalid));
+
+        // A field that falls back to its default doesn't hide the limit it hit
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        struct R {
+            a: u8,
+            #[noodle(default)]
+            big: Vec<u8>,
+            tail: u8,
+        }
+
+        let limits = DeserializeLimits::new(64, 4, 2);
+
+        let mut ser = Vec::new();
+        R { a: 1, big: alloc::vec![0; 8], tail: 2 }.serialize(&mut ser).unwrap();
+        let mut ptr = &ser[..];
+        assert!(limits.deserialize::<R, _>(&mut ptr)
+            == Err(DeserializeError::TooManyElements));
+
+        #[derive(PartialEq, Debug, Serialize, Deserialize)]
+        struct Trailing {
+            a: u8,
+            #[noodle(default)]
+            big: Vec<u8>,
+        }
+
+        let mut ptr = &[1u8][..];
+        assert!(limits.deserialize::<Trailing, _>(&mut ptr)
+            == Err(DeserializeError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_describe() {
+        use alloc::collections::BTreeMap;
+        use core::time::Duration;
+
+        #[derive(Serialize, Deserialize, Describe)]
+        enum State {
+            Idle,
+            Running { since: u64 },
+            Fault(u32, String),
+        }
+
+        #[derive(Serialize, Deserialize, Describe)]
+        struct Report {
+            core: u32,
+            #[noodle(rename = "current")]
+            state: State,
+            history: Vec<State>,
+            #[noodle(skip)]
+            _cache: u64,
+            #[noodle(varint)]
+            counters: BTreeMap<String, i64>,
+            last: Option<(char, bool)>,
+            empty: (),
+            uptime: Duration,
+            result: Result<Option<u8>, ()>,
+        }
+
+        let mut counters = BTreeMap::new();
+        counters.insert(String::from("irqs"), 12);
+        counters.insert(String::from("faults\n"), -1);
+
+        let report = Report {
+            core:    2,
+            state:   State::Running { since: 77 },
+            history: alloc::vec![State::Idle, State::Fault(14, String::from("#PF \"x\""))],
+            _cache:  5,
+            counters,
+            last:    Some(('q', true)),
+            empty:   (),
+            uptime:  Duration::new(3, 500),
+            result:  Ok(None),
+        };
+
+        let mut ser = Vec::new();
+        report.serialize(&mut ser).unwrap();
+
+        let text = describe::to_text::<Report, _>(&mut &ser[..]).unwrap();
+        assert_eq!(text, r##"Report {
+    core: 2,
+    current: State::Running {
+        since: 77,
+    },
+    history: [
+        State::Idle,
+        State::Fault(
+            14,
+            "#PF \"x\"",
+        ),
+    ],
+    counters: {
+        "faults\n": -1,
+        "irqs": 12,
+    },
+    last: Some((
+        'q',
+        true,
+    )),
+    empty: (),
+    uptime: Duration {
+        secs: 3,
+        nanos: 500,
+    },
+    result: Result::Ok(
+        None,
+    ),
+}"##);
+
+        let json = describe::to_json::<Report, _>(&mut &ser[..]).unwrap();
+        assert_eq!(json, concat!(r#"{"core":2,"current":{"Running":{"since":77}},"#,
+            r##""history":["Idle",{"Fault":[14,"#PF \"x\""]}],"##,
+            r#""counters":[["faults\n",-1],["irqs",12]],"last":["q",true],"empty":[],"#,
+            r#""uptime":{"secs":3,"nanos":500},"result":{"Ok":[null]}}"#));
+
+        // Describing stops at the first invalid value
+        let mut text = describe::Text::new(String::new());
+        let mut ptr = &[2, 0, 0, 0, 9, 0, 0, 0][..];
+        assert!(Report::describe(&mut ptr, &mut text).is_none());
+        assert!(text.into_inner() == "Report {\n    core: 2,\n    current: ");
+
+        // Frames describe the message they hold
+        let mut buf = [0u8; 256];
+        let mut channel = Channel::new(Loopback::default(), &mut buf);
+        channel.send(&Stats::Faults { count: 2, last: Some(9) }).unwrap();
+
+        let frame = channel.recv().unwrap();
+        let mut json = describe::Json::new(String::new());
+        assert!(frame.describe::<Hello, _>(&mut json).is_none());
+        frame.describe::<Stats, _>(&mut json).unwrap();
+        assert!(json.into_inner() == r#"{"Faults":{"count":2,"last":9}}"#);
+    }
+
+    #[test]
+    fn test_describe_versioned() {
+        #[derive(Serialize, Deserialize, Describe)]
+        #[noodle(version = 1)]
+        struct ConfigV1 {
+            #[noodle(varint)]
+            cores: u32,
+        }
+
+        #[derive(Serialize, Deserialize, Describe)]
+        #[noodle(version = 2)]
+        struct ConfigV2 {
+            #[noodle(varint)]
+            cores: u32,
+            #[noodle(default)]
+            name: String,
+            #[noodle(default)]
+            debug: bool,
+        }
+
+        // Fields from newer versions are shown as their bytes
+        let mut ser = Vec::new();
+        ConfigV2 { cores: 300, name: String::from("ab"), debug: true }
+            .serialize(&mut ser).unwrap();
+        let text = describe::to_text::<ConfigV1, _>(&mut &ser[..]).unwrap();
+        assert_eq!(text, "ConfigV1 {\n    cores: 300,\n    <unknown>: [\n        \
+            <02000000000000006162>,\n        <01>,\n    ],\n}");
+        let json = describe::to_json::<ConfigV1, _>(&mut &ser[..]).unwrap();
+        assert_eq!(json, r#"{"cores":300,"<unknown>":["02000000000000006162","01"]}"#);
+
+        // Fields missing from older versions aren't shown
+        let mut ser = Vec::new();
+        ConfigV1 { cores: 1 }.serialize(&mut ser).unwrap();
+        let json = describe::to_json::<ConfigV2, _>(&mut &ser[..]).unwrap();
+        assert_eq!(json, r#"{"cores":1}"#);
+    }
+}
@@ -0,0 +1,207 @@
+//! Limits on the resources deserialization may use, for input that can't be trusted
+
+use crate::{Reader, Deserialize, BorrowedReader, DeserializeBorrowed};
+
+/// Reason deserializing with [`DeserializeLimits`] failed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeserializeError {
+    /// The reader ran out of bytes
+    UnexpectedEof,
+
+    /// More than [`DeserializeLimits::max_bytes`] bytes would have been read
+    TooManyBytes,
+
+    /// Containers would have held more than [`DeserializeLimits::max_elements`] elements
+    TooManyElements,
+
+    /// Containers were nested deeper than [`DeserializeLimits::max_depth`]
+    TooDeep,
+
+    /// The bytes did not describe a valid value, such as an unknown enum variant or a
+    /// `String` that isn't UTF-8
+    Invalid,
+}
+
+/// Bounds on a single deserialization. Container lengths are read from the input, so
+/// without limits a corrupt length can request an arbitrarily large allocation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// Maximum number of bytes read from the reader
+    pub max_bytes: usize,
+
+    /// Maximum number of elements across every container, including the bytes of
+    /// strings
+    pub max_elements: usize,
+
+    /// Maximum number of containers nested in each other
+    pub max_depth: usize,
+}
+
+impl DeserializeLimits {
+    /// Limits which never fail
+    pub const UNLIMITED: DeserializeLimits = DeserializeLimits {
+        max_bytes:    usize::MAX,
+        max_elements: usize::MAX,
+        max_depth:    usize::MAX,
+    };
+
+    /// Create limits from the maximum number of bytes, elements and nesting depth
+    pub const fn new(max_bytes: usize, max_elements: usize, max_depth: usize) -> Self {
+        DeserializeLimits { max_bytes, max_elements, max_depth }
+    }
+
+    /// Deserialize a `T` from `reader` within these limits
+    ///
+    /// # Errors
+    ///
+    /// If a limit is exceeded, if `reader` runs out of bytes, or if the bytes are not a
+    /// valid `T`. This includes running out of bytes right before a `#[noodle(default)]`
+    /// field of a struct that isn't versioned.
+    pub fn deserialize<T: Deserialize, R: Reader>(&self, reader: &mut R)
+            -> Result<T, DeserializeError> {
+        let mut limited = LimitedReader::new(reader, *self);
+
+        let val = T::deserialize(&mut limited);
+
+        // A field falling back to its default may have hidden a failed read
+        match (val, limited.error()) {
+            (_, Some(error))  => Err(error),
+            (Some(val), None) => Ok(val),
+            (None, None)      => Err(DeserializeError::Invalid),
+        }
+    }
+
+    /// Deserialize a `T` borrowing from `reader` within these limits
+    ///
+    /// # Errors
+    ///
+    /// If a limit is exceeded, if `reader` runs out of bytes, or if the bytes are not a
+    /// valid `T`
+    pub fn deserialize_borrowed<'de, T, R>(&self, reader: &mut R)
+            -> Result<T, DeserializeError>
+            where T: DeserializeBorrowed<'de>, R: BorrowedReader<'de> {
+        let mut limited = LimitedReader::new(reader, *self);
+
+        let val = T::deserialize_borrowed(&mut limited);
+
+        // A field falling back to its default may have hidden a failed read
+        match (val, limited.error()) {
+            (_, Some(error))  => Err(error),
+            (Some(val), None) => Ok(val),
+            (None, None)      => Err(DeserializeError::Invalid),
+        }
+    }
+}
+
+impl Default for DeserializeLimits {
+    /// 16 MiB of input, a million elements and 64 levels of nesting
+    fn default() -> Self {
+        DeserializeLimits::new(16 * 1024 * 1024, 1024 * 1024, 64)
+    }
+}
+
+/// [`Reader`] enforcing [`DeserializeLimits`] on an inner reader
+pub struct LimitedReader<'a, R: Reader> {
+    /// Reader the bytes come from
+    inner: &'a mut R,
+
+    /// Limits to enforce
+    limits: DeserializeLimits,
+
+    /// Number of bytes read so far
+    bytes: usize,
+
+    /// Number of container elements so far
+    elements: usize,
+
+    /// Current nesting depth of containers
+    depth: usize,
+
+    /// First limit exceeded or the end of the input, if either happened
+    error: Option<DeserializeError>,
+}
+
+impl<'a, R: Reader> LimitedReader<'a, R> {
+    /// Create a new reader enforcing `limits` on `inner`
+    pub fn new(inner: &'a mut R, limits: DeserializeLimits) -> Self {
+        LimitedReader { inner, limits, bytes: 0, elements: 0, depth: 0, error: None }
+    }
+
+    /// Number of bytes read from the inner reader
+    pub fn bytes_read(&self) -> usize {
+        self.bytes
+    }
+
+    /// The first limit exceeded, or [`DeserializeError::UnexpectedEof`] if the inner
+    /// reader ran out of bytes
+    pub fn error(&self) -> Option<DeserializeError> {
+        self.error
+    }
+
+    /// Record `error` if it is the first failure and fail
+    fn fail<T>(&mut self, error: DeserializeError) -> Option<T> {
+        self.error.get_or_insert(error);
+        None
+    }
+}
+
+impl<'a, R: Reader> Reader for LimitedReader<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        // Only read as much as the byte limit still allows
+        let allowed = core::cmp::min(buf.len(), self.limits.max_bytes - self.bytes);
+        if allowed == 0 && !buf.is_empty() {
+            return self.fail(DeserializeError::TooManyBytes);
+        }
+
+        match self.inner.read(&mut buf[..allowed]) {
+            Some(bread) if bread > 0 || allowed == 0 => {
+                self.bytes += bread;
+                Some(bread)
+            }
+            _ => self.fail(DeserializeError::UnexpectedEof),
+        }
+    }
+
+    fn compact(&self) -> bool {
+        self.inner.compact()
+    }
+
+    fn elements(&mut self, count: usize) -> Option<()> {
+        match self.elements.checked_add(count) {
+            Some(elements) if elements <= self.limits.max_elements => {
+                self.elements = elements;
+                Some(())
+            }
+            _ => self.fail(DeserializeError::TooManyElements),
+        }
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        if self.depth >= self.limits.max_depth {
+            return self.fail(DeserializeError::TooDeep);
+        }
+
+        self.depth += 1;
+        Some(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl<'a, 'de, R: BorrowedReader<'de>> BorrowedReader<'de> for LimitedReader<'a, R> {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        if len > self.limits.max_bytes - self.bytes {
+            return self.fail(DeserializeError::TooManyBytes);
+        }
+
+        match self.inner.read_borrowed(len) {
+            Some(borrowed) => {
+                self.bytes += len;
+                Some(borrowed)
+            }
+            None => self.fail(DeserializeError::UnexpectedEof),
+        }
+    }
+}
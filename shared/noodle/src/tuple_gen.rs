@@ -1,2216 +0,0 @@
-/// AUTOGENERATED BY serialize_tuple_gen.py DO NOT MODIFY!
-/// Handles serialization of tuples by accessing each field as part of a
-/// different matching rule indicating the depth of the macro
-#[macro_export]
-macro_rules! handle_serialize_named_tuple {
-    ($self:ident, $buf:expr) => {
-    };
-    ($self:ident, $buf:expr, $ty0:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty, $ty59:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-        Serialize::serialize(&$self.59, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty, $ty59:ty, $ty60:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-        Serialize::serialize(&$self.59, $buf)?;
-        Serialize::serialize(&$self.60, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty, $ty59:ty, $ty60:ty, $ty61:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-        Serialize::serialize(&$self.59, $buf)?;
-        Serialize::serialize(&$self.60, $buf)?;
-        Serialize::serialize(&$self.61, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty, $ty59:ty, $ty60:ty, $ty61:ty, $ty62:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-        Serialize::serialize(&$self.59, $buf)?;
-        Serialize::serialize(&$self.60, $buf)?;
-        Serialize::serialize(&$self.61, $buf)?;
-        Serialize::serialize(&$self.62, $buf)?;
-    };
-    ($self:ident, $buf:expr, $ty0:ty, $ty1:ty, $ty2:ty, $ty3:ty, $ty4:ty, $ty5:ty, $ty6:ty, $ty7:ty, $ty8:ty, $ty9:ty, $ty10:ty, $ty11:ty, $ty12:ty, $ty13:ty, $ty14:ty, $ty15:ty, $ty16:ty, $ty17:ty, $ty18:ty, $ty19:ty, $ty20:ty, $ty21:ty, $ty22:ty, $ty23:ty, $ty24:ty, $ty25:ty, $ty26:ty, $ty27:ty, $ty28:ty, $ty29:ty, $ty30:ty, $ty31:ty, $ty32:ty, $ty33:ty, $ty34:ty, $ty35:ty, $ty36:ty, $ty37:ty, $ty38:ty, $ty39:ty, $ty40:ty, $ty41:ty, $ty42:ty, $ty43:ty, $ty44:ty, $ty45:ty, $ty46:ty, $ty47:ty, $ty48:ty, $ty49:ty, $ty50:ty, $ty51:ty, $ty52:ty, $ty53:ty, $ty54:ty, $ty55:ty, $ty56:ty, $ty57:ty, $ty58:ty, $ty59:ty, $ty60:ty, $ty61:ty, $ty62:ty, $ty63:ty) => {
-        Serialize::serialize(&$self.0, $buf)?;
-        Serialize::serialize(&$self.1, $buf)?;
-        Serialize::serialize(&$self.2, $buf)?;
-        Serialize::serialize(&$self.3, $buf)?;
-        Serialize::serialize(&$self.4, $buf)?;
-        Serialize::serialize(&$self.5, $buf)?;
-        Serialize::serialize(&$self.6, $buf)?;
-        Serialize::serialize(&$self.7, $buf)?;
-        Serialize::serialize(&$self.8, $buf)?;
-        Serialize::serialize(&$self.9, $buf)?;
-        Serialize::serialize(&$self.10, $buf)?;
-        Serialize::serialize(&$self.11, $buf)?;
-        Serialize::serialize(&$self.12, $buf)?;
-        Serialize::serialize(&$self.13, $buf)?;
-        Serialize::serialize(&$self.14, $buf)?;
-        Serialize::serialize(&$self.15, $buf)?;
-        Serialize::serialize(&$self.16, $buf)?;
-        Serialize::serialize(&$self.17, $buf)?;
-        Serialize::serialize(&$self.18, $buf)?;
-        Serialize::serialize(&$self.19, $buf)?;
-        Serialize::serialize(&$self.20, $buf)?;
-        Serialize::serialize(&$self.21, $buf)?;
-        Serialize::serialize(&$self.22, $buf)?;
-        Serialize::serialize(&$self.23, $buf)?;
-        Serialize::serialize(&$self.24, $buf)?;
-        Serialize::serialize(&$self.25, $buf)?;
-        Serialize::serialize(&$self.26, $buf)?;
-        Serialize::serialize(&$self.27, $buf)?;
-        Serialize::serialize(&$self.28, $buf)?;
-        Serialize::serialize(&$self.29, $buf)?;
-        Serialize::serialize(&$self.30, $buf)?;
-        Serialize::serialize(&$self.31, $buf)?;
-        Serialize::serialize(&$self.32, $buf)?;
-        Serialize::serialize(&$self.33, $buf)?;
-        Serialize::serialize(&$self.34, $buf)?;
-        Serialize::serialize(&$self.35, $buf)?;
-        Serialize::serialize(&$self.36, $buf)?;
-        Serialize::serialize(&$self.37, $buf)?;
-        Serialize::serialize(&$self.38, $buf)?;
-        Serialize::serialize(&$self.39, $buf)?;
-        Serialize::serialize(&$self.40, $buf)?;
-        Serialize::serialize(&$self.41, $buf)?;
-        Serialize::serialize(&$self.42, $buf)?;
-        Serialize::serialize(&$self.43, $buf)?;
-        Serialize::serialize(&$self.44, $buf)?;
-        Serialize::serialize(&$self.45, $buf)?;
-        Serialize::serialize(&$self.46, $buf)?;
-        Serialize::serialize(&$self.47, $buf)?;
-        Serialize::serialize(&$self.48, $buf)?;
-        Serialize::serialize(&$self.49, $buf)?;
-        Serialize::serialize(&$self.50, $buf)?;
-        Serialize::serialize(&$self.51, $buf)?;
-        Serialize::serialize(&$self.52, $buf)?;
-        Serialize::serialize(&$self.53, $buf)?;
-        Serialize::serialize(&$self.54, $buf)?;
-        Serialize::serialize(&$self.55, $buf)?;
-        Serialize::serialize(&$self.56, $buf)?;
-        Serialize::serialize(&$self.57, $buf)?;
-        Serialize::serialize(&$self.58, $buf)?;
-        Serialize::serialize(&$self.59, $buf)?;
-        Serialize::serialize(&$self.60, $buf)?;
-        Serialize::serialize(&$self.61, $buf)?;
-        Serialize::serialize(&$self.62, $buf)?;
-        Serialize::serialize(&$self.63, $buf)?;
-    };
-}
@@ -0,0 +1,118 @@
+//! LEB128 and zigzag encoding of integers for compact streams
+
+use crate::{Reader, Writer, BorrowedReader};
+
+/// Maximum number of bytes in the LEB128 encoding of a `u128`
+const MAX_LEB128_LEN: usize = 19;
+
+/// Wraps a [`Reader`] or [`Writer`] so that integers and lengths going through it use
+/// the compact encoding: LEB128 for unsigned values and zigzag LEB128 for signed
+/// values. `u8`, `i8` and `bool` are always a single byte.
+pub struct Compact<'a, T> {
+    /// Reader or writer doing the I/O
+    inner: &'a mut T,
+}
+
+impl<'a, T> Compact<'a, T> {
+    /// Use the compact encoding for everything going through `inner`
+    pub fn new(inner: &'a mut T) -> Self {
+        Compact { inner }
+    }
+}
+
+impl<'a, T: Writer> Writer for Compact<'a, T> {
+    fn write(&mut self, buf: &[u8]) -> Option<()> {
+        self.inner.write(buf)
+    }
+
+    fn compact(&self) -> bool {
+        true
+    }
+}
+
+impl<'a, T: Reader> Reader for Compact<'a, T> {
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        self.inner.read(buf)
+    }
+
+    fn compact(&self) -> bool {
+        true
+    }
+
+    fn elements(&mut self, count: usize) -> Option<()> {
+        self.inner.elements(count)
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        self.inner.enter()
+    }
+
+    fn leave(&mut self) {
+        self.inner.leave();
+    }
+}
+
+impl<'a, 'de, T: BorrowedReader<'de>> BorrowedReader<'de> for Compact<'a, T> {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        self.inner.read_borrowed(len)
+    }
+}
+
+/// Write `val` as LEB128
+pub(crate) fn write_unsigned<W: Writer>(writer: &mut W, mut val: u128) -> Option<()> {
+    let mut buf = [0u8; MAX_LEB128_LEN];
+    let mut len = 0;
+
+    loop {
+        // Low 7 bits, with the high bit set if more bytes follow
+        let byte = (val & 0x7f) as u8;
+        val >>= 7;
+
+        if val == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+
+    writer.write(&buf[..len])
+}
+
+/// Read a LEB128 value. Fails if the value doesn't fit in a `u128`.
+pub(crate) fn read_unsigned<R: Reader>(reader: &mut R) -> Option<u128> {
+    let mut val = 0u128;
+
+    for index in 0..MAX_LEB128_LEN {
+        let mut byte = [0u8; 1];
+        reader.read_exact(&mut byte)?;
+
+        let bits  = u128::from(byte[0] & 0x7f);
+        let shift = index * 7;
+
+        // The last byte only has room for the top 2 bits of a `u128`
+        if shift + 7 > 128 && bits >> (128 - shift) != 0 {
+            return None;
+        }
+
+        val |= bits << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return Some(val);
+        }
+    }
+
+    None
+}
+
+/// Map a signed value onto an unsigned one so that small magnitudes stay small
+pub(crate) fn zigzag(val: i128) -> u128 {
+    ((val << 1) ^ (val >> 127)) as u128
+}
+
+/// Inverse of [`zigzag`]
+pub(crate) fn unzigzag(val: u128) -> i128 {
+    ((val >> 1) as i128) ^ -((val & 1) as i128)
+}
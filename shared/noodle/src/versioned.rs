@@ -0,0 +1,282 @@
+//! Forward compatible encoding for structs deriving with `#[noodle(version = N)]`
+//!
+//! A versioned struct is written as:
+//!
+//! ```text
+//! version: u32
+//! fields:  u32
+//! field:   len: usize, value: [u8; len]
+//! ```
+//!
+//! Every field carries its own length, so a reader built for an older version skips the
+//! trailing fields it doesn't know about, and a reader built for a newer version fills
+//! the fields missing from the data with their `#[noodle(default)]`.
+//!
+//! Structs that aren't versioned can only tell a missing field apart from a corrupt one
+//! when the data ends right before the field, which [`ReadCounter`] is used to check.
+
+use crate::{Reader, Writer, Serialize, Deserialize, BorrowedReader, DeserializeBorrowed};
+use crate::describe::{Describe, Visitor, Compound};
+
+/// [`Writer`] which only counts the bytes written to it, used to find the length of a
+/// field before writing it
+pub struct ByteCounter {
+    /// Number of bytes written
+    len: usize,
+
+    /// Count the bytes of the compact encoding
+    compact: bool,
+}
+
+impl ByteCounter {
+    /// Create a counter for a stream using the compact encoding if `compact` is set
+    pub fn new(compact: bool) -> Self {
+        ByteCounter { len: 0, compact }
+    }
+
+    /// Number of bytes written
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Writer for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> Option<()> {
+        self.len = self.len.checked_add(buf.len())?;
+        Some(())
+    }
+
+    fn compact(&self) -> bool {
+        self.compact
+    }
+}
+
+/// [`Reader`] which reads at most a fixed number of bytes from an inner reader
+pub struct Take<'a, R: Reader> {
+    /// Reader the bytes come from
+    inner: &'a mut R,
+
+    /// Number of bytes which can still be read
+    remaining: usize,
+}
+
+impl<'a, R: Reader> Take<'a, R> {
+    /// Read at most `len` bytes from `inner`
+    pub fn new(inner: &'a mut R, len: usize) -> Self {
+        Take { inner, remaining: len }
+    }
+
+    /// Read and discard the bytes which weren't read yet
+    pub fn skip_rest(&mut self) -> Option<()> {
+        let mut scratch = [0u8; 64];
+
+        while self.remaining > 0 {
+            let to_skip = core::cmp::min(self.remaining, scratch.len());
+            self.inner.read_exact(&mut scratch[..to_skip])?;
+            self.remaining -= to_skip;
+        }
+
+        Some(())
+    }
+}
+
+impl<'a, R: Reader> Reader for Take<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        let to_read = core::cmp::min(buf.len(), self.remaining);
+        if to_read == 0 {
+            return None;
+        }
+
+        let bread = self.inner.read(&mut buf[..to_read])?;
+        self.remaining -= bread;
+        Some(bread)
+    }
+
+    fn compact(&self) -> bool {
+        self.inner.compact()
+    }
+
+    fn elements(&mut self, count: usize) -> Option<()> {
+        self.inner.elements(count)
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        self.inner.enter()
+    }
+
+    fn leave(&mut self) {
+        self.inner.leave();
+    }
+}
+
+impl<'a, 'de, R: BorrowedReader<'de>> BorrowedReader<'de> for Take<'a, R> {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        if len > self.remaining {
+            return None;
+        }
+
+        let borrowed = self.inner.read_borrowed(len)?;
+        self.remaining -= len;
+        Some(borrowed)
+    }
+}
+
+/// [`Reader`] which counts the bytes read through it, used to check that a field
+/// failed to deserialize without reading anything
+pub struct ReadCounter<'a, R: Reader> {
+    /// Reader the bytes come from
+    inner: &'a mut R,
+
+    /// Number of bytes read
+    len: usize,
+}
+
+impl<'a, R: Reader> ReadCounter<'a, R> {
+    /// Count the bytes read from `inner`
+    pub fn new(inner: &'a mut R) -> Self {
+        ReadCounter { inner, len: 0 }
+    }
+
+    /// Number of bytes read
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was read
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, R: Reader> Reader for ReadCounter<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Option<usize> {
+        let bread = self.inner.read(buf)?;
+        self.len += bread;
+        Some(bread)
+    }
+
+    fn compact(&self) -> bool {
+        self.inner.compact()
+    }
+
+    fn elements(&mut self, count: usize) -> Option<()> {
+        self.inner.elements(count)
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        self.inner.enter()
+    }
+
+    fn leave(&mut self) {
+        self.inner.leave();
+    }
+}
+
+impl<'a, 'de, R: BorrowedReader<'de>> BorrowedReader<'de> for ReadCounter<'a, R> {
+    fn read_borrowed(&mut self, len: usize) -> Option<&'de [u8]> {
+        let borrowed = self.inner.read_borrowed(len)?;
+        self.len += len;
+        Some(borrowed)
+    }
+}
+
+/// Write the version header of a struct with `fields` serialized fields
+pub fn serialize_header<W: Writer>(version: u32, fields: u32, writer: &mut W)
+        -> Option<()> {
+    version.serialize(writer)?;
+    fields.serialize(writer)
+}
+
+/// Read the version header of a struct, returning the version and the number of fields
+/// in the data
+pub fn deserialize_header<R: Reader>(reader: &mut R) -> Option<(u32, u32)> {
+    Some((u32::deserialize(reader)?, u32::deserialize(reader)?))
+}
+
+/// Write `val` prefixed by its length
+pub fn serialize_field<T, W>(val: &T, writer: &mut W) -> Option<()>
+        where T: Serialize + ?Sized, W: Writer {
+    let mut counter = ByteCounter::new(writer.compact());
+    val.serialize(&mut counter)?;
+
+    counter.len().serialize(writer)?;
+    val.serialize(writer)
+}
+
+/// Read a field written by [`serialize_field`]. Returns `None` if the length can't be
+/// read or the field can't be skipped, and `Some(None)` if the field didn't deserialize
+/// as a `T`. Bytes of the field not used by `T` are skipped.
+pub fn deserialize_field<T: Deserialize, R: Reader>(reader: &mut R) -> Option<Option<T>> {
+    let len = usize::deserialize(reader)?;
+
+    let mut field = Take::new(reader, len);
+    let val = T::deserialize(&mut field);
+    field.skip_rest()?;
+
+    Some(val)
+}
+
+/// Read a field written by [`serialize_field`], borrowing from the buffer of `reader`.
+/// See [`deserialize_field`].
+pub fn deserialize_field_borrowed<'de, T, R>(reader: &mut R) -> Option<Option<T>>
+        where T: DeserializeBorrowed<'de>, R: BorrowedReader<'de> {
+    let len = usize::deserialize(reader)?;
+
+    let mut field = Take::new(reader, len);
+    let val = T::deserialize_borrowed(&mut field);
+    field.skip_rest()?;
+
+    Some(val)
+}
+
+/// Skip a field written by [`serialize_field`] that this version doesn't know about
+pub fn skip_field<R: Reader>(reader: &mut R) -> Option<()> {
+    let len = usize::deserialize(reader)?;
+    Take::new(reader, len).skip_rest()
+}
+
+/// Describe a field written by [`serialize_field`] as a `T`. Bytes of the field not
+/// used by `T` are skipped.
+pub fn describe_field<T, R, V>(reader: &mut R, visitor: &mut V) -> Option<()>
+        where T: Describe + ?Sized, R: Reader, V: Visitor {
+    let len = usize::deserialize(reader)?;
+
+    let mut field = Take::new(reader, len);
+    T::describe(&mut field, visitor)?;
+    field.skip_rest()
+}
+
+/// Describe `count` fields written by [`serialize_field`] that this version doesn't
+/// know about, as a sequence of their bytes
+pub fn describe_unknown<R: Reader, V: Visitor>(reader: &mut R, visitor: &mut V,
+        count: u32) -> Option<()> {
+    let fields = Compound::Seq(count as usize);
+    visitor.begin(&fields)?;
+
+    for index in 0..count as usize {
+        let len = usize::deserialize(reader)?;
+
+        let unknown = Compound::Unknown(len);
+        visitor.element(index)?;
+        visitor.begin(&unknown)?;
+
+        // Hand the bytes to the visitor a chunk at a time
+        let mut scratch = [0u8; 64];
+        let mut remaining = len;
+        while remaining > 0 {
+            let chunk = core::cmp::min(remaining, scratch.len());
+            reader.read_exact(&mut scratch[..chunk])?;
+            visitor.bytes(&scratch[..chunk])?;
+            remaining -= chunk;
+        }
+
+        visitor.end(&unknown, len)?;
+    }
+
+    visitor.end(&fields, count as usize)
+}
@@ -0,0 +1,730 @@
+//! `#[derive(Serialize, Deserialize, DeserializeBorrowed, Describe)]` for `noodle`
+//!
+//! The generated code uses the same wire format as the `noodle!` macro. Fields are
+//! serialized in declaration order, and enum variants are prefixed with the index of
+//! the variant as a `u32`.
+//!
+//! Fields accept the following attributes:
+//!
+//! * `#[noodle(skip)]`: The field is not serialized. It is filled with
+//!   `Default::default()` during deserialization, or with the function given by
+//!   `default = "path"`.
+//! * `#[noodle(default)]` or `#[noodle(default = "path")]`: If the data ends right
+//!   before the field, it is filled with `Default::default()` or `path()` instead of
+//!   failing the whole type. Meant for trailing fields added after older data was
+//!   written. A field that fails after reading any of its bytes still fails the type.
+//! * `#[noodle(rename = "name")]`: The name the field is described by. The wire format
+//!   is positional, so this doesn't change the serialized bytes.
+//! * `#[noodle(varint)]`: Integers and lengths in the field use the compact varint
+//!   encoding of `noodle::Compact`, regardless of the stream.
+//!
+//! Structs accept `#[noodle(version = N)]` to use the forward compatible encoding of
+//! `noodle::versioned`. Fields are written with their length after a header with the
+//! version and the number of fields. Readers skip fields they don't know about, and
+//! fields missing from the data use their `default`, failing if they have none. New
+//! fields must only be added at the end of the struct.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, Span};
+use quote::{quote, format_ident};
+use syn::{parse_macro_input, parse_quote, DeriveInput, Data, Fields, Field, Generics};
+use syn::{Index, Meta, NestedMeta, Lit, Error, Path, Ident, GenericParam, LifetimeDef};
+use syn::ext::IdentExt;
+
+/// Trait implemented by a deserialize derive
+#[derive(Copy, Clone)]
+enum Target {
+    /// `noodle::Deserialize`
+    Owned,
+
+    /// `noodle::DeserializeBorrowed`, borrowing from the `'__de` buffer
+    Borrowed,
+}
+
+impl Target {
+    /// Function deserializing a value
+    fn deserialize(self) -> TokenStream2 {
+        match self {
+            Target::Owned    => quote! { ::noodle::Deserialize::deserialize },
+            Target::Borrowed => quote! { ::noodle::DeserializeBorrowed::deserialize_borrowed },
+        }
+    }
+
+    /// Function deserializing a field of a versioned struct
+    fn deserialize_field(self) -> TokenStream2 {
+        match self {
+            Target::Owned    => quote! { ::noodle::versioned::deserialize_field },
+            Target::Borrowed => quote! { ::noodle::versioned::deserialize_field_borrowed },
+        }
+    }
+}
+
+/// Value used for a field that was skipped or failed to deserialize
+enum DefaultValue {
+    /// Use `Default::default()`
+    Trait,
+
+    /// Call the function at the given path
+    Path(Path)
+}
+
+impl DefaultValue {
+    /// Expression creating the default value
+    fn expr(&self) -> TokenStream2 {
+        match self {
+            DefaultValue::Trait      => quote! { ::core::default::Default::default() },
+            DefaultValue::Path(path) => quote! { #path() },
+        }
+    }
+}
+
+/// Parsed `#[noodle(...)]` attributes of a field
+#[derive(Default)]
+struct FieldAttrs {
+    /// The field is not serialized
+    skip: bool,
+
+    /// Value to use if the field is skipped or fails to deserialize
+    default: Option<DefaultValue>,
+
+    /// The field uses the compact varint encoding
+    varint: bool,
+
+    /// Name the field is described by, instead of its identifier
+    rename: Option<String>,
+}
+
+impl FieldAttrs {
+    /// Parse the `#[noodle(...)]` attributes of `field`
+    fn parse(field: &Field) -> syn::Result<FieldAttrs> {
+        let mut attrs = FieldAttrs::default();
+
+        for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("noodle")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "expected #[noodle(...)]")),
+            };
+
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                        attrs.skip = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("varint") => {
+                        attrs.varint = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                        attrs.default = Some(DefaultValue::Trait);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                        let path = match &nv.lit {
+                            Lit::Str(lit) => lit.parse::<Path>()?,
+                            lit => return Err(Error::new_spanned(lit,
+                                "expected a function path string")),
+                        };
+                        attrs.default = Some(DefaultValue::Path(path));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                        attrs.rename = Some(match &nv.lit {
+                            Lit::Str(lit) => lit.value(),
+                            lit => return Err(Error::new_spanned(lit, "expected a string")),
+                        });
+                    }
+                    nested => {
+                        return Err(Error::new_spanned(nested,
+                            "unknown noodle attribute, expected skip, default, rename or varint"));
+                    }
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+
+    /// Expression used in place of the field if it fails to deserialize or is missing
+    /// from versioned data
+    fn fallback(&self) -> TokenStream2 {
+        match &self.default {
+            Some(default) => default.expr(),
+            None          => quote! { return None },
+        }
+    }
+
+    /// Expression giving the reader to deserialize this field from
+    fn reader(&self) -> TokenStream2 {
+        if self.varint {
+            quote! { &mut ::noodle::Compact::new(__reader) }
+        } else {
+            quote! { __reader }
+        }
+    }
+
+    /// Expression giving the writer to serialize this field into
+    fn writer(&self) -> TokenStream2 {
+        if self.varint {
+            quote! { &mut ::noodle::Compact::new(__writer) }
+        } else {
+            quote! { __writer }
+        }
+    }
+
+    /// Statement telling `__visitor` that the field at `index` is described next.
+    /// Unnamed fields stay positional even if they are renamed.
+    fn announce(&self, field: &Field, index: usize) -> TokenStream2 {
+        let name = match (&field.ident, &self.rename) {
+            (None, _)               => return quote! { __visitor.element(#index)?; },
+            (Some(_), Some(rename)) => rename.clone(),
+            (Some(ident), None)     => ident.unraw().to_string(),
+        };
+
+        quote! { __visitor.field(#index, #name)?; }
+    }
+
+    /// Expression deserializing this field from `__reader`
+    fn deserialize_expr(&self, target: Target) -> TokenStream2 {
+        let reader      = self.reader();
+        let deserialize = target.deserialize();
+
+        match (&self.default, self.skip) {
+            (Some(default), true) => default.expr(),
+            (None, true)          => DefaultValue::Trait.expr(),
+            (Some(default), false) => {
+                // Only data that ends before the field is missing it, anything else
+                // would leave the stream in the middle of the field
+                let default = default.expr();
+                quote! {{
+                    let mut __counter = ::noodle::versioned::ReadCounter::new(__reader);
+                    let __reader = &mut __counter;
+
+                    match #deserialize(#reader) {
+                        Some(val) => val,
+                        None if __reader.is_empty() => #default,
+                        None => return None,
+                    }
+                }}
+            }
+            (None, false) => quote! { #deserialize(#reader)? },
+        }
+    }
+}
+
+/// Parse the `#[noodle(...)]` attributes of the type itself, returning the version if
+/// it uses the versioned encoding
+fn parse_version(input: &DeriveInput) -> syn::Result<Option<u32>> {
+    let mut version = None;
+
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("noodle")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected #[noodle(...)]")),
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("version") => {
+                    version = Some(match &nv.lit {
+                        Lit::Int(lit) => lit.base10_parse::<u32>()?,
+                        lit => return Err(Error::new_spanned(lit, "expected a u32")),
+                    });
+                }
+                nested => {
+                    return Err(Error::new_spanned(nested,
+                        "unknown noodle attribute, expected version"));
+                }
+            }
+        }
+    }
+
+    if version.is_some() && !matches!(input.data, Data::Struct(_)) {
+        return Err(Error::new(Span::call_site(), "only structs can be versioned"));
+    }
+
+    Ok(version)
+}
+
+/// Add `bound` to every type parameter of `generics`
+fn add_bounds(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+
+    generics
+}
+
+/// Statements serializing every field of a struct accessed through `self`
+fn serialize_struct(fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut stmts = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let index = Index::from(i);
+                quote! { #index }
+            }
+        };
+
+        let writer = attrs.writer();
+        stmts.push(quote! { ::noodle::Serialize::serialize(&self.#member, #writer)?; });
+    }
+
+    Ok(quote! { #(#stmts)* })
+}
+
+/// Statements serializing every field of a versioned struct accessed through `self`
+fn serialize_versioned(version: u32, fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut stmts = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let index = Index::from(i);
+                quote! { #index }
+            }
+        };
+
+        let writer = attrs.writer();
+        stmts.push(quote! {
+            ::noodle::versioned::serialize_field(&self.#member, #writer)?;
+        });
+    }
+
+    let count = stmts.len() as u32;
+    Ok(quote! {
+        ::noodle::versioned::serialize_header(#version, #count, __writer)?;
+        #(#stmts)*
+    })
+}
+
+/// Expression deserializing a versioned struct `path` with `fields`
+fn deserialize_versioned(path: TokenStream2, fields: &Fields, target: Target)
+        -> syn::Result<TokenStream2> {
+    let deserialize_field = target.deserialize_field();
+    let mut stmts    = Vec::new();
+    let mut bindings = Vec::new();
+    let mut count    = 0u32;
+
+    for (i, field) in fields.iter().enumerate() {
+        let attrs   = FieldAttrs::parse(field)?;
+        let binding = format_ident!("__field{}", i);
+        let ty      = &field.ty;
+
+        let value = if attrs.skip {
+            attrs.default.as_ref().unwrap_or(&DefaultValue::Trait).expr()
+        } else {
+            // Fields past the end of the data were added after it was written
+            let reader   = attrs.reader();
+            let fallback = attrs.fallback();
+            let slot     = count;
+            count += 1;
+
+            quote! {
+                if #slot < __fields {
+                    match #deserialize_field(#reader)? {
+                        Some(val) => val,
+                        None      => #fallback,
+                    }
+                } else {
+                    #fallback
+                }
+            }
+        };
+
+        stmts.push(quote! { let #binding: #ty = #value; });
+        bindings.push(match &field.ident {
+            Some(ident) => quote! { #ident: #binding },
+            None        => quote! { #binding },
+        });
+    }
+
+    let value = match fields {
+        Fields::Named(_)   => quote! { #path { #(#bindings),* } },
+        Fields::Unnamed(_) => quote! { #path ( #(#bindings),* ) },
+        Fields::Unit       => path,
+    };
+
+    Ok(quote! {
+        let (_, __fields) = ::noodle::versioned::deserialize_header(__reader)?;
+        #(#stmts)*
+
+        // Skip the fields added after this version
+        for _ in #count..__fields {
+            ::noodle::versioned::skip_field(__reader)?;
+        }
+
+        Some(#value)
+    })
+}
+
+/// Expression constructing `path` by deserializing each of `fields` in order
+fn deserialize_fields(path: TokenStream2, fields: &Fields, target: Target)
+        -> syn::Result<TokenStream2> {
+    let mut exprs = Vec::new();
+
+    for field in fields.iter() {
+        let expr = FieldAttrs::parse(field)?.deserialize_expr(target);
+
+        exprs.push(match &field.ident {
+            Some(ident) => quote! { #ident: #expr },
+            None        => expr,
+        });
+    }
+
+    Ok(match fields {
+        Fields::Named(_)   => quote! { #path { #(#exprs),* } },
+        Fields::Unnamed(_) => quote! { #path ( #(#exprs),* ) },
+        Fields::Unit       => path,
+    })
+}
+
+/// Match arm serializing an enum variant with the given index
+fn serialize_variant(name: &Ident, variant: &syn::Variant, index: u32)
+        -> syn::Result<TokenStream2> {
+    let ident = &variant.ident;
+    let mut bindings = Vec::new();
+    let mut stmts    = Vec::new();
+
+    for (i, field) in variant.fields.iter().enumerate() {
+        let attrs   = FieldAttrs::parse(field)?;
+        let skip    = attrs.skip;
+        let binding = format_ident!("__field{}", i);
+
+        match &field.ident {
+            Some(field_ident) if skip => bindings.push(quote! { #field_ident: _ }),
+            Some(field_ident)         => bindings.push(quote! { #field_ident: #binding }),
+            None if skip              => bindings.push(quote! { _ }),
+            None                      => bindings.push(quote! { #binding }),
+        }
+
+        if !skip {
+            let writer = attrs.writer();
+            stmts.push(quote! { ::noodle::Serialize::serialize(#binding, #writer)?; });
+        }
+    }
+
+    let pattern = match &variant.fields {
+        Fields::Named(_)   => quote! { #name::#ident { #(#bindings),* } },
+        Fields::Unnamed(_) => quote! { #name::#ident ( #(#bindings),* ) },
+        Fields::Unit       => quote! { #name::#ident },
+    };
+
+    Ok(quote! {
+        #pattern => {
+            ::noodle::Serialize::serialize(&#index, __writer)?;
+            #(#stmts)*
+        }
+    })
+}
+
+/// Derive `noodle::Serialize`
+#[proc_macro_derive(Serialize, attributes(noodle))]
+pub fn derive_serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_serialize(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_serialize(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name     = &input.ident;
+    let version  = parse_version(input)?;
+    let generics = add_bounds(&input.generics, parse_quote!(::noodle::Serialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match (&input.data, version) {
+        (Data::Struct(data), Some(version)) => {
+            let stmts = serialize_versioned(version, &data.fields)?;
+            quote! { #stmts Some(()) }
+        }
+        (Data::Struct(data), None) => {
+            let stmts = serialize_struct(&data.fields)?;
+            quote! { #stmts Some(()) }
+        }
+        // Empty enums have no variants to match
+        (Data::Enum(data), _) if data.variants.is_empty() => quote! { match *self {} },
+        (Data::Enum(data), _) => {
+            let arms = data.variants.iter().enumerate()
+                .map(|(i, variant)| serialize_variant(name, variant, i as u32))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! { match self { #(#arms)* } Some(()) }
+        }
+        (Data::Union(_), _) => {
+            return Err(Error::new(Span::call_site(), "noodle can't serialize unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::noodle::Serialize for #name #ty_generics #where_clause {
+            fn serialize<__W: ::noodle::Writer>(&self, __writer: &mut __W)
+                    -> Option<()> {
+                #body
+            }
+        }
+    })
+}
+
+/// Derive `noodle::Deserialize`
+#[proc_macro_derive(Deserialize, attributes(noodle))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = deserialize_body(&input, Target::Owned).map(|body| {
+        let name     = &input.ident;
+        let generics = add_bounds(&input.generics, parse_quote!(::noodle::Deserialize));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics ::noodle::Deserialize for #name #ty_generics #where_clause {
+                fn deserialize<__R: ::noodle::Reader>(__reader: &mut __R) -> Option<Self> {
+                    #body
+                }
+            }
+        }
+    });
+
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derive `noodle::DeserializeBorrowed`. Every lifetime of the type is outlived by the
+/// buffer being deserialized from, so `&str`, `&[u8]` and `Cow` fields borrow from it.
+#[proc_macro_derive(DeserializeBorrowed, attributes(noodle))]
+pub fn derive_deserialize_borrowed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = deserialize_body(&input, Target::Borrowed).map(|body| {
+        let name = &input.ident;
+
+        // Add the `'__de` lifetime of the buffer, outliving every lifetime of the type
+        let mut generics = add_bounds(&input.generics,
+            parse_quote!(::noodle::DeserializeBorrowed<'__de>));
+        let mut de: LifetimeDef = parse_quote!('__de);
+        for param in input.generics.lifetimes() {
+            de.bounds.push(param.lifetime.clone());
+        }
+        generics.params.insert(0, GenericParam::Lifetime(de));
+
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics ::noodle::DeserializeBorrowed<'__de> for #name #ty_generics
+                    #where_clause {
+                fn deserialize_borrowed<__R: ::noodle::BorrowedReader<'__de>>(
+                        __reader: &mut __R) -> Option<Self> {
+                    #body
+                }
+            }
+        }
+    });
+
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Body of the function deserializing `input` for `target`
+fn deserialize_body(input: &DeriveInput, target: Target) -> syn::Result<TokenStream2> {
+    let name    = &input.ident;
+    let version = parse_version(input)?;
+
+    let body = match (&input.data, version) {
+        (Data::Struct(data), Some(_)) => {
+            deserialize_versioned(quote! { #name }, &data.fields, target)?
+        }
+        (Data::Struct(data), None) => {
+            let value = deserialize_fields(quote! { #name }, &data.fields, target)?;
+            quote! { Some(#value) }
+        }
+        (Data::Enum(data), _) => {
+            let mut arms = Vec::new();
+
+            for (i, variant) in data.variants.iter().enumerate() {
+                let index = i as u32;
+                let ident = &variant.ident;
+                let value = deserialize_fields(quote! { #name::#ident }, &variant.fields,
+                    target)?;
+
+                arms.push(quote! { #index => Some(#value), });
+            }
+
+            quote! {
+                // Get the index of the enum variant
+                let __variant = <u32 as ::noodle::Deserialize>::deserialize(__reader)?;
+
+                match __variant {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+        (Data::Union(_), _) => {
+            return Err(Error::new(Span::call_site(), "noodle can't deserialize unions"));
+        }
+    };
+
+    Ok(body)
+}
+
+/// Expression giving the `noodle::describe::Fields` of `fields`
+fn fields_kind(fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(_)   => quote! { ::noodle::describe::Fields::Named },
+        Fields::Unnamed(_) => quote! { ::noodle::describe::Fields::Unnamed },
+        Fields::Unit       => quote! { ::noodle::describe::Fields::Unit },
+    }
+}
+
+/// Statements describing `fields` in a `compound` expression to `__visitor`
+fn describe_fields(compound: TokenStream2, fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut stmts = Vec::new();
+
+    for field in fields.iter() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let announce = attrs.announce(field, stmts.len());
+        let reader   = attrs.reader();
+        let ty       = &field.ty;
+        stmts.push(quote! {
+            #announce
+            <#ty as ::noodle::Describe>::describe(#reader, __visitor)?;
+        });
+    }
+
+    let count = stmts.len();
+    Ok(quote! {
+        let __compound = #compound;
+        __visitor.begin(&__compound)?;
+        #(#stmts)*
+        __visitor.end(&__compound, #count)
+    })
+}
+
+/// Statements describing the fields of a versioned struct `name` to `__visitor`. Fields
+/// added after this version are described as `<unknown>` bytes.
+fn describe_versioned(name: &Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut stmts = Vec::new();
+
+    for field in fields.iter() {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let slot     = stmts.len();
+        let data     = slot as u32;
+        let announce = attrs.announce(field, slot);
+        let reader   = attrs.reader();
+        let ty       = &field.ty;
+        stmts.push(quote! {
+            if #data < __fields {
+                #announce
+                ::noodle::versioned::describe_field::<#ty, _, _>(#reader, __visitor)?;
+            }
+        });
+    }
+
+    let count = stmts.len() as u32;
+    let (kind, unknown) = match fields {
+        Fields::Unnamed(_) => (fields_kind(fields),
+            quote! { __visitor.element(__described)?; }),
+        _ => (quote! { ::noodle::describe::Fields::Named },
+            quote! { __visitor.field(__described, "<unknown>")?; }),
+    };
+    let name = name.unraw().to_string();
+
+    Ok(quote! {
+        let (_, __fields) = ::noodle::versioned::deserialize_header(__reader)?;
+
+        let __compound = ::noodle::describe::Compound::Struct(#name, #kind);
+        __visitor.begin(&__compound)?;
+        #(#stmts)*
+
+        // Fields added after this version
+        let mut __described = ::core::cmp::min(__fields, #count) as usize;
+        if __fields > #count {
+            #unknown
+            ::noodle::versioned::describe_unknown(__reader, __visitor, __fields - #count)?;
+            __described += 1;
+        }
+
+        __visitor.end(&__compound, __described)
+    })
+}
+
+/// Derive `noodle::Describe`, describing fields by their names or `rename`
+#[proc_macro_derive(Describe, attributes(noodle))]
+pub fn derive_describe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_describe(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_describe(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name     = &input.ident;
+    let version  = parse_version(input)?;
+    let generics = add_bounds(&input.generics, parse_quote!(::noodle::Describe));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name = name.unraw().to_string();
+
+    let body = match (&input.data, version) {
+        (Data::Struct(data), Some(_)) => describe_versioned(name, &data.fields)?,
+        (Data::Struct(data), None) => {
+            let kind = fields_kind(&data.fields);
+            describe_fields(quote! {
+                ::noodle::describe::Compound::Struct(#type_name, #kind)
+            }, &data.fields)?
+        }
+        (Data::Enum(data), _) => {
+            let mut arms = Vec::new();
+
+            for (i, variant) in data.variants.iter().enumerate() {
+                let index   = i as u32;
+                let kind    = fields_kind(&variant.fields);
+                let variant_name = variant.ident.unraw().to_string();
+                let stmts = describe_fields(quote! {
+                    ::noodle::describe::Compound::Variant(#type_name, #variant_name, #kind)
+                }, &variant.fields)?;
+
+                arms.push(quote! { #index => { #stmts } });
+            }
+
+            quote! {
+                // Get the index of the enum variant
+                let __variant = <u32 as ::noodle::Deserialize>::deserialize(__reader)?;
+
+                match __variant {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+        (Data::Union(_), _) => {
+            return Err(Error::new(Span::call_site(), "noodle can't describe unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::noodle::Describe for #name #ty_generics #where_clause {
+            fn describe<__R: ::noodle::Reader, __V: ::noodle::Visitor>(__reader: &mut __R,
+                    __visitor: &mut __V) -> Option<()> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,304 @@
+//! Debug directory parsing used to tie a running image back to the build it came from
+//!
+//! Reference: [`The .debug Section`](https://docs.microsoft.com/en-us/windows/win32/debug/pe-format#the-debug-section)
+
+use core::convert::TryInto;
+
+use crate::{Parsed, DirectoryEntry};
+
+/// `IMAGE_DEBUG_TYPE_CODEVIEW` debug directory type
+const DEBUG_TYPE_CODEVIEW: u32 = 2;
+
+/// Signature of a CodeView PDB 7.0 record
+const RSDS_SIGNATURE: &[u8; 4] = b"RSDS";
+
+/// A debug directory entry from
+/// [`IMAGE_DEBUG_DIRECTORY`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_debug_directory)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct DebugDirectory {
+    /// Reserved, must be zero
+    pub characteristics: u32,
+
+    /// The time and date the debug data was created
+    pub timestamp: u32,
+
+    /// The major version number of the debug data format
+    pub major_version: u16,
+
+    /// The minor version number of the debug data format
+    pub minor_version: u16,
+
+    /// The format of the debugging information
+    pub debug_type: u32,
+
+    /// The size of the debug data, not including the debug directory itself
+    pub size_of_data: u32,
+
+    /// The relative virtual address of the debug data when loaded
+    pub address_of_raw_data: u32,
+
+    /// The file offset of the debug data
+    pub pointer_to_raw_data: u32,
+}
+
+impl DebugDirectory {
+    /// Parse the `DebugDirectory` found at the start of `bytes`
+    fn from_bytes(bytes: &[u8]) -> Option<DebugDirectory> {
+        let u32_at = |offset: usize| -> Option<u32> {
+            Some(u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?))
+        };
+
+        let u16_at = |offset: usize| -> Option<u16> {
+            Some(u16::from_le_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?))
+        };
+
+        Some(DebugDirectory {
+            characteristics:     u32_at(0)?,
+            timestamp:           u32_at(4)?,
+            major_version:       u16_at(8)?,
+            minor_version:       u16_at(10)?,
+            debug_type:          u32_at(12)?,
+            size_of_data:        u32_at(16)?,
+            address_of_raw_data: u32_at(20)?,
+            pointer_to_raw_data: u32_at(24)?,
+        })
+    }
+}
+
+/// A GUID formatted as `XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Guid(pub [u8; 16]);
+
+impl core::fmt::Display for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let bytes = &self.0;
+
+        // The first three fields of the GUID are stored little endian
+        write!(f, "{:08X}-{:04X}-{:04X}-",
+            u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            u16::from_le_bytes(bytes[4..6].try_into().unwrap()),
+            u16::from_le_bytes(bytes[6..8].try_into().unwrap()))?;
+
+        for byte in &bytes[8..10] {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        write!(f, "-")?;
+
+        for byte in &bytes[10..] {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        core::fmt::Result::Ok(())
+    }
+}
+
+/// A CodeView PDB 7.0 (`RSDS`) record identifying the build of an image
+#[derive(Debug, Copy, Clone)]
+pub struct CodeView<'a> {
+    /// Unique identifier of the PDB written by the linker
+    pub guid: Guid,
+
+    /// Incremented each time the PDB is written for the same GUID
+    pub age: u32,
+
+    /// Path of the PDB at link time, not including the null terminator
+    pub pdb_path: &'a [u8],
+}
+
+impl<'a> CodeView<'a> {
+    /// Parse the `RSDS` record found at the start of `bytes`
+    fn from_bytes(bytes: &'a [u8]) -> Option<CodeView<'a>> {
+        if bytes.get(..4)? != RSDS_SIGNATURE {
+            return None;
+        }
+
+        let guid = Guid(bytes.get(4..20)?.try_into().ok()?);
+        let age  = u32::from_le_bytes(bytes.get(20..24)?.try_into().ok()?);
+
+        // The path is null terminated, but may fill the rest of the record without one
+        let path = &bytes[24..];
+        let path_len = path.iter().position(|&byte| byte == 0).unwrap_or(path.len());
+
+        Some(CodeView { guid, age, pdb_path: &path[..path_len] })
+    }
+
+    /// The path of the PDB as a `str`, if it is valid UTF-8
+    pub fn pdb_path_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.pdb_path).ok()
+    }
+}
+
+impl<'a> Parsed<'a> {
+    /// Iterate over the `IMAGE_DEBUG_DIRECTORY` entries of the debug directory
+    pub fn debug_directories(&self) -> impl Iterator<Item = DebugDirectory> + 'a {
+        self.directory(DirectoryEntry::Debug)
+            .unwrap_or(&[])
+            .chunks_exact(28)
+            .filter_map(DebugDirectory::from_bytes)
+    }
+
+    /// Get the CodeView `RSDS` record of the image, if the linker emitted one
+    pub fn codeview(&self) -> Option<CodeView<'a>> {
+        let entry = self.debug_directories()
+            .find(|entry| entry.debug_type == DEBUG_TYPE_CODEVIEW)?;
+
+        // Prefer the mapped data, but fall back to the file offset for debug data that
+        // isn't part of any section
+        let record = self.rva_to_slice(entry.address_of_raw_data, entry.size_of_data)
+            .or_else(|| {
+                let start = entry.pointer_to_raw_data as usize;
+                self.data.get(start..start.checked_add(entry.size_of_data as usize)?)
+            })?;
+
+        CodeView::from_bytes(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataDirectory, Machine, Magic, SectionPermissions};
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// Relative virtual address of the only section of the test image
+    const SECTION: u32 = 0x1000;
+
+    /// Relative virtual address of the `RSDS` record in the test image
+    const RECORD: u32 = 0x1040;
+
+    /// Build an `RSDS` record for `path`
+    fn record(path: &[u8]) -> Vec<u8> {
+        let mut record = Vec::new();
+        record.extend_from_slice(RSDS_SIGNATURE);
+        record.extend((0..16).map(|byte| byte as u8));
+        record.extend_from_slice(&3u32.to_le_bytes());
+        record.extend_from_slice(path);
+        record
+    }
+
+    /// Build a debug directory entry of `debug_type` pointing at `size` bytes at `rva`
+    /// and at the file offset `offset`
+    fn entry(debug_type: u32, rva: u32, offset: u32, size: u32) -> Vec<u8> {
+        [0, 0x1234_5678, 0x0002_0001, debug_type, size, rva, offset].iter()
+            .flat_map(|field: &u32| field.to_le_bytes())
+            .collect()
+    }
+
+    /// Wrap `section` and the file `data` in a [`Parsed`] image with a debug directory
+    /// of `entries` entries at the start of the section
+    fn parsed<'a>(section: &'a [u8], data: &'a [u8], entries: u32) -> Parsed<'a> {
+        let permissions = SectionPermissions {
+            executable: false,
+            readable:   true,
+            writable:   false
+        };
+
+        let mut sections = [None; 6];
+        sections[0] = Some((section, SECTION, permissions));
+
+        let mut data_directories = [DataDirectory::default(); 16];
+        data_directories[DirectoryEntry::Debug as usize] =
+            DataDirectory { rva: SECTION, size: entries * 28 };
+
+        Parsed {
+            sections,
+            image_base:  0x1_4000_0000,
+            entry_point: 0x1_4000_1000,
+            machine:     Machine::Amd64,
+            magic:       Magic::Hdr64,
+            data_directories,
+            timestamp:   0,
+            data
+        }
+    }
+
+    #[test]
+    fn test_debug_directory() {
+        let bytes = entry(DEBUG_TYPE_CODEVIEW, RECORD, 0x440, 0x20);
+
+        let directory = DebugDirectory::from_bytes(&bytes).unwrap();
+        assert_eq!(directory.timestamp, 0x1234_5678);
+        assert_eq!((directory.major_version, directory.minor_version), (1, 2));
+        assert_eq!(directory.debug_type, DEBUG_TYPE_CODEVIEW);
+        assert_eq!(directory.size_of_data, 0x20);
+        assert_eq!(directory.address_of_raw_data, RECORD);
+        assert_eq!(directory.pointer_to_raw_data, 0x440);
+
+        assert!(DebugDirectory::from_bytes(&bytes[..27]).is_none());
+    }
+
+    #[test]
+    fn test_codeview() {
+        let record = record(b"kernel.pdb\0junk");
+
+        // A second entry of another type comes first
+        let mut section = [0u8; 0x100];
+        section[..28].copy_from_slice(&entry(1, 0, 0, 0));
+        section[28..56].copy_from_slice(&entry(DEBUG_TYPE_CODEVIEW, RECORD, 0,
+            record.len() as u32));
+        section[0x40..0x40 + record.len()].copy_from_slice(&record);
+
+        let parsed = parsed(&section, &[], 2);
+        assert_eq!(parsed.debug_directories().count(), 2);
+
+        let codeview = parsed.codeview().unwrap();
+        assert_eq!(codeview.age, 3);
+        assert_eq!(codeview.pdb_path, b"kernel.pdb");
+        assert_eq!(codeview.pdb_path_str(), Some("kernel.pdb"));
+        assert_eq!(std::format!("{}", codeview.guid),
+            "03020100-0504-0706-0809-0A0B0C0D0E0F");
+    }
+
+    #[test]
+    fn test_codeview_file_offset() {
+        let record = record(b"kernel.pdb\0");
+
+        // The record isn't mapped by any section, so it is found by its file offset
+        let mut section = [0u8; 0x40];
+        section[..28].copy_from_slice(&entry(DEBUG_TYPE_CODEVIEW, 0x9000, 0x10,
+            record.len() as u32));
+
+        let mut data = [0u8; 0x80];
+        data[0x10..0x10 + record.len()].copy_from_slice(&record);
+
+        let codeview = parsed(&section, &data, 1).codeview().unwrap();
+        assert_eq!(codeview.pdb_path, b"kernel.pdb");
+
+        // Neither the mapping nor the file holds the whole record
+        section[..28].copy_from_slice(&entry(DEBUG_TYPE_CODEVIEW, 0x9000, 0x70,
+            record.len() as u32));
+        assert!(parsed(&section, &data, 1).codeview().is_none());
+    }
+
+    #[test]
+    fn test_codeview_invalid() {
+        let record = record(b"kernel.pdb\0");
+
+        // Truncated before the end of the age
+        assert!(CodeView::from_bytes(&record[..23]).is_none());
+        assert!(CodeView::from_bytes(&[]).is_none());
+
+        // Wrong signature
+        let mut bad = record.clone();
+        bad[..4].copy_from_slice(b"NB10");
+        assert!(CodeView::from_bytes(&bad).is_none());
+
+        // Without a null terminator the path fills the rest of the record
+        let codeview = CodeView::from_bytes(&record[..28]).unwrap();
+        assert_eq!(codeview.pdb_path, b"kern");
+
+        // An empty path
+        let codeview = CodeView::from_bytes(&record[..24]).unwrap();
+        assert!(codeview.pdb_path.is_empty());
+
+        // Not valid UTF-8
+        let invalid  = [&record[..24], &[0xff, 0xfe, 0]].concat();
+        let codeview = CodeView::from_bytes(&invalid).unwrap();
+        assert_eq!(codeview.pdb_path, &[0xff, 0xfe]);
+        assert_eq!(codeview.pdb_path_str(), None);
+    }
+}
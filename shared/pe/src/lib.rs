@@ -3,10 +3,15 @@
 //! Reference: [
 
 #![no_std]
+// The `ErrorChain` is stored inline since there is no allocator to box it with
+#![allow(clippy::result_large_err)]
 
 use core::convert::TryInto;
 use errchain::*;
 
+pub mod unwind;
+pub mod debug;
+
 #[derive(Copy, Clone, Debug)]
 pub enum Error {
     /// MZ header missing from the beginning of file
@@ -18,20 +23,69 @@ pub enum Error {
     /// Parsed PE has too many sections for this implementation to parse. Increase the
     /// `NUM_SECTIONS` value to parse everything properly.
     TooManySections,
+
+    /// The data directories extend past the end of the optional header
+    InvalidDataDirectories,
+
+    /// An `UNWIND_INFO` is out of bounds of the image or is malformed
+    InvalidUnwindInfo,
+
+    /// An `UNWIND_INFO` has a version other than 1 or 2
+    UnsupportedUnwindVersion,
+
+    /// The `Machine` of the COFF header is not a [`Machine`] known by this parser
+    UnknownMachine,
+
+    /// The optional header magic is neither PE32 nor PE32+
+    UnsupportedMagic,
+
+    /// The optional header is too small to hold the fields of its [`Magic`]
+    InvalidOptionalHeader,
+
+    /// The image was built for a different [`Machine`] than requested in [`parse_for`]
+    MachineMismatch,
+
+    /// The optional header [`Magic`] does not match the requested [`Machine`]
+    MagicMismatch,
+
+    /// A section header or the data of a section is out of bounds of the image
+    InvalidSection,
 }
 
 /// The architecture type of the computer. An image file can only be run on the specified
 /// computer or a system that emulates the specified computer.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u16)]
 pub enum Machine {
     I386  = 0x014c,
     Ia4   = 0x0200,
-    Amd64 = 0x8664
+    Amd64 = 0x8664,
+    Arm64 = 0xaa64
+}
+
+impl Machine {
+    /// Get the [`Machine`] for the raw `Machine` field of the COFF header
+    fn from_raw(machine: u16) -> Option<Machine> {
+        match machine {
+            0x014c => Some(Machine::I386),
+            0x0200 => Some(Machine::Ia4),
+            0x8664 => Some(Machine::Amd64),
+            0xaa64 => Some(Machine::Arm64),
+            _      => None
+        }
+    }
+
+    /// The optional header [`Magic`] of images built for this [`Machine`]
+    pub fn magic(self) -> Magic {
+        match self {
+            Machine::I386 => Magic::Hdr32,
+            Machine::Ia4 | Machine::Amd64 | Machine::Arm64 => Magic::Hdr64
+        }
+    }
 }
 
 /// The state of the image file
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u16)]
 pub enum Magic {
     /// The file is an 32-bit executable image. 
@@ -44,6 +98,39 @@ pub enum Magic {
     RomHdr = 0x107
 }
 
+/// Index of each entry in the data directories of the optional header
+#[derive(Debug, Copy, Clone)]
+#[repr(usize)]
+pub enum DirectoryEntry {
+    Export        = 0,
+    Import        = 1,
+    Resource      = 2,
+    Exception     = 3,
+    Security      = 4,
+    BaseReloc     = 5,
+    Debug         = 6,
+    Architecture  = 7,
+    GlobalPtr     = 8,
+    Tls           = 9,
+    LoadConfig    = 10,
+    BoundImport   = 11,
+    Iat           = 12,
+    DelayImport   = 13,
+    ComDescriptor = 14,
+}
+
+/// A data directory from
+/// [`IMAGE_DATA_DIRECTORY`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_data_directory)
+#[derive(Debug, Copy, Clone, Default)]
+#[repr(C)]
+pub struct DataDirectory {
+    /// The relative virtual address of the table
+    pub rva: u32,
+
+    /// The size of the table, in bytes
+    pub size: u32
+}
+
 /// Section permissions parsed from the [`Characteristics`]
 #[derive(Debug, Copy, Clone)]
 pub struct SectionPermissions {
@@ -59,6 +146,7 @@ pub struct SectionPermissions {
 
 /// Minimal
 /// [`characteristics`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_section_header) for the image
+#[repr(u32)]
 pub enum Characteristics {
     /// This section contains executable code
     Code = 0x00000020,
@@ -71,6 +159,7 @@ pub enum Characteristics {
 }
 
 /// PE Header from [`IMAGE_NT_HEADERS64`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-image_nt_headers64)
+/// up to the fields shared by the PE32 and PE32+ optional headers
 #[derive(Debug)]
 #[repr(C)]
 struct PeHeader {
@@ -79,7 +168,7 @@ struct PeHeader {
 
     /// The architecture type of the computer. An image file can only be run on the
     /// specified computer or a system that emulates the specified computer.
-    machine: Machine,
+    machine: u16,
     
     /// The number of sections. This indicates the size of the section table, which
     /// immediately follows the headers.
@@ -126,12 +215,6 @@ struct PeHeader {
 
     /// A pointer to the beginning of the code section, relative to the image base.
     code_base_rva: u32,
-
-    /// The preferred address of the first byte of the image when it is loaded in memory.
-    /// This value is a multiple of 64K bytes. The default value for DLLs is
-    /// `0x10000000`.  The default value for applications is `0x00400000`, except on
-    /// Windows CE where it is `0x00010000`.
-    image_base: u64,
 }
 
 /// A section header from
@@ -222,71 +305,413 @@ pub struct Parsed<'a> {
     /// Parsed sections with their permissions
     pub sections: [Option<(&'a [u8], u32, SectionPermissions)>; 6],
 
-    /// Requested image base for the PE file
+    /// Requested image base for the PE file. This value is a multiple of 64K bytes.
     pub image_base: u64,
 
     /// Entry point of the PE file
-    pub entry_point: u64
+    pub entry_point: u64,
+
+    /// The architecture the PE file was built for
+    pub machine: Machine,
+
+    /// Whether the PE file has a PE32 or PE32+ optional header
+    pub magic: Magic,
+
+    /// Data directories found in the optional header
+    pub data_directories: [DataDirectory; NUM_DATA_DIRECTORIES],
+
+    /// The low 32 bits of the time stamp of the image from the COFF header
+    pub timestamp: u32,
+
+    /// The raw PE file
+    data: &'a [u8]
+}
+
+impl<'a> Parsed<'a> {
+    /// Get the `size` bytes at the given relative virtual address from the section data
+    /// of the image. Returns `None` if the range isn't backed by a single section.
+    pub fn rva_to_slice(&self, rva: u32, size: u32) -> Option<&'a [u8]> {
+        self.sections.iter().flatten().find_map(|(data, virt_addr, _)| {
+            let offset = rva.checked_sub(*virt_addr)? as usize;
+            data.get(offset..offset.checked_add(size as usize)?)
+        })
+    }
+
+    /// Get the data of the given [`DirectoryEntry`] if it is present in the image
+    pub fn directory(&self, entry: DirectoryEntry) -> Option<&'a [u8]> {
+        let directory = self.data_directories[entry as usize];
+
+        if directory.rva == 0 || directory.size == 0 {
+            return None;
+        }
+
+        self.rva_to_slice(directory.rva, directory.size)
+    }
 }
 
 /// Number of sections that can be parsed and returned
 const NUM_SECTIONS: u16 = 6;
 
-pub fn parse<'a>(data: &'a [u8]) -> Result<Parsed> {
+/// Number of data directories in the optional header
+const NUM_DATA_DIRECTORIES: usize = 16;
+
+/// Offset of the data directories from the start of a PE32 optional header
+const DATA_DIRECTORIES_OFFSET_32: usize = 0x60;
+
+/// Offset of the data directories from the start of a PE32+ optional header
+const DATA_DIRECTORIES_OFFSET_64: usize = 0x70;
+
+/// Read the little endian `u32` at `offset` in `bytes`
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+/// Read the little endian `u64` at `offset` in `bytes`
+fn read_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(bytes.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+/// Parse the given PE, ensuring that it was built for the `expected` [`Machine`] and
+/// that its optional header matches that [`Machine`]
+///
+/// # Errors
+///
+/// If the PE fails to parse or was built for a different architecture
+pub fn parse_for<'a>(data: &'a [u8], expected: Machine) -> Result<Parsed<'a>> {
+    let parsed = parse(data)?;
+
+    ensure!(parsed.machine == expected,        &Error::MachineMismatch);
+    ensure!(parsed.magic == expected.magic(), &Error::MagicMismatch);
+
+    Ok(parsed)
+}
+
+/// Parse the given PE. Any bytes are accepted, so this can also be used to check
+/// whether a file is a PE at all.
+///
+/// # Errors
+///
+/// If a header is missing, out of bounds of `data` or malformed
+pub fn parse<'a>(data: &'a [u8]) -> Result<Parsed<'a>> {
     // Ensure the data begins with MZ
-    ensure!(&data[..2] == b"MZ", &Error::InvalidMZHeader);
+    ensure!(data.get(..2) == Some(b"MZ"), &Error::InvalidMZHeader);
 
     // Get the offset to the PE section from the MZ header
-    let pe_offset = u32::from_le_bytes(data[0x3c..0x40].try_into().unwrap()) as usize;
+    let pe_offset = match read_u32(data, 0x3c) {
+        Some(pe_offset) => pe_offset as usize,
+        None => return err!(&Error::InvalidMZHeader)
+    };
 
     // Get the PE header
-    let pe_header = &data[pe_offset..];
+    let pe_header = match data.get(pe_offset..) {
+        Some(pe_header) => pe_header,
+        None => return err!(&Error::InvalidPEHEader)
+    };
 
     // Ensure the PE header was found
-    ensure!(&pe_header[..2] == b"PE", &Error::InvalidPEHEader);
-
-    let header = unsafe {
-        &*(pe_header[..core::mem::size_of::<PeHeader>()].as_ptr() as *const PeHeader)
+    ensure!(pe_header.get(..2) == Some(b"PE"), &Error::InvalidPEHEader);
+
+    // The header is at any offset the MZ header points to, so it may be unaligned
+    let header = match pe_header.get(..core::mem::size_of::<PeHeader>()) {
+        Some(header) => unsafe {
+            core::ptr::read_unaligned(header.as_ptr() as *const PeHeader)
+        },
+        None => return err!(&Error::InvalidPEHEader)
     };
 
     ensure!(header.number_of_sections <= NUM_SECTIONS, &Error::TooManySections);
 
-    let section_start_offset = (header.opt_header_size + 0x18) as usize;
+    let machine = match Machine::from_raw(header.machine) {
+        Some(machine) => machine,
+        None => return err!(&Error::UnknownMachine)
+    };
+
+    let section_start_offset = usize::from(header.opt_header_size) + 0x18;
+
+    // Get the optional header which immediately follows the COFF header
+    let opt_header = pe_header.get(0x18..section_start_offset).unwrap_or(&[]);
+
+    // PE32 and PE32+ optional headers differ in the width of the image base, which
+    // also shifts the location of the data directories
+    let (magic, image_base, directories_offset) = match header.magic {
+        0x10b => (Magic::Hdr32, read_u32(opt_header, 28).map(u64::from),
+                  DATA_DIRECTORIES_OFFSET_32),
+        0x20b => (Magic::Hdr64, read_u64(opt_header, 24), DATA_DIRECTORIES_OFFSET_64),
+        _     => return err!(&Error::UnsupportedMagic)
+    };
+
+    let image_base = match image_base {
+        Some(image_base) => image_base,
+        None => return err!(&Error::InvalidOptionalHeader)
+    };
 
     // Init the returned parsed sections
     let mut sections = [None; 6];
 
-    for section_num in 0..header.number_of_sections {
-        // Get the beginning of this section header
-        let section_ptr = &pe_header[section_start_offset..];
-
-        // Store the length of the section header
-        let section_len = core::mem::size_of::<Section>() as usize;
+    // Store the length of the section header
+    let section_len = core::mem::size_of::<Section>();
 
+    for section_num in 0..header.number_of_sections {
         // Get the start/end of the current section header
-        let section_start = section_len * section_num as usize;
+        let section_start = section_start_offset + section_len * section_num as usize;
         let section_end   = section_start + section_len;
 
-        // Cast the current data location as a `Section`
-        let section = unsafe {
-            &*(section_ptr[section_start..section_end].as_ptr() as *const Section)
+        // Read the current section header, which may be unaligned as well
+        let section = match pe_header.get(section_start..section_end) {
+            Some(section) => unsafe {
+                core::ptr::read_unaligned(section.as_ptr() as *const Section)
+            },
+            None => return err!(&Error::InvalidSection)
         };
 
         // Get the start/end of the actual section data
         let section_data_start = section.raw_data_ptr as usize;
-        let section_data_end   = (section.raw_data_ptr + section.raw_data_size) as usize;
+        let section_data_end   = match section.raw_data_ptr
+                .checked_add(section.raw_data_size) {
+            Some(end) => end as usize,
+            None => return err!(&Error::InvalidSection)
+        };
+
+        let section_data = match data.get(section_data_start..section_data_end) {
+            Some(section_data) => section_data,
+            None => return err!(&Error::InvalidSection)
+        };
 
         // Store the parsed section
         sections[section_num as usize] = Some((
-            &data[section_data_start..section_data_end],
+            section_data,
             section.virt_addr,
             section.permissions()
         ));
     }
 
+    // Parse the data directories
+    let mut data_directories = [DataDirectory::default(); NUM_DATA_DIRECTORIES];
+
+    // Get the number of data directories found in the optional header
+    let num_directories = read_u32(opt_header, directories_offset - 4).unwrap_or(0);
+    let num_directories = core::cmp::min(num_directories as usize, NUM_DATA_DIRECTORIES);
+
+    for (index, directory) in data_directories.iter_mut()
+            .take(num_directories).enumerate() {
+        let start = directories_offset + index * 8;
+
+        let rva  = read_u32(opt_header, start);
+        let size = read_u32(opt_header, start + 4);
+
+        let (rva, size) = match (rva, size) {
+            (Some(rva), Some(size)) => (rva, size),
+            _ => return err!(&Error::InvalidDataDirectories)
+        };
+
+        *directory = DataDirectory { rva, size };
+    }
+
     Ok(Parsed {
         sections,
-        image_base: header.image_base,
-        entry_point: header.entry_point_rva as u64 + header.image_base
+        image_base,
+        entry_point: header.entry_point_rva as u64 + image_base,
+        machine,
+        magic,
+        data_directories,
+        timestamp: header.date_stamp,
+        data
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    /// Offset of the PE header in the test image
+    const PE: usize = 0x40;
+
+    /// Offset of the section data in the test image
+    const RAW_DATA: u32 = 0x300;
+
+    /// Copy `bytes` into `image` at `offset`
+    fn put(image: &mut [u8], offset: usize, bytes: &[u8]) {
+        image[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Build an image for `machine` with the optional header `magic` and one section
+    fn test_image(machine: Machine, magic: Magic) -> Vec<u8> {
+        let directories_offset = match magic {
+            Magic::Hdr32 => DATA_DIRECTORIES_OFFSET_32,
+            _            => DATA_DIRECTORIES_OFFSET_64
+        };
+        let opt_header_size = directories_offset + NUM_DATA_DIRECTORIES * 8;
+        let opt = PE + 0x18;
+
+        let mut image = vec![0u8; 0x400];
+        put(&mut image, 0,    b"MZ");
+        put(&mut image, 0x3c, &(PE as u32).to_le_bytes());
+
+        put(&mut image, PE,      b"PE\0\0");
+        put(&mut image, PE + 4,  &(machine as u16).to_le_bytes());
+        put(&mut image, PE + 6,  &1u16.to_le_bytes());
+        put(&mut image, PE + 8,  &0x1234_5678u32.to_le_bytes());
+        put(&mut image, PE + 20, &(opt_header_size as u16).to_le_bytes());
+
+        put(&mut image, opt,      &(magic as u16).to_le_bytes());
+        put(&mut image, opt + 16, &0x1000u32.to_le_bytes());
+        match magic {
+            Magic::Hdr32 => put(&mut image, opt + 28, &0x40_0000u32.to_le_bytes()),
+            _            => put(&mut image, opt + 24, &0x1_4000_0000u64.to_le_bytes())
+        }
+        put(&mut image, opt + directories_offset - 4,
+            &(NUM_DATA_DIRECTORIES as u32).to_le_bytes());
+
+        // `.text` section header
+        let section = opt + opt_header_size;
+        put(&mut image, section,      b".text\0\0\0");
+        put(&mut image, section + 8,  &0x10u32.to_le_bytes());
+        put(&mut image, section + 12, &0x1000u32.to_le_bytes());
+        put(&mut image, section + 16, &0x10u32.to_le_bytes());
+        put(&mut image, section + 20, &RAW_DATA.to_le_bytes());
+        put(&mut image, section + 36, &0x6000_0020u32.to_le_bytes());
+
+        image
+    }
+
+    /// Get the offset of the section header in an image built by [`test_image`]
+    fn section_offset(image: &[u8]) -> usize {
+        let opt_header_size = u16::from_le_bytes([image[PE + 20], image[PE + 21]]);
+        PE + 0x18 + usize::from(opt_header_size)
+    }
+
+    /// Get the [`Error`] `result` failed with, if any
+    fn error(result: Result<Parsed>) -> Option<Error> {
+        result.err()?.downcast_ref::<Error>().copied()
+    }
+
+    #[test]
+    fn test_parse() {
+        let image  = test_image(Machine::Amd64, Magic::Hdr64);
+        let parsed = parse(&image).unwrap();
+
+        assert_eq!(parsed.machine, Machine::Amd64);
+        assert_eq!(parsed.magic, Magic::Hdr64);
+        assert_eq!(parsed.image_base, 0x1_4000_0000);
+        assert_eq!(parsed.entry_point, 0x1_4000_1000);
+        assert_eq!(parsed.timestamp, 0x1234_5678);
+
+        let (data, virt_addr, permissions) = parsed.sections[0].unwrap();
+        assert_eq!(data.len(), 0x10);
+        assert_eq!(virt_addr, 0x1000);
+        assert!(permissions.executable && permissions.readable && !permissions.writable);
+        assert!(parsed.sections[1].is_none());
+    }
+
+    #[test]
+    fn test_pe32() {
+        let image  = test_image(Machine::I386, Magic::Hdr32);
+        let parsed = parse_for(&image, Machine::I386).unwrap();
+
+        assert_eq!(parsed.magic, Magic::Hdr32);
+        assert_eq!(parsed.image_base, 0x40_0000);
+        assert_eq!(parsed.entry_point, 0x40_1000);
+    }
+
+    #[test]
+    fn test_parse_for() {
+        let image = test_image(Machine::Amd64, Magic::Hdr64);
+        assert!(parse_for(&image, Machine::Amd64).is_ok());
+
+        let result = parse_for(&image, Machine::Arm64);
+        assert!(matches!(error(result), Some(Error::MachineMismatch)));
+
+        let image  = test_image(Machine::Amd64, Magic::Hdr32);
+        let result = parse_for(&image, Machine::Amd64);
+        assert!(matches!(error(result), Some(Error::MagicMismatch)));
+    }
+
+    #[test]
+    fn test_machine_from_raw() {
+        for machine in [Machine::I386, Machine::Ia4, Machine::Amd64, Machine::Arm64] {
+            assert_eq!(Machine::from_raw(machine as u16), Some(machine));
+        }
+
+        assert_eq!(Machine::from_raw(0x1c0), None);
+        assert_eq!(Machine::Arm64.magic(), Magic::Hdr64);
+        assert_eq!(Machine::I386.magic(), Magic::Hdr32);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let image = test_image(Machine::Amd64, Magic::Hdr64);
+
+        // Every prefix that cuts off the section data fails without panicking
+        for len in 0..RAW_DATA as usize + 0x10 {
+            assert!(parse(&image[..len]).is_err(), "parsed {:#x} bytes", len);
+        }
+
+        let section = section_offset(&image);
+        assert!(matches!(error(parse(&image[..1])), Some(Error::InvalidMZHeader)));
+        assert!(matches!(error(parse(&image[..0x3e])), Some(Error::InvalidMZHeader)));
+        assert!(matches!(error(parse(&image[..PE + 8])), Some(Error::InvalidPEHEader)));
+
+        let result = parse(&image[..section + 8]);
+        assert!(matches!(error(result), Some(Error::InvalidSection)));
+
+        let result = parse(&image[..RAW_DATA as usize]);
+        assert!(matches!(error(result), Some(Error::InvalidSection)));
+    }
+
+    #[test]
+    fn test_corrupt() {
+        let good = test_image(Machine::Amd64, Magic::Hdr64);
+
+        let mut image = good.clone();
+        put(&mut image, 0x3c, &u32::MAX.to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::InvalidPEHEader)));
+
+        let mut image = good.clone();
+        put(&mut image, PE, b"NE");
+        assert!(matches!(error(parse(&image)), Some(Error::InvalidPEHEader)));
+
+        let mut image = good.clone();
+        put(&mut image, PE + 4, &0x1c0u16.to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::UnknownMachine)));
+
+        let mut image = good.clone();
+        put(&mut image, PE + 0x18, &0x107u16.to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::UnsupportedMagic)));
+
+        let mut image = good.clone();
+        put(&mut image, PE + 6, &(NUM_SECTIONS + 1).to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::TooManySections)));
+
+        // The optional header would extend past the end of the image
+        let mut image = good.clone();
+        put(&mut image, PE + 20, &u16::MAX.to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::InvalidOptionalHeader)));
+
+        // The end of the section data overflows a `u32`
+        let mut image = good.clone();
+        let section = section_offset(&image);
+        put(&mut image, section + 20, &u32::MAX.to_le_bytes());
+        assert!(matches!(error(parse(&image)), Some(Error::InvalidSection)));
+    }
+
+    #[test]
+    fn test_unaligned() {
+        let good = test_image(Machine::Amd64, Magic::Hdr64);
+
+        // Move every header one byte further, leaving the section data in place
+        let mut image = vec![0u8; good.len()];
+        image[..PE].copy_from_slice(&good[..PE]);
+        let raw_data = RAW_DATA as usize;
+        image[PE + 1..raw_data].copy_from_slice(&good[PE..raw_data - 1]);
+        image[RAW_DATA as usize..].copy_from_slice(&good[RAW_DATA as usize..]);
+        put(&mut image, 0x3c, &(PE as u32 + 1).to_le_bytes());
+
+        let parsed = parse(&image).unwrap();
+        assert_eq!(parsed.image_base, 0x1_4000_0000);
+        assert_eq!(parsed.sections[0].unwrap().0.len(), 0x10);
+    }
+}
@@ -0,0 +1,684 @@
+//! x64 exception directory (`.pdata`) parsing and a stack unwinder built on top of it
+//!
+//! Reference: [`x64 exception handling`](https://docs.microsoft.com/en-us/cpp/build/exception-handling-x64)
+
+use core::convert::TryInto;
+use errchain::*;
+
+use crate::{Parsed, DirectoryEntry, Error, Machine};
+
+/// Index of `rsp` in [`Context::regs`]
+pub const RSP: usize = 4;
+
+/// Index of `rbp` in [`Context::regs`]
+pub const RBP: usize = 5;
+
+/// `UNWIND_INFO` flag signaling that a chained `RUNTIME_FUNCTION` follows the codes
+const UNW_FLAG_CHAININFO: u8 = 0x4;
+
+/// A function table entry from
+/// [`RUNTIME_FUNCTION`](https://docs.microsoft.com/en-us/cpp/build/exception-handling-x64#struct-runtime_function)
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct RuntimeFunction {
+    /// Relative virtual address of the start of the function
+    pub begin_address: u32,
+
+    /// Relative virtual address of the end of the function
+    pub end_address: u32,
+
+    /// Relative virtual address of the `UNWIND_INFO` of the function
+    pub unwind_info_address: u32,
+}
+
+impl RuntimeFunction {
+    /// Parse the `RuntimeFunction` found at the start of `bytes`
+    fn from_bytes(bytes: &[u8]) -> Option<RuntimeFunction> {
+        Some(RuntimeFunction {
+            begin_address:       u32::from_le_bytes(bytes.get(0..4)?.try_into().ok()?),
+            end_address:         u32::from_le_bytes(bytes.get(4..8)?.try_into().ok()?),
+            unwind_info_address: u32::from_le_bytes(bytes.get(8..12)?.try_into().ok()?),
+        })
+    }
+}
+
+/// A decoded [`UNWIND_CODE`](https://docs.microsoft.com/en-us/cpp/build/exception-handling-x64#struct-unwind_code)
+#[derive(Debug, Copy, Clone)]
+pub enum UnwindOp {
+    /// Push a nonvolatile integer register (`UWOP_PUSH_NONVOL`)
+    PushNonVolatile(u8),
+
+    /// Allocate an area on the stack (`UWOP_ALLOC_LARGE` and `UWOP_ALLOC_SMALL`)
+    Alloc(u32),
+
+    /// Establish the frame pointer register (`UWOP_SET_FPREG`)
+    SetFramePointer,
+
+    /// Save a nonvolatile integer register at the given offset from the frame
+    /// (`UWOP_SAVE_NONVOL` and `UWOP_SAVE_NONVOL_FAR`)
+    SaveNonVolatile {
+        /// Register index that was saved
+        register: u8,
+
+        /// Offset of the saved register from the frame
+        offset: u32
+    },
+
+    /// Save all 128 bits of a nonvolatile XMM register (`UWOP_SAVE_XMM128` and
+    /// `UWOP_SAVE_XMM128_FAR`)
+    SaveXmm128,
+
+    /// Push a machine frame, optionally preceded by an error code
+    /// (`UWOP_PUSH_MACHFRAME`)
+    PushMachineFrame {
+        /// An error code was pushed before the machine frame
+        error_code: bool
+    },
+
+    /// Epilog description from version 2 unwind info (`UWOP_EPILOG`)
+    Epilog,
+}
+
+/// Parsed [`UNWIND_INFO`](https://docs.microsoft.com/en-us/cpp/build/exception-handling-x64#struct-unwind_info)
+#[derive(Debug, Copy, Clone)]
+pub struct UnwindInfo<'a> {
+    /// Version number of the unwind data
+    pub version: u8,
+
+    /// `UNW_FLAG_*` flags of the unwind data
+    pub flags: u8,
+
+    /// Length of the function prolog in bytes
+    pub size_of_prolog: u8,
+
+    /// Register used as the frame pointer, or zero if no frame pointer is used
+    pub frame_register: u8,
+
+    /// Scaled offset from `rsp` applied to the frame pointer when it is established
+    pub frame_offset: u8,
+
+    /// Raw unwind code slots
+    codes: &'a [u8],
+
+    /// The function whose unwind info continues this one
+    pub chained: Option<RuntimeFunction>,
+}
+
+impl<'a> UnwindInfo<'a> {
+    /// Iterate over the decoded unwind codes as `(prolog offset, operation)`
+    pub fn codes(&self) -> UnwindCodes<'a> {
+        UnwindCodes { codes: self.codes }
+    }
+}
+
+/// Iterator over the unwind codes of an [`UnwindInfo`]
+pub struct UnwindCodes<'a> {
+    /// Remaining unwind code slots
+    codes: &'a [u8],
+}
+
+impl<'a> UnwindCodes<'a> {
+    /// Read the `u16` in the slot at `index` from the remaining codes
+    fn slot(&self, index: usize) -> Option<u32> {
+        let bytes = self.codes.get(index * 2..index * 2 + 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().ok()?) as u32)
+    }
+}
+
+impl<'a> Iterator for UnwindCodes<'a> {
+    type Item = (u8, UnwindOp);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prolog_offset = *self.codes.first()?;
+        let op_info       = *self.codes.get(1)?;
+        let op            = op_info & 0xf;
+        let info          = op_info >> 4;
+
+        // Decode the operation and the number of slots it uses
+        let (op, slots) = match op {
+            0 => (UnwindOp::PushNonVolatile(info), 1),
+            1 if info == 0 => (UnwindOp::Alloc(self.slot(1)? * 8), 2),
+            1 => (UnwindOp::Alloc(self.slot(1)? | self.slot(2)? << 16), 3),
+            2 => (UnwindOp::Alloc(info as u32 * 8 + 8), 1),
+            3 => (UnwindOp::SetFramePointer, 1),
+            4 => (UnwindOp::SaveNonVolatile { register: info, offset: self.slot(1)? * 8 }, 2),
+            5 => (UnwindOp::SaveNonVolatile {
+                register: info,
+                offset:   self.slot(1)? | self.slot(2)? << 16
+            }, 3),
+            6 => (UnwindOp::Epilog, 2),
+            8 => (UnwindOp::SaveXmm128, 2),
+            9 => (UnwindOp::SaveXmm128, 3),
+            10 => (UnwindOp::PushMachineFrame { error_code: info != 0 }, 1),
+
+            // Unknown (or the spare `7`) codes can't be skipped reliably
+            _ => return None
+        };
+
+        self.codes = self.codes.get(slots * 2..)?;
+        Some((prolog_offset, op))
+    }
+}
+
+impl<'a> Parsed<'a> {
+    /// Get the exception directory if it holds x64 `RUNTIME_FUNCTION` entries. Other
+    /// architectures use a different layout for their entries.
+    fn x64_exception_directory(&self) -> Option<&'a [u8]> {
+        if self.machine != Machine::Amd64 {
+            return None;
+        }
+
+        self.directory(DirectoryEntry::Exception)
+    }
+
+    /// Iterate over the `RUNTIME_FUNCTION` entries of the exception directory
+    pub fn runtime_functions(&self) -> impl Iterator<Item = RuntimeFunction> + 'a {
+        self.x64_exception_directory()
+            .unwrap_or(&[])
+            .chunks_exact(core::mem::size_of::<RuntimeFunction>())
+            .filter_map(RuntimeFunction::from_bytes)
+    }
+
+    /// Find the `RUNTIME_FUNCTION` containing the given relative virtual address. Leaf
+    /// functions do not have an entry.
+    pub fn runtime_function(&self, rva: u32) -> Option<RuntimeFunction> {
+        let table = self.x64_exception_directory()?;
+        let entry_size = core::mem::size_of::<RuntimeFunction>();
+
+        // The exception directory is sorted by address, binary search for the function
+        let (mut low, mut high) = (0, table.len() / entry_size);
+
+        while low < high {
+            let mid  = low + (high - low) / 2;
+            let func = RuntimeFunction::from_bytes(&table[mid * entry_size..])?;
+
+            if rva < func.begin_address {
+                high = mid;
+            } else if rva >= func.end_address {
+                low = mid + 1;
+            } else {
+                return Some(func);
+            }
+        }
+
+        None
+    }
+
+    /// Parse the `UNWIND_INFO` for the given [`RuntimeFunction`]
+    ///
+    /// # Errors
+    ///
+    /// If the unwind info is out of bounds of the image or has an unsupported version
+    pub fn unwind_info(&self, func: &RuntimeFunction) -> Result<UnwindInfo<'a>> {
+        let header = match self.rva_to_slice(func.unwind_info_address, 4) {
+            Some(header) => header,
+            None => return err!(&Error::InvalidUnwindInfo)
+        };
+
+        let version = header[0] & 0x7;
+        let flags   = header[0] >> 3;
+        let count   = header[2] as u32;
+
+        ensure!(version == 1 || version == 2, &Error::UnsupportedUnwindVersion);
+
+        // The code slots are padded to an even count before the chained entry
+        let codes_size   = count * 2;
+        let chained_size = if flags & UNW_FLAG_CHAININFO > 0 { 12 } else { 0 };
+        let padded_size  = (count + (count & 1)) * 2;
+
+        let data = match self.rva_to_slice(func.unwind_info_address,
+                                           4 + padded_size + chained_size) {
+            Some(data) => data,
+            None => return err!(&Error::InvalidUnwindInfo)
+        };
+
+        let chained = if chained_size > 0 {
+            RuntimeFunction::from_bytes(&data[4 + padded_size as usize..])
+        } else {
+            None
+        };
+
+        Ok(UnwindInfo {
+            version,
+            flags,
+            size_of_prolog: header[1],
+            frame_register: header[3] & 0xf,
+            frame_offset:   header[3] >> 4,
+            codes:          &data[4..4 + codes_size as usize],
+            chained
+        })
+    }
+}
+
+/// Register state used as the starting point of an unwind
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Context {
+    /// Instruction pointer
+    pub rip: u64,
+
+    /// General purpose registers in unwind code order (`rax`, `rcx`, `rdx`, `rbx`,
+    /// `rsp`, `rbp`, `rsi`, `rdi`, `r8`-`r15`)
+    pub regs: [u64; 16],
+}
+
+impl Context {
+    /// Create a [`Context`] from the registers needed to start unwinding. Other
+    /// nonvolatile registers are recovered from the stack while unwinding.
+    pub fn new(rip: u64, rsp: u64, rbp: u64) -> Context {
+        let mut regs = [0; 16];
+        regs[RSP] = rsp;
+        regs[RBP] = rbp;
+
+        Context { rip, regs }
+    }
+}
+
+/// Unwind a single frame of `context` using the unwind info of the function containing
+/// `context.rip`. Functions without unwind info are treated as leaf functions.
+fn unwind_frame<F>(image: &Parsed, context: &mut Context, read_u64: &mut F) -> Option<()>
+        where F: FnMut(u64) -> Option<u64> {
+    let rva: u32 = context.rip.checked_sub(image.image_base)?.try_into().ok()?;
+
+    // Only unwind from addresses inside of the image
+    image.rva_to_slice(rva, 1)?;
+
+    if let Some(mut func) = image.runtime_function(rva) {
+        // Offset into the function, only relevant for the primary unwind info
+        let mut func_offset = Some(rva - func.begin_address);
+
+        loop {
+            let info = match image.unwind_info(&func) {
+                Ok(info) => info,
+                Err(_)   => return None
+            };
+
+            // Operations past the current offset in the prolog haven't executed yet
+            let executed = |prolog_offset: u8| match func_offset {
+                Some(offset) if offset < info.size_of_prolog as u32 =>
+                    prolog_offset as u32 <= offset,
+                _ => true
+            };
+
+            // Calculate the frame that saved registers are relative to
+            let mut frame = context.regs[RSP];
+            let frame_set = info.codes().any(|(prolog_offset, op)|
+                matches!(op, UnwindOp::SetFramePointer) && executed(prolog_offset));
+
+            if info.frame_register != 0 && frame_set {
+                frame = context.regs[info.frame_register as usize]
+                    .wrapping_sub(info.frame_offset as u64 * 16);
+            }
+
+            for (prolog_offset, op) in info.codes() {
+                if !executed(prolog_offset) {
+                    continue;
+                }
+
+                match op {
+                    UnwindOp::PushNonVolatile(register) => {
+                        context.regs[register as usize] = read_u64(context.regs[RSP])?;
+                        context.regs[RSP] = context.regs[RSP].wrapping_add(8);
+                    }
+                    UnwindOp::Alloc(size) => {
+                        context.regs[RSP] = context.regs[RSP].wrapping_add(size as u64);
+                    }
+                    UnwindOp::SetFramePointer => {
+                        context.regs[RSP] = frame;
+                    }
+                    UnwindOp::SaveNonVolatile { register, offset } => {
+                        context.regs[register as usize] =
+                            read_u64(frame.wrapping_add(offset as u64))?;
+                    }
+                    UnwindOp::PushMachineFrame { error_code } => {
+                        let error_size = if error_code { 8 } else { 0 };
+                        let rsp = context.regs[RSP].wrapping_add(error_size);
+
+                        // The machine frame holds the interrupted `rip` and `rsp`
+                        context.rip       = read_u64(rsp)?;
+                        context.regs[RSP] = read_u64(rsp.wrapping_add(24))?;
+                        return Some(());
+                    }
+                    UnwindOp::SaveXmm128 | UnwindOp::Epilog => {}
+                }
+            }
+
+            match info.chained {
+                Some(chained) => {
+                    func        = chained;
+                    func_offset = None;
+                }
+                None => break
+            }
+        }
+    }
+
+    // Pop the return address
+    context.rip       = read_u64(context.regs[RSP])?;
+    context.regs[RSP] = context.regs[RSP].wrapping_add(8);
+
+    Some(())
+}
+
+/// Walk the stack starting from `context` using the exception directory of `image`.
+/// `read_u64` reads the stack of the unwound core and returns `None` for unreadable
+/// addresses.
+///
+/// The starting `rip` followed by each return address is written into `frames`.
+/// Returns the number of frames written. Unwinding stops at the first return address
+/// outside of the image, on an unreadable stack, or once `frames` is full.
+///
+/// Epilogs are not simulated, so a `rip` inside of an epilog may produce a wrong
+/// caller.
+pub fn backtrace<F>(image: &Parsed, mut context: Context, mut read_u64: F,
+        frames: &mut [u64]) -> usize
+        where F: FnMut(u64) -> Option<u64> {
+    let mut count = 0;
+
+    while count < frames.len() && context.rip != 0 {
+        frames[count] = context.rip;
+        count += 1;
+
+        let prev_rsp = context.regs[RSP];
+
+        if unwind_frame(image, &mut context, &mut read_u64).is_none() {
+            break;
+        }
+
+        // The stack only grows down, so a caller must have a higher stack pointer
+        if context.regs[RSP] <= prev_rsp {
+            break;
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataDirectory, Magic, SectionPermissions};
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// Image base of the test image
+    const BASE: u64 = 0x1_4000_0000;
+
+    /// Address of the bottom of the test stack
+    const STACK: u64 = 0x7000_0000;
+
+    /// Relative virtual address of the only section of the test image
+    const SECTION: u32 = 0x1000;
+
+    /// Relative virtual address of the `.pdata` table
+    const PDATA: u32 = 0x1180;
+
+    /// `UWOP_*` codes
+    const PUSH_NONVOL:    u8 = 0;
+    const ALLOC_SMALL:    u8 = 2;
+    const SET_FPREG:      u8 = 3;
+    const PUSH_MACHFRAME: u8 = 10;
+
+    /// `rbx` in unwind code order
+    const RBX: usize = 3;
+
+    /// Copy `bytes` into the section at the relative virtual address `rva`
+    fn put(section: &mut [u8], rva: u32, bytes: &[u8]) {
+        let offset = (rva - SECTION) as usize;
+        section[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Write a `RUNTIME_FUNCTION` to the section
+    fn put_function(section: &mut [u8], rva: u32, func: (u32, u32, u32)) {
+        put(section, rva,      &func.0.to_le_bytes());
+        put(section, rva + 4,  &func.1.to_le_bytes());
+        put(section, rva + 8,  &func.2.to_le_bytes());
+    }
+
+    /// Write an `UNWIND_INFO` with the given `(prolog offset, op, info)` codes to the
+    /// section
+    fn put_info(section: &mut [u8], rva: u32, flags: u8, prolog: u8, frame: u8,
+            codes: &[(u8, u8, u8)], chained: Option<(u32, u32, u32)>) {
+        put(section, rva, &[1 | flags << 3, prolog, codes.len() as u8, frame]);
+
+        for (index, (offset, op, info)) in codes.iter().enumerate() {
+            put(section, rva + 4 + index as u32 * 2, &[*offset, op | info << 4]);
+        }
+
+        if let Some(chained) = chained {
+            let padded = (codes.len() + (codes.len() & 1)) as u32 * 2;
+            put_function(section, rva + 4 + padded, chained);
+        }
+    }
+
+    /// Build the section of an image with four functions:
+    ///
+    /// * `0x1000`: `push rbx; sub rsp, 0x20`
+    /// * `0x1040`: `push rbp; sub rsp, 0x30; lea rbp, [rsp + 0x10]`
+    /// * `0x1080`: an interrupt handler entered with an error code
+    /// * `0x10a0`: `sub rsp, 0x10` chained to the unwind info of `0x1000`
+    fn section() -> Vec<u8> {
+        let mut section = std::vec![0u8; 0x200];
+
+        put_info(&mut section, 0x1100, 0, 6, 0,
+            &[(6, ALLOC_SMALL, 3), (1, PUSH_NONVOL, RBX as u8)], None);
+        put_info(&mut section, 0x1120, 0, 9, RBP as u8 | 1 << 4,
+            &[(9, SET_FPREG, 0), (5, ALLOC_SMALL, 5), (1, PUSH_NONVOL, RBP as u8)], None);
+        put_info(&mut section, 0x1140, 0, 0, 0, &[(0, PUSH_MACHFRAME, 1)], None);
+        put_info(&mut section, 0x1160, UNW_FLAG_CHAININFO, 4, 0,
+            &[(4, ALLOC_SMALL, 1)], Some((0x10c0, 0x10e0, 0x1100)));
+
+        put_function(&mut section, PDATA,      (0x1000, 0x1040, 0x1100));
+        put_function(&mut section, PDATA + 12, (0x1040, 0x1080, 0x1120));
+        put_function(&mut section, PDATA + 24, (0x1080, 0x10a0, 0x1140));
+        put_function(&mut section, PDATA + 36, (0x10a0, 0x10c0, 0x1160));
+
+        section
+    }
+
+    /// Wrap `section` in a [`Parsed`] image
+    fn parsed(section: &[u8]) -> Parsed<'_> {
+        let permissions = SectionPermissions {
+            executable: true,
+            readable:   true,
+            writable:   false
+        };
+
+        let mut sections = [None; 6];
+        sections[0] = Some((section, SECTION, permissions));
+
+        let mut data_directories = [DataDirectory::default(); 16];
+        data_directories[DirectoryEntry::Exception as usize] =
+            DataDirectory { rva: PDATA, size: 48 };
+
+        Parsed {
+            sections,
+            image_base:  BASE,
+            entry_point: BASE + 0x1000,
+            machine:     Machine::Amd64,
+            magic:       Magic::Hdr64,
+            data_directories,
+            timestamp:   0,
+            data:        &[]
+        }
+    }
+
+    /// Unwind a single frame of `context` with `stack` starting at [`STACK`]
+    fn unwind(image: &Parsed, context: &mut Context, stack: &[u64]) -> Option<()> {
+        unwind_frame(image, context, &mut |addr: u64| {
+            let index = addr.checked_sub(STACK)?;
+            if index % 8 != 0 {
+                return None;
+            }
+            stack.get(index as usize / 8).copied()
+        })
+    }
+
+    /// Decode raw unwind code slots
+    fn decode(codes: &[u8]) -> Vec<(u8, UnwindOp)> {
+        UnwindInfo {
+            version:        1,
+            flags:          0,
+            size_of_prolog: 0,
+            frame_register: 0,
+            frame_offset:   0,
+            codes,
+            chained:        None
+        }.codes().collect()
+    }
+
+    #[test]
+    fn test_decode() {
+        let ops = decode(&[
+            0x10, 0x30,             // UWOP_PUSH_NONVOL rbx
+            0x0e, 0x01, 0x20, 0x00, // UWOP_ALLOC_LARGE 0x20 * 8
+            0x0c, 0x11, 0x00, 0x00, 0x01, 0x00, // UWOP_ALLOC_LARGE 0x10000
+            0x08, 0x72,             // UWOP_ALLOC_SMALL 7 * 8 + 8
+            0x06, 0x03,             // UWOP_SET_FPREG
+            0x04, 0x64, 0x03, 0x00, // UWOP_SAVE_NONVOL rsi at 3 * 8
+            0x03, 0x75, 0x10, 0x00, 0x02, 0x00, // UWOP_SAVE_NONVOL_FAR rdi at 0x20010
+            0x02, 0x68, 0x01, 0x00, // UWOP_SAVE_XMM128 xmm6
+            0x01, 0x0a,             // UWOP_PUSH_MACHFRAME without an error code
+        ]);
+
+        assert!(matches!(ops[..], [
+            (0x10, UnwindOp::PushNonVolatile(3)),
+            (0x0e, UnwindOp::Alloc(0x100)),
+            (0x0c, UnwindOp::Alloc(0x10000)),
+            (0x08, UnwindOp::Alloc(0x40)),
+            (0x06, UnwindOp::SetFramePointer),
+            (0x04, UnwindOp::SaveNonVolatile { register: 6, offset: 0x18 }),
+            (0x03, UnwindOp::SaveNonVolatile { register: 7, offset: 0x20010 }),
+            (0x02, UnwindOp::SaveXmm128),
+            (0x01, UnwindOp::PushMachineFrame { error_code: false }),
+        ]));
+
+        // A truncated code and the spare `7` code stop the decoding
+        assert!(matches!(decode(&[0x10, 0x30, 0x0e, 0x01])[..],
+            [(0x10, UnwindOp::PushNonVolatile(3))]));
+        assert!(decode(&[0x00, 0x07, 0x10, 0x30]).is_empty());
+    }
+
+    #[test]
+    fn test_unwind_info() {
+        let section = section();
+        let image   = parsed(&section);
+
+        let func = image.runtime_function(0x1050).unwrap();
+        assert_eq!((func.begin_address, func.end_address), (0x1040, 0x1080));
+        assert!(image.runtime_function(0x10c0).is_none());
+        assert_eq!(image.runtime_functions().count(), 4);
+
+        let info = image.unwind_info(&func).unwrap();
+        assert_eq!(info.size_of_prolog, 9);
+        assert_eq!((info.frame_register, info.frame_offset), (RBP as u8, 1));
+        assert_eq!(info.codes().count(), 3);
+
+        let func = image.runtime_function(0x10a0).unwrap();
+        let chained = image.unwind_info(&func).unwrap().chained.unwrap();
+        assert_eq!(chained.unwind_info_address, 0x1100);
+
+        // Unsupported versions are rejected
+        let mut section = section.clone();
+        put(&mut section, 0x1100, &[3]);
+        let image = parsed(&section);
+        let func  = image.runtime_function(0x1000).unwrap();
+        assert!(image.unwind_info(&func).is_err());
+    }
+
+    #[test]
+    fn test_unwind_push_alloc() {
+        let section = section();
+        let image   = parsed(&section);
+
+        let stack = [0, 0, 0, 0, 0x1234, BASE + 0x1050];
+        let mut context = Context::new(BASE + 0x1020, STACK, 0);
+        unwind(&image, &mut context, &stack).unwrap();
+
+        assert_eq!(context.rip, BASE + 0x1050);
+        assert_eq!(context.regs[RSP], STACK + 48);
+        assert_eq!(context.regs[RBX], 0x1234);
+
+        // Inside of the prolog only the push has executed
+        let stack = [0x1234, BASE + 0x1050];
+        let mut context = Context::new(BASE + 0x1004, STACK, 0);
+        unwind(&image, &mut context, &stack).unwrap();
+
+        assert_eq!(context.rip, BASE + 0x1050);
+        assert_eq!(context.regs[RSP], STACK + 16);
+        assert_eq!(context.regs[RBX], 0x1234);
+    }
+
+    #[test]
+    fn test_unwind_frame_pointer() {
+        let section = section();
+        let image   = parsed(&section);
+
+        // The stack pointer moved below the fixed allocation, so the frame has to be
+        // found from `rbp`
+        let stack = [0, 0, 0, 0, 0, 0, 0x5555, BASE + 0x1020];
+        let mut context = Context::new(BASE + 0x1050, STACK - 0x40, STACK + 0x10);
+        unwind(&image, &mut context, &stack).unwrap();
+
+        assert_eq!(context.rip, BASE + 0x1020);
+        assert_eq!(context.regs[RSP], STACK + 64);
+        assert_eq!(context.regs[RBP], 0x5555);
+    }
+
+    #[test]
+    fn test_unwind_machine_frame() {
+        let section = section();
+        let image   = parsed(&section);
+
+        // Error code, rip, cs, rflags, rsp
+        let stack = [0xe, BASE + 0x1020, 0x8, 0x202, STACK + 0x100];
+        let mut context = Context::new(BASE + 0x1090, STACK, 0);
+        unwind(&image, &mut context, &stack).unwrap();
+
+        assert_eq!(context.rip, BASE + 0x1020);
+        assert_eq!(context.regs[RSP], STACK + 0x100);
+
+        // A corrupt stack pointer doesn't overflow
+        let mut context = Context::new(BASE + 0x1090, u64::MAX - 7, 0);
+        assert!(unwind(&image, &mut context, &stack).is_none());
+    }
+
+    #[test]
+    fn test_unwind_chained() {
+        let section = section();
+        let image   = parsed(&section);
+
+        // Primary allocation, chained allocation, rbx and the return address
+        let stack = [0, 0, 0, 0, 0, 0, 0x77, BASE + 0x1050];
+        let mut context = Context::new(BASE + 0x10a8, STACK, 0);
+        unwind(&image, &mut context, &stack).unwrap();
+
+        assert_eq!(context.rip, BASE + 0x1050);
+        assert_eq!(context.regs[RSP], STACK + 64);
+        assert_eq!(context.regs[RBX], 0x77);
+    }
+
+    #[test]
+    fn test_backtrace() {
+        let section = section();
+        let image   = parsed(&section);
+
+        // 0x1000 returns into 0x1040, which returns outside of the image
+        let stack = [0, 0, 0, 0, 0x1234, BASE + 0x1050,
+                     0, 0, 0, 0, 0, 0, 0x5555, 0x4141];
+        let read_u64 = |addr: u64| {
+            stack.get(addr.checked_sub(STACK)? as usize / 8).copied()
+        };
+
+        // `rbp` already holds the frame pointer of 0x1040
+        let mut frames = [0; 8];
+        let context = Context::new(BASE + 0x1020, STACK, STACK + 64);
+        let count   = backtrace(&image, context, read_u64, &mut frames);
+
+        assert_eq!(frames[..count], [BASE + 0x1020, BASE + 0x1050, 0x4141]);
+
+        // Stops once the frames are full
+        let count = backtrace(&image, context, read_u64, &mut frames[..1]);
+        assert_eq!(count, 1);
+    }
+}
@@ -29,6 +29,9 @@ pub enum RangeSetError {
 
     /// Attempted to delete an element out of bounds of the current [`RangeSet`]
     DeleteOutOfBounds,
+
+    /// No range in the [`RangeSet`] can fit the requested allocation
+    OutOfMemory,
 }
 
 /// A range that is inclusive of the final element.
@@ -411,9 +414,8 @@ impl RangeSet {
                 Ok(return_addr)
             }
 
-            // This code is not unreachable, not sure why the compiler thinks it is..
-            #[allow(unreachable_code)]
-            _ => unreachable!()
+            // No range was large enough
+            None => err!(&RangeSetError::OutOfMemory)
         }
     }
 }
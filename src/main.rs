@@ -90,24 +90,8 @@ pub fn build_arm() -> Result<()> {
     // Copy the bootloader into the output directory
     std::fs::copy(bootloader_path, Path::new(output_dir).join("paintbrush_arm.boot"))?;
 
-    // Build the kernel
-    if !Command::new("cargo")
-            .current_dir("kernel")
-            .args(&["build", "--release", "--target", ".cargo/aarch64-unknown-uefi.json"])
-            .status()?
-            .success() {
-        panic!("Failed to build kernel");
-    }
-
-    // Create the output path for the arm kernel
-    let bootloader_path = Path::new("kernel")
-        .join("target")
-        .join("aarch64-unknown-uefi")
-        .join("release")
-        .join("kernel.efi");
-
-    // Copy the bootloader into the output directory
-    std::fs::copy(bootloader_path, Path::new(output_dir).join("paintbrush_arm.kernel"))?;
+    // The kernel uses x86_64 MSRs, `rdtsc` and assembly throughout, so there is no
+    // aarch64 kernel to build yet
 
     Ok(())
 }